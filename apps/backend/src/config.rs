@@ -1,6 +1,8 @@
 use serde::Deserialize;
 use std::env;
 
+use crate::{models::Currency, services::currency_service::CurrencyService};
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct Config {
     pub database_url: String,
@@ -8,6 +10,21 @@ pub struct Config {
     pub jwt_secret: String,
     pub port: u16,
     pub cors_origins: Vec<String>,
+    pub base_currency: Currency,
+    pub supported_currencies: Vec<Currency>,
+    /// Where attachments are kept: `local` or `s3`.
+    pub file_storage: String,
+    /// Root directory of the local file store.
+    pub file_storage_path: String,
+    pub s3_bucket: Option<String>,
+    /// Endpoint of an S3-compatible service such as MinIO; AWS when unset.
+    pub s3_endpoint: Option<String>,
+    pub s3_region: String,
+    pub max_attachment_bytes: usize,
+    /// Address of the web app, e.g. `https://print.example.com`. Job ticket
+    /// QR codes link to the job page under it, or hold the bare job id when
+    /// unset.
+    pub app_url: Option<String>,
 }
 
 impl Config {
@@ -30,8 +47,43 @@ impl Config {
                 .split(',')
                 .map(|s| s.trim().to_string())
                 .collect(),
+            base_currency: env::var("BASE_CURRENCY")
+                .unwrap_or_else(|_| "USD".to_string())
+                .parse()?,
+            supported_currencies: env::var("SUPPORTED_CURRENCIES")
+                .unwrap_or_else(|_| "USD,XAF,EUR,GBP,CAD".to_string())
+                .split(',')
+                .map(|s| s.trim().parse())
+                .collect::<Result<_, _>>()?,
+            file_storage: env::var("FILE_STORAGE")
+                .unwrap_or_else(|_| "local".to_string()),
+            file_storage_path: env::var("FILE_STORAGE_PATH")
+                .unwrap_or_else(|_| "./uploads".to_string()),
+            s3_bucket: env::var("S3_BUCKET").ok(),
+            s3_endpoint: env::var("S3_ENDPOINT").ok(),
+            s3_region: env::var("S3_REGION")
+                .unwrap_or_else(|_| "us-east-1".to_string()),
+            max_attachment_bytes: env::var("MAX_ATTACHMENT_MB")
+                .unwrap_or_else(|_| "50".to_string())
+                .parse::<usize>()
+                .unwrap_or(50)
+                * 1024
+                * 1024,
+            app_url: env::var("APP_URL")
+                .ok()
+                .map(|url| url.trim_end_matches('/').to_string())
+                .filter(|url| !url.is_empty()),
         };
 
+        // Every quote shown in another currency is converted from the base one
+        if !CurrencyService::has_rate(config.base_currency) {
+            return Err(format!(
+                "Base currency {} has no exchange rate source",
+                config.base_currency
+            )
+            .into());
+        }
+
         Ok(config)
     }
 }
@@ -1,4 +1,8 @@
+use sea_orm::prelude::Decimal;
 use sea_orm_migration::prelude::*;
+use std::str::FromStr;
+
+use crate::models::default_machines;
 
 pub struct Migrator;
 
@@ -10,6 +14,22 @@ impl MigratorTrait for Migrator {
             Box::new(CreateCostParametersTable),
             Box::new(CreateJobsTable),
             Box::new(CreateBrandingSettingsTable),
+            Box::new(CreateCurrencySettingsTable),
+            Box::new(CreateCustomersTable),
+            Box::new(AddUserPreferredCurrency),
+            Box::new(CreateMachinesTable),
+            Box::new(AddCustomerProfitRate),
+            Box::new(AddCustomerTaxFields),
+            Box::new(CreateTaxSettingsTable),
+            Box::new(AddCustomerDiscount),
+            Box::new(CreateJobTemplatesTable),
+            Box::new(CreateJobAttachmentsTable),
+            Box::new(CreateJobCommentsTables),
+            Box::new(AddMachineCapacity),
+            Box::new(CreateHolidaysTable),
+            Box::new(CreateCostParameterVersionsTable),
+            Box::new(RenamePlateCostParameter),
+            Box::new(SeedDefaultMachines),
         ]
     }
 }
@@ -204,6 +224,702 @@ impl MigrationTrait for CreateBrandingSettingsTable {
     }
 }
 
+#[derive(DeriveMigrationName)]
+pub struct CreateCurrencySettingsTable;
+
+#[async_trait::async_trait]
+impl MigrationTrait for CreateCurrencySettingsTable {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(CurrencySettings::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(CurrencySettings::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .extra("DEFAULT gen_random_uuid()".to_string()),
+                    )
+                    .col(ColumnDef::new(CurrencySettings::BaseCurrency).string_len(3).not_null().default("USD"))
+                    .col(ColumnDef::new(CurrencySettings::DefaultCurrency).string_len(3).not_null().default("USD"))
+                    .col(ColumnDef::new(CurrencySettings::SupportedCurrencies).string().not_null().default("USD"))
+                    .col(
+                        ColumnDef::new(CurrencySettings::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(CurrencySettings::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(CurrencySettings::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveMigrationName)]
+pub struct CreateCustomersTable;
+
+#[async_trait::async_trait]
+impl MigrationTrait for CreateCustomersTable {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Customers::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Customers::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .extra("DEFAULT gen_random_uuid()".to_string()),
+                    )
+                    .col(ColumnDef::new(Customers::Name).string().not_null())
+                    .col(ColumnDef::new(Customers::Email).string())
+                    .col(ColumnDef::new(Customers::Phone).string())
+                    .col(ColumnDef::new(Customers::PreferredCurrency).string_len(3))
+                    .col(
+                        ColumnDef::new(Customers::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(Customers::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Customers::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveMigrationName)]
+pub struct AddUserPreferredCurrency;
+
+#[async_trait::async_trait]
+impl MigrationTrait for AddUserPreferredCurrency {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Users::Table)
+                    .add_column_if_not_exists(ColumnDef::new(Users::PreferredCurrency).string_len(3))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Users::Table)
+                    .drop_column(Users::PreferredCurrency)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveMigrationName)]
+pub struct CreateMachinesTable;
+
+#[async_trait::async_trait]
+impl MigrationTrait for CreateMachinesTable {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Machines::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Machines::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .extra("DEFAULT gen_random_uuid()".to_string()),
+                    )
+                    .col(ColumnDef::new(Machines::Name).string().not_null())
+                    .col(ColumnDef::new(Machines::Kind).string().not_null())
+                    .col(ColumnDef::new(Machines::MakeReadyMinutes).decimal().not_null().default(0))
+                    .col(ColumnDef::new(Machines::SpeedBands).text().not_null())
+                    .col(ColumnDef::new(Machines::MachineRatePerHour).decimal().not_null())
+                    .col(ColumnDef::new(Machines::OperatorRatePerHour).decimal().not_null())
+                    .col(ColumnDef::new(Machines::IsActive).boolean().not_null().default(true))
+                    .col(
+                        ColumnDef::new(Machines::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(Machines::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Machines::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveMigrationName)]
+pub struct AddCustomerProfitRate;
+
+#[async_trait::async_trait]
+impl MigrationTrait for AddCustomerProfitRate {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Customers::Table)
+                    .add_column_if_not_exists(ColumnDef::new(Customers::ProfitRate).decimal())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Customers::Table)
+                    .drop_column(Customers::ProfitRate)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveMigrationName)]
+pub struct AddCustomerTaxFields;
+
+#[async_trait::async_trait]
+impl MigrationTrait for AddCustomerTaxFields {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Customers::Table)
+                    .add_column_if_not_exists(ColumnDef::new(Customers::Country).string_len(2))
+                    .add_column_if_not_exists(ColumnDef::new(Customers::TaxId).string())
+                    .add_column_if_not_exists(ColumnDef::new(Customers::TaxExempt).boolean().not_null().default(false))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Customers::Table)
+                    .drop_column(Customers::Country)
+                    .drop_column(Customers::TaxId)
+                    .drop_column(Customers::TaxExempt)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveMigrationName)]
+pub struct CreateTaxSettingsTable;
+
+#[async_trait::async_trait]
+impl MigrationTrait for CreateTaxSettingsTable {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(TaxSettings::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(TaxSettings::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .extra("DEFAULT gen_random_uuid()".to_string()),
+                    )
+                    .col(ColumnDef::new(TaxSettings::CompanyCountry).string_len(2).not_null().default("CM"))
+                    .col(ColumnDef::new(TaxSettings::CompanyTaxId).string())
+                    .col(ColumnDef::new(TaxSettings::Rates).text().not_null())
+                    .col(ColumnDef::new(TaxSettings::ReverseChargeCountries).string().not_null().default(""))
+                    .col(ColumnDef::new(TaxSettings::ReverseChargeNote).text().not_null())
+                    .col(ColumnDef::new(TaxSettings::ExemptionNote).text().not_null())
+                    .col(
+                        ColumnDef::new(TaxSettings::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(TaxSettings::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(TaxSettings::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveMigrationName)]
+pub struct AddCustomerDiscount;
+
+#[async_trait::async_trait]
+impl MigrationTrait for AddCustomerDiscount {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Customers::Table)
+                    .add_column_if_not_exists(ColumnDef::new(Customers::DiscountKind).string())
+                    .add_column_if_not_exists(ColumnDef::new(Customers::DiscountValue).decimal())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Customers::Table)
+                    .drop_column(Customers::DiscountKind)
+                    .drop_column(Customers::DiscountValue)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveMigrationName)]
+pub struct CreateJobTemplatesTable;
+
+#[async_trait::async_trait]
+impl MigrationTrait for CreateJobTemplatesTable {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(JobTemplates::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(JobTemplates::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .extra("DEFAULT gen_random_uuid()".to_string()),
+                    )
+                    .col(ColumnDef::new(JobTemplates::Name).string().not_null())
+                    .col(ColumnDef::new(JobTemplates::Description).text())
+                    .col(ColumnDef::new(JobTemplates::JobType).string().not_null())
+                    .col(ColumnDef::new(JobTemplates::Quantity).integer().not_null())
+                    .col(ColumnDef::new(JobTemplates::Specifications).text().not_null())
+                    .col(ColumnDef::new(JobTemplates::Scope).string().not_null().default("user"))
+                    .col(ColumnDef::new(JobTemplates::OwnerId).uuid().not_null())
+                    .col(
+                        ColumnDef::new(JobTemplates::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(JobTemplates::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(JobTemplates::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveMigrationName)]
+pub struct CreateJobAttachmentsTable;
+
+#[async_trait::async_trait]
+impl MigrationTrait for CreateJobAttachmentsTable {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(JobAttachments::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(JobAttachments::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .extra("DEFAULT gen_random_uuid()".to_string()),
+                    )
+                    .col(ColumnDef::new(JobAttachments::JobId).uuid().not_null())
+                    .col(ColumnDef::new(JobAttachments::Kind).string().not_null().default("other"))
+                    .col(ColumnDef::new(JobAttachments::Filename).string().not_null())
+                    .col(ColumnDef::new(JobAttachments::MimeType).string().not_null())
+                    .col(ColumnDef::new(JobAttachments::SizeBytes).big_integer().not_null())
+                    .col(ColumnDef::new(JobAttachments::Checksum).string_len(64).not_null())
+                    .col(ColumnDef::new(JobAttachments::UploadedBy).uuid())
+                    .col(ColumnDef::new(JobAttachments::StorageKey).string().not_null())
+                    .col(
+                        ColumnDef::new(JobAttachments::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_job_attachments_job_id")
+                    .table(JobAttachments::Table)
+                    .col(JobAttachments::JobId)
+                    .if_not_exists()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(JobAttachments::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveMigrationName)]
+pub struct CreateJobCommentsTables;
+
+#[async_trait::async_trait]
+impl MigrationTrait for CreateJobCommentsTables {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(JobComments::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(JobComments::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .extra("DEFAULT gen_random_uuid()".to_string()),
+                    )
+                    .col(ColumnDef::new(JobComments::JobId).uuid().not_null())
+                    .col(ColumnDef::new(JobComments::AuthorId).uuid().not_null())
+                    .col(ColumnDef::new(JobComments::Body).text().not_null())
+                    .col(ColumnDef::new(JobComments::CustomerVisible).boolean().not_null().default(false))
+                    .col(ColumnDef::new(JobComments::Mentions).text().not_null().default("[]"))
+                    .col(ColumnDef::new(JobComments::Edited).boolean().not_null().default(false))
+                    .col(
+                        ColumnDef::new(JobComments::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(JobComments::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_job_comments_job_id")
+                    .table(JobComments::Table)
+                    .col(JobComments::JobId)
+                    .if_not_exists()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(JobCommentEdits::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(JobCommentEdits::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .extra("DEFAULT gen_random_uuid()".to_string()),
+                    )
+                    .col(ColumnDef::new(JobCommentEdits::CommentId).uuid().not_null())
+                    .col(ColumnDef::new(JobCommentEdits::Body).text().not_null())
+                    .col(ColumnDef::new(JobCommentEdits::CustomerVisible).boolean().not_null())
+                    .col(ColumnDef::new(JobCommentEdits::EditedBy).uuid().not_null())
+                    .col(
+                        ColumnDef::new(JobCommentEdits::EditedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_job_comment_edits_comment_id")
+                    .table(JobCommentEdits::Table)
+                    .col(JobCommentEdits::CommentId)
+                    .if_not_exists()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(JobCommentEdits::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(JobComments::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveMigrationName)]
+pub struct AddMachineCapacity;
+
+#[async_trait::async_trait]
+impl MigrationTrait for AddMachineCapacity {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Machines::Table)
+                    .add_column_if_not_exists(ColumnDef::new(Machines::HoursPerDay).decimal().not_null().default(8))
+                    .add_column_if_not_exists(
+                        ColumnDef::new(Machines::WorkingDays).string().not_null().default("[1,2,3,4,5]"),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Machines::Table)
+                    .drop_column(Machines::HoursPerDay)
+                    .drop_column(Machines::WorkingDays)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveMigrationName)]
+pub struct CreateHolidaysTable;
+
+#[async_trait::async_trait]
+impl MigrationTrait for CreateHolidaysTable {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Holidays::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(Holidays::Date).date().not_null().primary_key())
+                    .col(ColumnDef::new(Holidays::Name).string().not_null())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Holidays::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveMigrationName)]
+pub struct CreateCostParameterVersionsTable;
+
+#[async_trait::async_trait]
+impl MigrationTrait for CreateCostParameterVersionsTable {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(CostParameterVersions::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(CostParameterVersions::Version).integer().not_null().primary_key())
+                    .col(ColumnDef::new(CostParameterVersions::Parameters).text().not_null())
+                    .col(
+                        ColumnDef::new(CostParameterVersions::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(CostParameterVersions::Table).to_owned())
+            .await
+    }
+}
+
+/// Plate cost has always been charged per plate; stored parameter versions
+/// get the field name that says so.
+#[derive(DeriveMigrationName)]
+pub struct RenamePlateCostParameter;
+
+#[async_trait::async_trait]
+impl MigrationTrait for RenamePlateCostParameter {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .exec_stmt(
+                Query::update()
+                    .table(CostParameterVersions::Table)
+                    .value(
+                        CostParameterVersions::Parameters,
+                        Expr::cust(r#"replace(parameters, '"plateCostPerJob":', '"plateCost":')"#),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .exec_stmt(
+                Query::update()
+                    .table(CostParameterVersions::Table)
+                    .value(
+                        CostParameterVersions::Parameters,
+                        Expr::cust(r#"replace(parameters, '"plateCost":', '"plateCostPerJob":')"#),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+/// Seeds the built-in equipment, with its fixed ids, into a shop that has
+/// not configured any machines, so adding one machine later does not leave
+/// the others out of routing.
+#[derive(DeriveMigrationName)]
+pub struct SeedDefaultMachines;
+
+#[async_trait::async_trait]
+impl MigrationTrait for SeedDefaultMachines {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+        let existing = Query::select().column(Machines::Id).from(Machines::Table).limit(1).to_owned();
+        if db.query_one(db.get_database_backend().build(&existing)).await?.is_some() {
+            return Ok(());
+        }
+
+        let mut insert = Query::insert();
+        insert.into_table(Machines::Table).columns([
+            Machines::Id,
+            Machines::Name,
+            Machines::Kind,
+            Machines::MakeReadyMinutes,
+            Machines::SpeedBands,
+            Machines::MachineRatePerHour,
+            Machines::OperatorRatePerHour,
+            Machines::HoursPerDay,
+            Machines::WorkingDays,
+            Machines::IsActive,
+        ]);
+        let decimal = |value: String| {
+            Decimal::from_str(&value).map_err(|e| DbErr::Custom(format!("Invalid default machine value {}: {}", value, e)))
+        };
+        for machine in default_machines() {
+            let speed_bands = serde_json::to_string(&machine.speed_bands).map_err(|e| DbErr::Custom(e.to_string()))?;
+            let working_days = serde_json::to_string(&machine.working_days).map_err(|e| DbErr::Custom(e.to_string()))?;
+            insert.values_panic([
+                machine.id.into(),
+                machine.name.into(),
+                machine.kind.to_string().into(),
+                decimal(machine.make_ready_minutes.to_string())?.into(),
+                speed_bands.into(),
+                decimal(machine.machine_rate_per_hour.to_string())?.into(),
+                decimal(machine.operator_rate_per_hour.to_string())?.into(),
+                decimal(machine.hours_per_day.to_string())?.into(),
+                working_days.into(),
+                machine.is_active.into(),
+            ]);
+        }
+        manager.exec_stmt(insert).await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let ids: Vec<_> = default_machines().into_iter().map(|machine| machine.id).collect();
+        manager
+            .exec_stmt(
+                Query::delete()
+                    .from_table(Machines::Table)
+                    .and_where(Expr::col(Machines::Id).is_in(ids))
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
 #[derive(Iden)]
 enum Users {
     Table,
@@ -214,6 +930,7 @@ enum Users {
     FirstName,
     LastName,
     IsActive,
+    PreferredCurrency,
     CreatedAt,
     UpdatedAt,
 }
@@ -257,3 +974,133 @@ enum BrandingSettings {
     CreatedAt,
     UpdatedAt,
 }
+
+#[derive(Iden)]
+enum CurrencySettings {
+    Table,
+    Id,
+    BaseCurrency,
+    DefaultCurrency,
+    SupportedCurrencies,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(Iden)]
+enum Customers {
+    Table,
+    Id,
+    Name,
+    Email,
+    Phone,
+    PreferredCurrency,
+    ProfitRate,
+    Country,
+    TaxId,
+    TaxExempt,
+    DiscountKind,
+    DiscountValue,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(Iden)]
+enum Machines {
+    Table,
+    Id,
+    Name,
+    Kind,
+    MakeReadyMinutes,
+    SpeedBands,
+    MachineRatePerHour,
+    OperatorRatePerHour,
+    HoursPerDay,
+    WorkingDays,
+    IsActive,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(Iden)]
+enum TaxSettings {
+    Table,
+    Id,
+    CompanyCountry,
+    CompanyTaxId,
+    Rates,
+    ReverseChargeCountries,
+    ReverseChargeNote,
+    ExemptionNote,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(Iden)]
+enum JobTemplates {
+    Table,
+    Id,
+    Name,
+    Description,
+    JobType,
+    Quantity,
+    Specifications,
+    Scope,
+    OwnerId,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(Iden)]
+enum JobAttachments {
+    Table,
+    Id,
+    JobId,
+    Kind,
+    Filename,
+    MimeType,
+    SizeBytes,
+    Checksum,
+    UploadedBy,
+    StorageKey,
+    CreatedAt,
+}
+
+#[derive(Iden)]
+enum JobComments {
+    Table,
+    Id,
+    JobId,
+    AuthorId,
+    Body,
+    CustomerVisible,
+    Mentions,
+    Edited,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(Iden)]
+enum JobCommentEdits {
+    Table,
+    Id,
+    CommentId,
+    Body,
+    CustomerVisible,
+    EditedBy,
+    EditedAt,
+}
+
+#[derive(Iden)]
+enum Holidays {
+    Table,
+    Date,
+    Name,
+}
+
+#[derive(Iden)]
+enum CostParameterVersions {
+    Table,
+    Version,
+    Parameters,
+    CreatedAt,
+}
@@ -0,0 +1,27 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize)]
+#[sea_orm(table_name = "machines")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: Uuid,
+    pub name: String,
+    pub kind: String,
+    pub make_ready_minutes: Decimal,
+    /// JSON array of `{maxGsm, sheetsPerHour}` bands.
+    pub speed_bands: String,
+    pub machine_rate_per_hour: Decimal,
+    pub operator_rate_per_hour: Decimal,
+    pub hours_per_day: Decimal,
+    /// JSON array of ISO weekday numbers.
+    pub working_days: String,
+    pub is_active: bool,
+    pub created_at: DateTimeWithTimeZone,
+    pub updated_at: DateTimeWithTimeZone,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
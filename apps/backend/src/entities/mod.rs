@@ -2,8 +2,28 @@ pub mod users;
 pub mod jobs;
 pub mod cost_parameters;
 pub mod branding_settings;
+pub mod currency_settings;
+pub mod customers;
+pub mod machines;
+pub mod tax_settings;
+pub mod job_templates;
+pub mod job_attachments;
+pub mod job_comments;
+pub mod job_comment_edits;
+pub mod holidays;
+pub mod cost_parameter_versions;
 
 pub use users::Entity as Users;
 pub use jobs::Entity as Jobs;
 pub use cost_parameters::Entity as CostParameters;
 pub use branding_settings::Entity as BrandingSettings;
+pub use currency_settings::Entity as CurrencySettings;
+pub use customers::Entity as Customers;
+pub use machines::Entity as Machines;
+pub use tax_settings::Entity as TaxSettings;
+pub use job_templates::Entity as JobTemplates;
+pub use job_attachments::Entity as JobAttachments;
+pub use job_comments::Entity as JobComments;
+pub use job_comment_edits::Entity as JobCommentEdits;
+pub use holidays::Entity as Holidays;
+pub use cost_parameter_versions::Entity as CostParameterVersions;
@@ -0,0 +1,112 @@
+use axum::{
+    extract::{Multipart, Path, State},
+    http::{header, HeaderMap, StatusCode},
+    response::{IntoResponse, Json, Response},
+};
+use std::str::FromStr;
+use uuid::Uuid;
+
+use crate::{
+    handlers::{auth::require_user, jobs},
+    models::{ArtworkAnalysis, AttachmentKind, JobAttachment},
+    services::{artwork_service, attachment_service::AttachmentService},
+    utils::errors::AppError,
+    AppState,
+};
+
+fn service(state: &AppState) -> AttachmentService<'_> {
+    AttachmentService::new(&state.db.connection, state.files.as_ref(), state.config.max_attachment_bytes)
+}
+
+/// Takes a multipart form with a `file` field and an optional `kind` field
+/// (`artwork`, `proof`, `reference` or `other`; defaults to `artwork`).
+pub async fn upload_attachment(
+    State(state): State<AppState>,
+    Path(job_id): Path<Uuid>,
+    headers: HeaderMap,
+    mut multipart: Multipart,
+) -> Result<(StatusCode, Json<JobAttachment>), AppError> {
+    let (user_id, _) = require_user(&headers, &state.config.jwt_secret)?;
+    jobs::find_job(job_id)?;
+
+    let mut kind = AttachmentKind::Artwork;
+    let mut file = None;
+    while let Some(field) = multipart
+        .next_field()
+        .await
+        .map_err(|e| AppError::BadRequest(e.to_string()))?
+    {
+        match field.name() {
+            Some("kind") => {
+                let value = field.text().await.map_err(|e| AppError::BadRequest(e.to_string()))?;
+                kind = AttachmentKind::from_str(&value).map_err(AppError::ValidationError)?;
+            }
+            Some("file") => {
+                let filename = field.file_name().unwrap_or_default().to_string();
+                let bytes = field.bytes().await.map_err(|e| AppError::BadRequest(e.to_string()))?;
+                file = Some((filename, bytes));
+            }
+            _ => {}
+        }
+    }
+    let (filename, bytes) = file.ok_or(AppError::BadRequest("Missing file field".to_string()))?;
+
+    let attachment = service(&state)
+        .upload(job_id, kind, &filename, bytes, Some(user_id))
+        .await?;
+    Ok((StatusCode::CREATED, Json(attachment)))
+}
+
+pub async fn list_attachments(
+    State(state): State<AppState>,
+    Path(job_id): Path<Uuid>,
+) -> Result<Json<Vec<JobAttachment>>, AppError> {
+    jobs::find_job(job_id)?;
+    let attachments = service(&state).list_for_job(job_id).await?;
+    Ok(Json(attachments))
+}
+
+pub async fn download_attachment(
+    State(state): State<AppState>,
+    Path((job_id, attachment_id)): Path<(Uuid, Uuid)>,
+    headers: HeaderMap,
+) -> Result<Response, AppError> {
+    require_user(&headers, &state.config.jwt_secret)?;
+    let (attachment, bytes) = service(&state).download(job_id, attachment_id).await?;
+
+    let headers = [
+        (header::CONTENT_TYPE, attachment.mime_type.clone()),
+        (header::CONTENT_DISPOSITION, format!("attachment; filename=\"{}\"", attachment.filename)),
+    ];
+
+    Ok((StatusCode::OK, headers, bytes).into_response())
+}
+
+/// Reads the page count, trim and bleed of a PDF attachment and suggests
+/// the job's `pages` and `paper_size` from them.
+pub async fn analyze_attachment(
+    State(state): State<AppState>,
+    Path((job_id, attachment_id)): Path<(Uuid, Uuid)>,
+) -> Result<Json<ArtworkAnalysis>, AppError> {
+    let job = jobs::find_job(job_id)?;
+    let (attachment, bytes) = service(&state).download(job_id, attachment_id).await?;
+    if attachment.mime_type != "application/pdf" {
+        return Err(AppError::ValidationError("Only PDF attachments can be analyzed".to_string()));
+    }
+
+    // Parsing a large PDF is CPU-bound
+    let analysis = tokio::task::spawn_blocking(move || artwork_service::analyze(&attachment, &bytes, &job))
+        .await
+        .map_err(|e| AppError::InternalServerError(e.to_string()))??;
+    Ok(Json(analysis))
+}
+
+pub async fn delete_attachment(
+    State(state): State<AppState>,
+    Path((job_id, attachment_id)): Path<(Uuid, Uuid)>,
+    headers: HeaderMap,
+) -> Result<StatusCode, AppError> {
+    require_user(&headers, &state.config.jwt_secret)?;
+    service(&state).delete(job_id, attachment_id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
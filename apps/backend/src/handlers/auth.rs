@@ -29,15 +29,22 @@ pub async fn login(
     State(state): State<AppState>,
     Json(payload): Json<LoginRequest>,
 ) -> Result<Json<LoginResponse>, AppError> {
-    use crate::models::{UserRole, UserInfo};
-    
-    // For demo purposes, accept any email/password combination
-    // In production, you would verify against the database
-    let user_info = UserInfo {
-        id: Uuid::new_v4(),
-        email: payload.email.clone(),
-        name: "Demo User".to_string(),
-        role: UserRole::User,
+    let user_info = match UserService::new(&state.db.connection).find_by_email(&payload.email).await? {
+        Some(user) => {
+            let valid = verify(&payload.password, &user.password_hash).unwrap_or(false);
+            if !valid || !user.is_active {
+                return Err(AppError::Unauthorized("Invalid email or password".to_string()));
+            }
+            UserInfo::from(user)
+        }
+        // For demo purposes, accept any other email/password combination
+        // as a regular user
+        None => UserInfo {
+            id: Uuid::new_v4(),
+            email: payload.email.clone(),
+            name: "Demo User".to_string(),
+            role: UserRole::User,
+        },
     };
     
     // Generate JWT token
@@ -72,33 +79,66 @@ pub async fn me(
     State(state): State<AppState>,
     headers: axum::http::HeaderMap,
 ) -> Result<Json<UserInfo>, AppError> {
-    use crate::models::{UserRole, UserInfo};
+    let claims = decode_claims(&headers, &state.config.jwt_secret)?;
+    let user_id = Uuid::parse_str(&claims.sub)
+        .map_err(|_| AppError::Unauthorized("Invalid user ID in token".to_string()))?;
+
+    let user_info = match UserService::new(&state.db.connection).find_by_id(user_id).await? {
+        Some(user) => UserInfo::from(user),
+        // Demo sessions have no stored user; the signed token carries the role
+        None => UserInfo {
+            id: user_id,
+            email: claims.email,
+            name: "Demo User".to_string(),
+            role: claims.role.parse().unwrap_or(UserRole::User),
+        },
+    };
     
-    // Extract token from Authorization header
-    let auth_header = headers
+    Ok(Json(user_info))
+}
+
+/// Decodes and validates the bearer token in the Authorization header.
+pub fn decode_claims(headers: &axum::http::HeaderMap, jwt_secret: &str) -> Result<Claims, AppError> {
+    let token = headers
         .get("Authorization")
         .and_then(|header| header.to_str().ok())
-        .and_then(|header| header.strip_prefix("Bearer "));
+        .and_then(|header| header.strip_prefix("Bearer "))
+        .ok_or(AppError::Unauthorized("Missing token".to_string()))?;
 
-    let token = auth_header.ok_or(AppError::Unauthorized("Missing token".to_string()))?;
-
-    // Decode and validate token
-    let claims = decode::<Claims>(
+    decode::<Claims>(
         token,
-        &DecodingKey::from_secret(state.config.jwt_secret.as_ref()),
+        &DecodingKey::from_secret(jwt_secret.as_ref()),
         &Validation::default(),
     )
-    .map_err(|_| AppError::Unauthorized("Invalid token".to_string()))?;
-
-    let user_info = UserInfo {
-        id: Uuid::parse_str(&claims.claims.sub)
-            .map_err(|_| AppError::Unauthorized("Invalid user ID in token".to_string()))?,
-        email: claims.claims.email,
-        name: "Demo User".to_string(),
-        role: UserRole::User,
-    };
-    
-    Ok(Json(user_info))
+    .map(|data| data.claims)
+    .map_err(|_| AppError::Unauthorized("Invalid token".to_string()))
+}
+
+/// User id of the caller, if the request carries a valid token.
+pub fn optional_user_id(headers: &axum::http::HeaderMap, jwt_secret: &str) -> Option<Uuid> {
+    decode_claims(headers, jwt_secret)
+        .ok()
+        .and_then(|claims| Uuid::parse_str(&claims.sub).ok())
+}
+
+/// User id of the caller, who must be signed in, and whether they may
+/// manage shared data.
+pub fn require_user(headers: &axum::http::HeaderMap, jwt_secret: &str) -> Result<(Uuid, bool), AppError> {
+    let claims = decode_claims(headers, jwt_secret)?;
+    let user_id = Uuid::parse_str(&claims.sub)
+        .map_err(|_| AppError::Unauthorized("Invalid user ID in token".to_string()))?;
+    let manager = matches!(claims.role.parse::<UserRole>(), Ok(UserRole::Manager | UserRole::Admin));
+    Ok((user_id, manager))
+}
+
+/// User id of the caller, who must be a manager or admin.
+pub fn require_manager(headers: &axum::http::HeaderMap, jwt_secret: &str) -> Result<Uuid, AppError> {
+    let claims = decode_claims(headers, jwt_secret)?;
+    match claims.role.parse::<UserRole>() {
+        Ok(UserRole::Manager | UserRole::Admin) => Uuid::parse_str(&claims.sub)
+            .map_err(|_| AppError::Unauthorized("Invalid user ID in token".to_string())),
+        _ => Err(AppError::Forbidden("Manager approval required".to_string())),
+    }
 }
 
 // Middleware to extract user from JWT token
@@ -107,22 +147,9 @@ pub async fn auth_middleware(
     mut request: axum::http::Request<axum::body::Body>,
     next: axum::middleware::Next,
 ) -> Result<axum::response::Response, AppError> {
-    let auth_header = request
-        .headers()
-        .get("Authorization")
-        .and_then(|header| header.to_str().ok())
-        .and_then(|header| header.strip_prefix("Bearer "));
-
-    let token = auth_header.ok_or(AppError::Unauthorized("Missing token".to_string()))?;
-
-    let claims = decode::<Claims>(
-        token,
-        &DecodingKey::from_secret(state.config.jwt_secret.as_ref()),
-        &Validation::default(),
-    )
-    .map_err(|_| AppError::Unauthorized("Invalid token".to_string()))?;
+    let claims = decode_claims(request.headers(), &state.config.jwt_secret)?;
 
-    let user_id = Uuid::parse_str(&claims.claims.sub)
+    let user_id = Uuid::parse_str(&claims.sub)
         .map_err(|_| AppError::Unauthorized("Invalid user ID in token".to_string()))?;
 
     let user_service = UserService::new(&state.db.connection);
@@ -0,0 +1,97 @@
+use axum::{
+    extract::{Path, State},
+    http::{HeaderMap, StatusCode},
+    response::Json,
+};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::{
+    handlers::{auth::require_user, jobs},
+    models::{CommentEdit, CreateCommentRequest, JobComment, UpdateCommentRequest},
+    services::comment_service::CommentService,
+    utils::errors::AppError,
+    AppState,
+};
+
+pub async fn list_comments(
+    State(state): State<AppState>,
+    Path(job_id): Path<Uuid>,
+) -> Result<Json<Vec<JobComment>>, AppError> {
+    jobs::find_job(job_id)?;
+    let comments = CommentService::new(&state.db.connection)
+        .list_for_job(job_id, false)
+        .await?;
+    Ok(Json(comments))
+}
+
+pub async fn create_comment(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(job_id): Path<Uuid>,
+    Json(payload): Json<CreateCommentRequest>,
+) -> Result<(StatusCode, Json<JobComment>), AppError> {
+    let (user_id, _) = require_user(&headers, &state.config.jwt_secret)?;
+    payload
+        .validate()
+        .map_err(|e| AppError::ValidationError(e.to_string()))?;
+    jobs::find_job(job_id)?;
+
+    let comment = CommentService::new(&state.db.connection)
+        .create_comment(job_id, user_id, payload)
+        .await?;
+    Ok((StatusCode::CREATED, Json(comment)))
+}
+
+pub async fn update_comment(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path((job_id, comment_id)): Path<(Uuid, Uuid)>,
+    Json(payload): Json<UpdateCommentRequest>,
+) -> Result<Json<JobComment>, AppError> {
+    let (user_id, _) = require_user(&headers, &state.config.jwt_secret)?;
+    payload
+        .validate()
+        .map_err(|e| AppError::ValidationError(e.to_string()))?;
+
+    let comment = CommentService::new(&state.db.connection)
+        .update_comment(job_id, comment_id, user_id, payload)
+        .await?;
+    Ok(Json(comment))
+}
+
+pub async fn comment_history(
+    State(state): State<AppState>,
+    Path((job_id, comment_id)): Path<(Uuid, Uuid)>,
+) -> Result<Json<Vec<CommentEdit>>, AppError> {
+    let edits = CommentService::new(&state.db.connection)
+        .history(job_id, comment_id)
+        .await?;
+    Ok(Json(edits))
+}
+
+pub async fn delete_comment(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path((job_id, comment_id)): Path<(Uuid, Uuid)>,
+) -> Result<StatusCode, AppError> {
+    let (user_id, manager) = require_user(&headers, &state.config.jwt_secret)?;
+
+    CommentService::new(&state.db.connection)
+        .delete_comment(job_id, comment_id, user_id, manager)
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Comments that mention the signed-in user, newest first.
+pub async fn my_mentions(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> Result<Json<Vec<JobComment>>, AppError> {
+    let (user_id, _) = require_user(&headers, &state.config.jwt_secret)?;
+
+    let comments = CommentService::new(&state.db.connection)
+        .list_mentions(user_id)
+        .await?;
+    Ok(Json(comments))
+}
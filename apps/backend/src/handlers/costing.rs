@@ -1,241 +1,331 @@
 use axum::{
-    extract::State,
+    extract::{Query, State},
+    http::HeaderMap,
     response::Json,
 };
+use bigdecimal::BigDecimal;
+use std::collections::{hash_map::Entry, HashMap};
+use std::str::FromStr;
+use uuid::Uuid;
 use validator::Validate;
 
 use crate::{
-    models::{CostCalculationRequest, CostCalculationResponse, Currency},
-    services::currency_service::CurrencyService,
+    handlers::auth::optional_user_id,
+    models::{
+        BatchCostItem, BatchCostRequest, BatchCostResponse, BudgetRequest, BudgetResponse, CostBreakdown,
+        CostCalculationQuery, CostCalculationRequest, CostCalculationResponse, Currency,
+    },
+    services::{
+        costing_service::{CostCalculationResult, CostingService, MAX_BATCH_ITEMS},
+        currency_service::CurrencyService,
+        currency_settings_service::CurrencySettingsService,
+    },
     utils::errors::AppError,
     AppState,
 };
 
 pub async fn calculate_cost(
+    State(state): State<AppState>,
+    Query(query): Query<CostCalculationQuery>,
+    headers: HeaderMap,
     Json(payload): Json<CostCalculationRequest>,
 ) -> Result<Json<CostCalculationResponse>, AppError> {
-    use crate::models::CostBreakdown;
-    use bigdecimal::BigDecimal;
-    use std::str::FromStr;
-    
-    // Basic cost calculation based on job specifications
-    let quantity = payload.quantity as f64;
-    let pages = payload.specifications.pages.unwrap_or(1) as f64;
-    
-    // Base costs per unit
-    let paper_cost_per_sheet = BigDecimal::from_str("0.10").unwrap();
-    let plate_cost_base = BigDecimal::from_str("25.00").unwrap();
-    let labor_cost_per_hour = BigDecimal::from_str("50.00").unwrap();
-    let binding_cost_per_unit = BigDecimal::from_str("0.50").unwrap();
-    
-    // Calculate individual costs
-    let paper_cost = &paper_cost_per_sheet * BigDecimal::from_str(&(quantity * pages).to_string()).unwrap();
-    let plate_cost = &plate_cost_base * BigDecimal::from_str(&(payload.specifications.colors.front_colors + payload.specifications.colors.back_colors).to_string()).unwrap();
-    let labor_hours = (quantity * pages / 1000.0).max(0.5); // Minimum 0.5 hours
-    let labor_cost = &labor_cost_per_hour * BigDecimal::from_str(&labor_hours.to_string()).unwrap();
-    let binding_cost = if payload.specifications.binding.is_some() && !payload.specifications.binding.as_ref().unwrap().is_empty() {
-        &binding_cost_per_unit * BigDecimal::from_str(&quantity.to_string()).unwrap()
-    } else {
-        BigDecimal::from_str("0.00").unwrap()
-    };
-    let finishing_cost = BigDecimal::from_str(&(payload.specifications.finishing.len() as f64 * quantity * 0.25).to_string()).unwrap();
-    
-    // Calculate subtotal
-    let subtotal = &paper_cost + &plate_cost + &labor_cost + &binding_cost + &finishing_cost;
-    
-    // Add overhead (15%)
-    let overhead = &subtotal * BigDecimal::from_str("0.15").unwrap();
-    let total_with_overhead = &subtotal + &overhead;
-    
-    // Add profit margin (20%)
-    let profit = &total_with_overhead * BigDecimal::from_str("0.20").unwrap();
-    let total_cost = &total_with_overhead + &profit;
-    
-    let unit_cost = &total_cost / BigDecimal::from_str(&quantity.to_string()).unwrap();
-    
-    let cost_breakdown = CostBreakdown {
-        paper_cost,
-        plate_cost,
-        labor_cost,
-        binding_cost,
-        finishing_cost,
-        overhead,
-    };
-    
+    let result = CostingService::new(&state.db.connection)
+        .calculate_cost(&payload.job_type, payload.quantity, &payload.specifications, payload.customer_id, &payload.terms)
+        .await?;
+
     // Handle currency conversion if requested
-    let (final_total, final_unit, final_breakdown, currency, exchange_rate) = if let Some(target_currency) = payload.currency {
-        if target_currency.code() != "USD" {
-            let currency_service = CurrencyService::new();
-            
-            // Convert all amounts
-            let total_f64: f64 = total_cost.to_string().parse().unwrap_or(0.0);
-            let unit_f64: f64 = unit_cost.to_string().parse().unwrap_or(0.0);
-            
-            let converted_total = currency_service.convert_amount(total_f64, Currency::USD, target_currency.clone()).await?;
-            let converted_unit = currency_service.convert_amount(unit_f64, Currency::USD, target_currency.clone()).await?;
-            
-            // Convert cost breakdown
-            let paper_f64: f64 = cost_breakdown.paper_cost.to_string().parse().unwrap_or(0.0);
-            let plate_f64: f64 = cost_breakdown.plate_cost.to_string().parse().unwrap_or(0.0);
-            let labor_f64: f64 = cost_breakdown.labor_cost.to_string().parse().unwrap_or(0.0);
-            let binding_f64: f64 = cost_breakdown.binding_cost.to_string().parse().unwrap_or(0.0);
-            let finishing_f64: f64 = cost_breakdown.finishing_cost.to_string().parse().unwrap_or(0.0);
-            let overhead_f64: f64 = cost_breakdown.overhead.to_string().parse().unwrap_or(0.0);
-            
-            let converted_paper = currency_service.convert_amount(paper_f64, Currency::USD, target_currency.clone()).await?;
-            let converted_plate = currency_service.convert_amount(plate_f64, Currency::USD, target_currency.clone()).await?;
-            let converted_labor = currency_service.convert_amount(labor_f64, Currency::USD, target_currency.clone()).await?;
-            let converted_binding = currency_service.convert_amount(binding_f64, Currency::USD, target_currency.clone()).await?;
-            let converted_finishing = currency_service.convert_amount(finishing_f64, Currency::USD, target_currency.clone()).await?;
-            let converted_overhead = currency_service.convert_amount(overhead_f64, Currency::USD, target_currency.clone()).await?;
-            
-            let converted_breakdown = CostBreakdown {
-                paper_cost: BigDecimal::from_str(&converted_paper.to_string()).unwrap(),
-                plate_cost: BigDecimal::from_str(&converted_plate.to_string()).unwrap(),
-                labor_cost: BigDecimal::from_str(&converted_labor.to_string()).unwrap(),
-                binding_cost: BigDecimal::from_str(&converted_binding.to_string()).unwrap(),
-                finishing_cost: BigDecimal::from_str(&converted_finishing.to_string()).unwrap(),
-                overhead: BigDecimal::from_str(&converted_overhead.to_string()).unwrap(),
-            };
-            
-            // Get exchange rate
-            let rates = currency_service.get_exchange_rates().await?;
-            let rate = rates.rates.get(target_currency.code()).copied().unwrap_or(1.0);
-            
-            (
-                BigDecimal::from_str(&converted_total.to_string()).unwrap(),
-                BigDecimal::from_str(&converted_unit.to_string()).unwrap(),
-                converted_breakdown,
-                Some(target_currency),
-                Some(rate)
-            )
-        } else {
-            (total_cost, unit_cost, cost_breakdown, Some(Currency::USD), Some(1.0))
-        }
-    } else {
-        (total_cost, unit_cost, cost_breakdown, None, None)
-    };
+    let (base_currency, target_currency) = resolve_currencies(&state, &headers, payload.currency, payload.customer_id).await?;
+    let exchange_rate = exchange_rate(base_currency, target_currency).await?;
 
-    let response = CostCalculationResponse {
-        total_cost: final_total,
-        unit_cost: final_unit,
-        cost_breakdown: final_breakdown,
-        estimated_delivery_days: estimate_delivery_days(&payload.job_type, payload.quantity),
-        currency,
-        exchange_rate,
-    };
-    
-    Ok(Json(response))
+    Ok(Json(to_response(result, target_currency, exchange_rate, query.explain.unwrap_or(false))))
+}
+
+/// Prices up to `MAX_BATCH_ITEMS` quotes against one snapshot of parameters
+/// and exchange rates. Items that cannot be priced report their error in
+/// place instead of failing the batch.
+pub async fn calculate_batch(
+    State(state): State<AppState>,
+    Query(query): Query<CostCalculationQuery>,
+    headers: HeaderMap,
+    Json(payload): Json<BatchCostRequest>,
+) -> Result<Json<BatchCostResponse>, AppError> {
+    if payload.items.is_empty() {
+        return Err(AppError::ValidationError("A batch needs at least one item".to_string()));
+    }
+    // Checked before any currency lookups, which cost round trips per item
+    if payload.items.len() > MAX_BATCH_ITEMS {
+        return Err(AppError::ValidationError(format!(
+            "A batch can price at most {} items",
+            MAX_BATCH_ITEMS
+        )));
+    }
+
+    // One display currency per currency and customer, one rate per currency.
+    // A currency that cannot be shown fails only the items asking for it.
+    let mut display_currencies: HashMap<(Option<Currency>, Option<Uuid>), Result<Currency, String>> = HashMap::new();
+    let mut rates: HashMap<Currency, f64> = HashMap::new();
+    let mut targets = Vec::with_capacity(payload.items.len());
+    for item in &payload.items {
+        let key = (item.currency, item.customer_id);
+        let target = match display_currencies.get(&key) {
+            Some(target) => target.clone(),
+            None => {
+                let target = match display_currency(&state, &headers, item.currency, item.customer_id, &mut rates).await {
+                    Ok(currency) => Ok(currency),
+                    Err(AppError::ValidationError(message) | AppError::BadRequest(message) | AppError::NotFound(message)) => {
+                        Err(message)
+                    }
+                    Err(error) => return Err(error),
+                };
+                display_currencies.insert(key, target.clone());
+                target
+            }
+        };
+        targets.push(target);
+    }
+
+    let results = CostingService::new(&state.db.connection)
+        .calculate_batch(payload.items)
+        .await?;
+
+    let explain = query.explain.unwrap_or(false);
+    let parameters_version = results
+        .iter()
+        .find_map(|result| result.as_ref().ok().map(|result| result.parameters_version));
+    let items: Vec<BatchCostItem> = results
+        .into_iter()
+        .zip(targets)
+        .enumerate()
+        .map(|(index, (result, target))| match (result, target) {
+            (Ok(result), Ok(currency)) => BatchCostItem {
+                index,
+                result: Some(to_response(result, currency, rates[&currency], explain)),
+                error: None,
+            },
+            (Err(error), _) => BatchCostItem {
+                index,
+                result: None,
+                error: Some(item_error(error)),
+            },
+            (Ok(_), Err(message)) => BatchCostItem {
+                index,
+                result: None,
+                error: Some(message),
+            },
+        })
+        .collect();
+    let succeeded = items.iter().filter(|item| item.result.is_some()).count();
+
+    Ok(Json(BatchCostResponse {
+        parameters_version,
+        succeeded,
+        failed: items.len() - succeeded,
+        items,
+    }))
 }
 
 pub async fn preview_cost(
+    state: State<AppState>,
+    query: Query<CostCalculationQuery>,
+    headers: HeaderMap,
     Json(payload): Json<CostCalculationRequest>,
 ) -> Result<Json<CostCalculationResponse>, AppError> {
     // Same as calculate_cost for now
-    calculate_cost(Json(payload)).await
+    calculate_cost(state, query, headers, Json(payload)).await
 }
 
 pub async fn quick_calculate(
+    State(state): State<AppState>,
+    headers: HeaderMap,
     Json(payload): Json<CostCalculationRequest>,
 ) -> Result<Json<CostCalculationResponse>, AppError> {
     // Quick calculation with simplified logic
-    use crate::models::CostBreakdown;
-    use bigdecimal::BigDecimal;
-    use std::str::FromStr;
-    
     let quantity = payload.quantity as f64;
     let base_cost = quantity * 0.5; // Simple base cost calculation
     
     let cost_breakdown = CostBreakdown {
         paper_cost: BigDecimal::from_str(&(base_cost * 0.4).to_string()).unwrap(),
         plate_cost: BigDecimal::from_str(&(base_cost * 0.2).to_string()).unwrap(),
+        ink_cost: BigDecimal::from(0),
         labor_cost: BigDecimal::from_str(&(base_cost * 0.2).to_string()).unwrap(),
         binding_cost: BigDecimal::from_str(&(base_cost * 0.1).to_string()).unwrap(),
         finishing_cost: BigDecimal::from_str(&(base_cost * 0.05).to_string()).unwrap(),
         overhead: BigDecimal::from_str(&(base_cost * 0.05).to_string()).unwrap(),
+        lines: Vec::new(),
+        production_hours: 0.0,
+        machine_hours: Vec::new(),
     };
     
     let total_cost = BigDecimal::from_str(&base_cost.to_string()).unwrap();
     let unit_cost = &total_cost / BigDecimal::from_str(&quantity.to_string()).unwrap();
     
     // Handle currency conversion for quick calculation too
-    let (final_total, final_unit, final_breakdown, currency, exchange_rate) = if let Some(target_currency) = payload.currency {
-        if target_currency.code() != "USD" {
-            let currency_service = CurrencyService::new();
-            let total_f64: f64 = total_cost.to_string().parse().unwrap_or(0.0);
-            let unit_f64: f64 = unit_cost.to_string().parse().unwrap_or(0.0);
-            
-            let converted_total = currency_service.convert_amount(total_f64, Currency::USD, target_currency.clone()).await?;
-            let converted_unit = currency_service.convert_amount(unit_f64, Currency::USD, target_currency.clone()).await?;
-            
-            // Convert cost breakdown components
-            let paper_f64: f64 = cost_breakdown.paper_cost.to_string().parse().unwrap_or(0.0);
-            let plate_f64: f64 = cost_breakdown.plate_cost.to_string().parse().unwrap_or(0.0);
-            let labor_f64: f64 = cost_breakdown.labor_cost.to_string().parse().unwrap_or(0.0);
-            let binding_f64: f64 = cost_breakdown.binding_cost.to_string().parse().unwrap_or(0.0);
-            let finishing_f64: f64 = cost_breakdown.finishing_cost.to_string().parse().unwrap_or(0.0);
-            let overhead_f64: f64 = cost_breakdown.overhead.to_string().parse().unwrap_or(0.0);
-            
-            let converted_breakdown = CostBreakdown {
-                paper_cost: BigDecimal::from_str(&currency_service.convert_amount(paper_f64, Currency::USD, target_currency.clone()).await?.to_string()).unwrap(),
-                plate_cost: BigDecimal::from_str(&currency_service.convert_amount(plate_f64, Currency::USD, target_currency.clone()).await?.to_string()).unwrap(),
-                labor_cost: BigDecimal::from_str(&currency_service.convert_amount(labor_f64, Currency::USD, target_currency.clone()).await?.to_string()).unwrap(),
-                binding_cost: BigDecimal::from_str(&currency_service.convert_amount(binding_f64, Currency::USD, target_currency.clone()).await?.to_string()).unwrap(),
-                finishing_cost: BigDecimal::from_str(&currency_service.convert_amount(finishing_f64, Currency::USD, target_currency.clone()).await?.to_string()).unwrap(),
-                overhead: BigDecimal::from_str(&currency_service.convert_amount(overhead_f64, Currency::USD, target_currency.clone()).await?.to_string()).unwrap(),
-            };
-            
-            let rates = currency_service.get_exchange_rates().await?;
-            let rate = rates.rates.get(target_currency.code()).copied().unwrap_or(1.0);
-            
-            (
-                BigDecimal::from_str(&converted_total.to_string()).unwrap(),
-                BigDecimal::from_str(&converted_unit.to_string()).unwrap(),
-                converted_breakdown,
-                Some(target_currency),
-                Some(rate)
-            )
-        } else {
-            (total_cost, unit_cost, cost_breakdown, Some(Currency::USD), Some(1.0))
-        }
-    } else {
-        (total_cost, unit_cost, cost_breakdown, None, None)
-    };
+    let (base_currency, target_currency) = resolve_currencies(&state, &headers, payload.currency, payload.customer_id).await?;
+    let (final_total, final_unit, final_breakdown, exchange_rate) =
+        convert_to_currency(base_currency, target_currency, total_cost, unit_cost, cost_breakdown).await?;
 
     let response = CostCalculationResponse {
         total_cost: final_total,
         unit_cost: final_unit,
         cost_breakdown: final_breakdown,
         estimated_delivery_days: 3,
-        currency,
-        exchange_rate,
+        pricing: None,
+        tax: None,
+        process: None,
+        alternatives: Vec::new(),
+        crossover_quantity: None,
+        parameters_version: None,
+        currency: Some(target_currency),
+        exchange_rate: Some(exchange_rate),
     };
     
     Ok(Json(response))
 }
 
-fn estimate_delivery_days(job_type: &crate::models::JobType, quantity: i32) -> i32 {
-    use crate::models::JobType;
-    
-    let base_days = match job_type {
-        JobType::BusinessCard => 1,
-        JobType::Flyer => 2,
-        JobType::Brochure => 3,
-        JobType::Book => 5,
-        JobType::Poster => 2,
-        JobType::Banner => 3,
-        JobType::Sticker => 2,
-        JobType::Custom => 5,
-    };
+/// Searches quantities, and optionally other stocks and finishes, for the
+/// options that fit a target price given in any enabled currency.
+pub async fn fit_budget(
+    State(state): State<AppState>,
+    Query(query): Query<CostCalculationQuery>,
+    headers: HeaderMap,
+    Json(payload): Json<BudgetRequest>,
+) -> Result<Json<BudgetResponse>, AppError> {
+    if payload.budget <= 0 {
+        return Err(AppError::ValidationError("Budget must be greater than zero".to_string()));
+    }
+
+    let (base_currency, budget_currency) =
+        resolve_currencies(&state, &headers, payload.currency, payload.customer_id).await?;
+    let exchange_rate = exchange_rate(base_currency, budget_currency).await?;
+    let factor = BigDecimal::from_str(&exchange_rate.to_string()).unwrap();
+
+    // The engine prices in the base currency
+    let base_budget = &payload.budget / &factor;
+    let options = CostingService::new(&state.db.connection)
+        .fit_budget(&payload, &base_budget)
+        .await?;
+
+    let explain = query.explain.unwrap_or(false);
+    let options = options
+        .iter()
+        .map(|option| {
+            let mut option = option.scaled(&factor);
+            if !explain {
+                option.cost_breakdown = option.cost_breakdown.without_explanations();
+            }
+            option
+        })
+        .collect();
+
+    Ok(Json(BudgetResponse {
+        budget: payload.budget,
+        currency: budget_currency,
+        exchange_rate,
+        options,
+    }))
+}
 
-    // Add extra days for large quantities
-    let quantity_factor = match quantity {
-        0..=100 => 0,
-        101..=500 => 1,
-        501..=1000 => 2,
-        1001..=5000 => 3,
-        _ => 5,
+/// Company base currency and the currency the caller should see, falling
+/// back from the request through customer, user and company defaults.
+async fn resolve_currencies(
+    state: &AppState,
+    headers: &HeaderMap,
+    requested: Option<Currency>,
+    customer_id: Option<Uuid>,
+) -> Result<(Currency, Currency), AppError> {
+    let settings_service = CurrencySettingsService::new(&state.db.connection, &state.config);
+    let base_currency = settings_service.get_settings().await?.base_currency;
+    let user_id = optional_user_id(headers, &state.config.jwt_secret);
+    let target_currency = settings_service
+        .resolve_display_currency(requested, customer_id, user_id)
+        .await?;
+
+    Ok((base_currency, target_currency))
+}
+
+/// Display currency for a batch item, with its rate added to `rates`.
+async fn display_currency(
+    state: &AppState,
+    headers: &HeaderMap,
+    requested: Option<Currency>,
+    customer_id: Option<Uuid>,
+    rates: &mut HashMap<Currency, f64>,
+) -> Result<Currency, AppError> {
+    let (base_currency, target_currency) = resolve_currencies(state, headers, requested, customer_id).await?;
+    if let Entry::Vacant(entry) = rates.entry(target_currency) {
+        entry.insert(exchange_rate(base_currency, target_currency).await?);
+    }
+    Ok(target_currency)
+}
+
+/// Rate from the company base currency to the display currency.
+async fn exchange_rate(base_currency: Currency, target_currency: Currency) -> Result<f64, AppError> {
+    if target_currency == base_currency {
+        return Ok(1.0);
+    }
+    CurrencyService::new().get_rate(base_currency, target_currency).await
+}
+
+/// Response for a result priced in the base currency, shown in `currency`.
+fn to_response(
+    result: CostCalculationResult,
+    currency: Currency,
+    exchange_rate: f64,
+    explain: bool,
+) -> CostCalculationResponse {
+    let factor = BigDecimal::from_str(&exchange_rate.to_string()).unwrap();
+    let cost_breakdown = if explain {
+        result.cost_breakdown
+    } else {
+        result.cost_breakdown.without_explanations()
     };
 
-    base_days + quantity_factor
+    CostCalculationResponse {
+        total_cost: &result.total_cost * &factor,
+        unit_cost: &result.unit_cost * &factor,
+        cost_breakdown: cost_breakdown.scaled(&factor),
+        estimated_delivery_days: result.estimated_delivery_days,
+        pricing: Some(result.pricing.scaled(&factor)),
+        tax: Some(result.tax.scaled(&factor)),
+        process: Some(result.process),
+        alternatives: result.alternatives.iter().map(|alternative| alternative.scaled(&factor)).collect(),
+        crossover_quantity: result.crossover_quantity,
+        parameters_version: Some(result.parameters_version),
+        currency: Some(currency),
+        exchange_rate: Some(exchange_rate),
+    }
+}
+
+/// Message for an item that could not be priced.
+fn item_error(error: AppError) -> String {
+    match error {
+        AppError::ValidationError(message) | AppError::BadRequest(message) | AppError::NotFound(message) => message,
+        other => other.to_string(),
+    }
+}
+
+/// Converts a result computed in the company base currency into the
+/// display currency.
+async fn convert_to_currency(
+    base_currency: Currency,
+    target_currency: Currency,
+    total_cost: BigDecimal,
+    unit_cost: BigDecimal,
+    cost_breakdown: CostBreakdown,
+) -> Result<(BigDecimal, BigDecimal, CostBreakdown, f64), AppError> {
+    if target_currency == base_currency {
+        return Ok((total_cost, unit_cost, cost_breakdown, 1.0));
+    }
+
+    let currency_service = CurrencyService::new();
+    let rate = currency_service.get_rate(base_currency, target_currency).await?;
+    let factor = BigDecimal::from_str(&rate.to_string()).unwrap();
+
+    Ok((
+        &total_cost * &factor,
+        &unit_cost * &factor,
+        cost_breakdown.scaled(&factor),
+        rate,
+    ))
 }
@@ -1,15 +1,17 @@
 use axum::{
-    extract::Query,
-    http::StatusCode,
+    extract::{Query, State},
+    http::HeaderMap,
     response::Json,
-    Extension,
 };
 use serde::Deserialize;
+use uuid::Uuid;
 
 use crate::{
-    models::currency::{Currency, CurrencyConversionRequest, CurrencyConversionResponse, CurrencySettings, ExchangeRates},
-    services::currency_service::CurrencyService,
+    handlers::auth::{decode_claims, require_manager},
+    models::currency::{Currency, CurrencyConversionRequest, CurrencyConversionResponse, CurrencyInfo, CurrencyPreference, CurrencySettings, ExchangeRates, UpdateCurrencySettingsRequest, CURRENCY_REGISTRY},
+    services::{currency_service::CurrencyService, currency_settings_service::CurrencySettingsService, user_service::UserService},
     utils::errors::AppError,
+    AppState,
 };
 
 #[derive(Deserialize)]
@@ -19,14 +21,27 @@ pub struct ConversionQuery {
     to: String,
 }
 
-pub async fn get_supported_currencies() -> Result<Json<Vec<Currency>>, AppError> {
-    let currencies = CurrencyService::get_supported_currencies();
-    Ok(Json(currencies))
+pub async fn get_supported_currencies(
+    State(state): State<AppState>,
+) -> Result<Json<Vec<Currency>>, AppError> {
+    let settings = CurrencySettingsService::new(&state.db.connection, &state.config)
+        .get_settings()
+        .await?;
+    Ok(Json(settings.supported_currencies))
 }
 
-pub async fn get_exchange_rates() -> Result<Json<ExchangeRates>, AppError> {
+pub async fn get_currency_registry() -> Result<Json<Vec<CurrencyInfo>>, AppError> {
+    Ok(Json(CURRENCY_REGISTRY.to_vec()))
+}
+
+pub async fn get_exchange_rates(
+    State(state): State<AppState>,
+) -> Result<Json<ExchangeRates>, AppError> {
+    let settings = CurrencySettingsService::new(&state.db.connection, &state.config)
+        .get_settings()
+        .await?;
     let service = CurrencyService::new();
-    let rates = service.get_exchange_rates().await?;
+    let rates = service.get_exchange_rates(settings.base_currency).await?;
     Ok(Json(rates))
 }
 
@@ -34,36 +49,78 @@ pub async fn convert_currency(
     Query(query): Query<ConversionQuery>,
 ) -> Result<Json<CurrencyConversionResponse>, AppError> {
     let service = CurrencyService::new();
-    
+
     // Parse currency codes
     let from_currency = parse_currency(&query.from)?;
     let to_currency = parse_currency(&query.to)?;
-    
+
     let request = CurrencyConversionRequest {
         amount: query.amount,
         from_currency,
         to_currency,
     };
-    
+
     let response = service.convert_currency(request).await?;
     Ok(Json(response))
 }
 
-pub async fn get_currency_settings() -> Result<Json<CurrencySettings>, AppError> {
-    // Return default currency settings
-    Ok(Json(CurrencySettings {
-        default_currency: Currency::USD,
-        supported_currencies: CurrencyService::get_supported_currencies(),
-    }))
+pub async fn get_currency_settings(
+    State(state): State<AppState>,
+) -> Result<Json<CurrencySettings>, AppError> {
+    let settings = CurrencySettingsService::new(&state.db.connection, &state.config)
+        .get_settings()
+        .await?;
+    Ok(Json(settings))
 }
 
-fn parse_currency(code: &str) -> Result<Currency, AppError> {
-    match code.to_uppercase().as_str() {
-        "USD" => Ok(Currency::USD),
-        "XAF" | "FCFA" => Ok(Currency::FCFA),
-        "EUR" => Ok(Currency::EUR),
-        "GBP" => Ok(Currency::GBP),
-        "CAD" => Ok(Currency::CAD),
-        _ => Err(AppError::BadRequest(format!("Unsupported currency: {}", code))),
+pub async fn update_currency_settings(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(payload): Json<UpdateCurrencySettingsRequest>,
+) -> Result<Json<CurrencySettings>, AppError> {
+    require_manager(&headers, &state.config.jwt_secret)?;
+    let settings = CurrencySettingsService::new(&state.db.connection, &state.config)
+        .update_settings(payload)
+        .await?;
+    Ok(Json(settings))
+}
+
+pub async fn get_currency_preference(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> Result<Json<CurrencyPreference>, AppError> {
+    let user_id = current_user_id(&state, &headers)?;
+    let currency = UserService::new(&state.db.connection)
+        .get_preferred_currency(user_id)
+        .await?;
+    Ok(Json(CurrencyPreference { currency }))
+}
+
+pub async fn update_currency_preference(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(payload): Json<CurrencyPreference>,
+) -> Result<Json<CurrencyPreference>, AppError> {
+    let user_id = current_user_id(&state, &headers)?;
+
+    if let Some(currency) = payload.currency {
+        CurrencySettingsService::new(&state.db.connection, &state.config)
+            .ensure_supported(currency)
+            .await?;
     }
+
+    UserService::new(&state.db.connection)
+        .set_preferred_currency(user_id, payload.currency)
+        .await?;
+    Ok(Json(payload))
+}
+
+fn current_user_id(state: &AppState, headers: &HeaderMap) -> Result<Uuid, AppError> {
+    let claims = decode_claims(headers, &state.config.jwt_secret)?;
+    Uuid::parse_str(&claims.sub)
+        .map_err(|_| AppError::Unauthorized("Invalid user ID in token".to_string()))
+}
+
+fn parse_currency(code: &str) -> Result<Currency, AppError> {
+    code.parse().map_err(AppError::BadRequest)
 }
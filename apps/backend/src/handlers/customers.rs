@@ -0,0 +1,72 @@
+use axum::{
+    extract::{Path, State},
+    response::Json,
+};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::{
+    models::{CreateCustomerRequest, Customer, UpdateCustomerRequest},
+    services::{currency_settings_service::CurrencySettingsService, customer_service::CustomerService},
+    utils::errors::AppError,
+    AppState,
+};
+
+pub async fn list_customers(
+    State(state): State<AppState>,
+) -> Result<Json<Vec<Customer>>, AppError> {
+    let customers = CustomerService::new(&state.db.connection).list_customers().await?;
+    Ok(Json(customers))
+}
+
+pub async fn create_customer(
+    State(state): State<AppState>,
+    Json(payload): Json<CreateCustomerRequest>,
+) -> Result<Json<Customer>, AppError> {
+    payload
+        .validate()
+        .map_err(|e| AppError::ValidationError(e.to_string()))?;
+
+    if let Some(currency) = payload.preferred_currency {
+        CurrencySettingsService::new(&state.db.connection, &state.config)
+            .ensure_supported(currency)
+            .await?;
+    }
+
+    let customer = CustomerService::new(&state.db.connection)
+        .create_customer(payload)
+        .await?;
+    Ok(Json(customer))
+}
+
+pub async fn get_customer(
+    State(state): State<AppState>,
+    Path(customer_id): Path<Uuid>,
+) -> Result<Json<Customer>, AppError> {
+    CustomerService::new(&state.db.connection)
+        .find_by_id(customer_id)
+        .await?
+        .map(Json)
+        .ok_or(AppError::NotFound("Customer not found".to_string()))
+}
+
+pub async fn update_customer(
+    State(state): State<AppState>,
+    Path(customer_id): Path<Uuid>,
+    Json(payload): Json<UpdateCustomerRequest>,
+) -> Result<Json<Customer>, AppError> {
+    payload
+        .validate()
+        .map_err(|e| AppError::ValidationError(e.to_string()))?;
+
+    if let Some(currency) = payload.preferred_currency {
+        CurrencySettingsService::new(&state.db.connection, &state.config)
+            .ensure_supported(currency)
+            .await?;
+    }
+
+    let customer = CustomerService::new(&state.db.connection)
+        .update_customer(customer_id, payload)
+        .await?;
+    Ok(Json(customer))
+}
@@ -1,25 +1,39 @@
 use axum::{
-    extract::{Path, State},
+    extract::{Path, Query, State},
     http::{header, StatusCode},
     response::{IntoResponse, Response},
     Extension,
 };
 use printpdf::*;
+use qrcode::{Color as QrColor, QrCode};
 use rust_xlsxwriter::{Workbook, Worksheet, Format};
 use uuid::Uuid;
 
 use crate::{
-    models::UserInfo,
-    services::{job_service::JobService, branding_service::BrandingService},
+    handlers::jobs,
+    models::{CostCategory, Currency, Job, JobComment, JobStatus, QuoteExportQuery, UserInfo},
+    services::{
+        job_service::JobService, branding_service::BrandingService, comment_service::CommentService,
+        currency_settings_service::CurrencySettingsService,
+    },
     utils::errors::AppError,
     AppState,
 };
 
 pub async fn export_pdf(
+    State(state): State<AppState>,
     Path(job_id): Path<Uuid>,
+    Query(query): Query<QuoteExportQuery>,
 ) -> Result<Response, AppError> {
-    // Placeholder implementation
-    let pdf_data = b"PDF placeholder data".to_vec();
+    let job = jobs::find_job(job_id)?;
+    let branding = BrandingService::new(&state.db.connection).get_current_branding().await?;
+    let comments = if query.include_comments {
+        CommentService::new(&state.db.connection).list_for_job(job_id, true).await?
+    } else {
+        Vec::new()
+    };
+    let currency = base_currency(&state).await?;
+    let pdf_data = generate_job_pdf(&job, &branding, &comments, currency)?;
 
     let headers = [
         (header::CONTENT_TYPE, "application/pdf"),
@@ -29,11 +43,40 @@ pub async fn export_pdf(
     Ok((StatusCode::OK, headers, pdf_data).into_response())
 }
 
+/// Shop-floor work order for an approved job: specifications, stock,
+/// imposition, route and finishing, with no prices, and a QR code that
+/// opens the job.
+pub async fn export_ticket(
+    State(state): State<AppState>,
+    Path(job_id): Path<Uuid>,
+) -> Result<Response, AppError> {
+    let job = jobs::find_job(job_id)?;
+    if !matches!(job.status, JobStatus::Approved | JobStatus::InProduction | JobStatus::Completed) {
+        return Err(AppError::ValidationError("A job ticket can only be printed once the job is approved".to_string()));
+    }
+    let branding = BrandingService::new(&state.db.connection).get_current_branding().await?;
+    let link = match &state.config.app_url {
+        Some(app_url) => format!("{}/jobs/{}", app_url, job.id),
+        None => job.id.to_string(),
+    };
+    let pdf_data = generate_job_ticket(&job, &branding, &link)?;
+
+    let headers = [
+        (header::CONTENT_TYPE, "application/pdf"),
+        (header::CONTENT_DISPOSITION, &format!("attachment; filename=\"ticket_{}.pdf\"", job_id)),
+    ];
+
+    Ok((StatusCode::OK, headers, pdf_data).into_response())
+}
+
 pub async fn export_excel(
+    State(state): State<AppState>,
     Path(job_id): Path<Uuid>,
 ) -> Result<Response, AppError> {
-    // Placeholder implementation
-    let excel_data = b"Excel placeholder data".to_vec();
+    let job = jobs::find_job(job_id)?;
+    let branding = BrandingService::new(&state.db.connection).get_current_branding().await?;
+    let currency = base_currency(&state).await?;
+    let excel_data = generate_job_excel(&job, &branding, currency)?;
 
     let headers = [
         (header::CONTENT_TYPE, "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet"),
@@ -43,12 +86,23 @@ pub async fn export_excel(
     Ok((StatusCode::OK, headers, excel_data).into_response())
 }
 
+/// Currency every stored job is priced in.
+async fn base_currency(state: &AppState) -> Result<Currency, AppError> {
+    let settings = CurrencySettingsService::new(&state.db.connection, &state.config).get_settings().await?;
+    Ok(settings.base_currency)
+}
+
+/// Characters of a note that fit on one line at 10pt.
+const NOTE_LINE_CHARS: usize = 90;
+
 fn generate_job_pdf(
     job: &crate::models::Job,
     branding: &crate::models::BrandingSettings,
+    comments: &[JobComment],
+    currency: Currency,
 ) -> Result<Vec<u8>, AppError> {
     let (doc, page1, layer1) = PdfDocument::new(&format!("Quote - {}", job.title), Mm(210.0), Mm(297.0), "Layer 1");
-    let current_layer = doc.get_page(page1).get_layer(layer1);
+    let mut current_layer = doc.get_page(page1).get_layer(layer1);
 
     // Add company header
     let font = doc.add_builtin_font(BuiltinFont::HelveticaBold)
@@ -71,37 +125,340 @@ fn generate_job_pdf(
     current_layer.use_text(&format!("Quantity: {}", job.quantity), 12.0, Mm(20.0), Mm(y_pos), &regular_font);
     y_pos -= 10.0;
     
-    current_layer.use_text(&format!("Total Cost: ${:.2}", job.total_cost), 12.0, Mm(20.0), Mm(y_pos), &regular_font);
+    current_layer.use_text(&format!("Total Cost: {}", money(&job.total_cost, currency)), 12.0, Mm(20.0), Mm(y_pos), &regular_font);
     y_pos -= 10.0;
     
-    current_layer.use_text(&format!("Unit Cost: ${:.2}", job.unit_cost), 12.0, Mm(20.0), Mm(y_pos), &regular_font);
+    current_layer.use_text(&format!("Unit Cost: {}", money(&job.unit_cost, currency)), 12.0, Mm(20.0), Mm(y_pos), &regular_font);
     y_pos -= 20.0;
 
     // Add cost breakdown
     current_layer.use_text("Cost Breakdown:", 14.0, Mm(20.0), Mm(y_pos), &font);
     y_pos -= 15.0;
 
-    current_layer.use_text(&format!("Paper Cost: ${:.2}", job.cost_breakdown.paper_cost), 10.0, Mm(25.0), Mm(y_pos), &regular_font);
+    current_layer.use_text(&format!("Paper Cost: {}", money(&job.cost_breakdown.paper_cost, currency)), 10.0, Mm(25.0), Mm(y_pos), &regular_font);
     y_pos -= 8.0;
     
-    current_layer.use_text(&format!("Plate Cost: ${:.2}", job.cost_breakdown.plate_cost), 10.0, Mm(25.0), Mm(y_pos), &regular_font);
+    current_layer.use_text(&format!("Plate Cost: {}", money(&job.cost_breakdown.plate_cost, currency)), 10.0, Mm(25.0), Mm(y_pos), &regular_font);
     y_pos -= 8.0;
     
-    current_layer.use_text(&format!("Labor Cost: ${:.2}", job.cost_breakdown.labor_cost), 10.0, Mm(25.0), Mm(y_pos), &regular_font);
+    current_layer.use_text(&format!("Labor Cost: {}", money(&job.cost_breakdown.labor_cost, currency)), 10.0, Mm(25.0), Mm(y_pos), &regular_font);
     y_pos -= 8.0;
     
-    current_layer.use_text(&format!("Binding Cost: ${:.2}", job.cost_breakdown.binding_cost), 10.0, Mm(25.0), Mm(y_pos), &regular_font);
+    current_layer.use_text(&format!("Binding Cost: {}", money(&job.cost_breakdown.binding_cost, currency)), 10.0, Mm(25.0), Mm(y_pos), &regular_font);
     y_pos -= 8.0;
     
-    current_layer.use_text(&format!("Overhead: ${:.2}", job.cost_breakdown.overhead), 10.0, Mm(25.0), Mm(y_pos), &regular_font);
+    current_layer.use_text(&format!("Overhead: {}", money(&job.cost_breakdown.overhead, currency)), 10.0, Mm(25.0), Mm(y_pos), &regular_font);
+    y_pos -= 15.0;
+
+    // Discounts and surcharges
+    let adjustments = job.pricing.as_ref().map(|pricing| pricing.adjustments.as_slice()).unwrap_or_default();
+    if !adjustments.is_empty() {
+        ensure_room(&doc, &mut current_layer, &mut y_pos);
+        current_layer.use_text("Adjustments:", 14.0, Mm(20.0), Mm(y_pos), &font);
+        y_pos -= 15.0;
+
+        for adjustment in adjustments {
+            ensure_room(&doc, &mut current_layer, &mut y_pos);
+            current_layer.use_text(
+                &format!("{}: {}", adjustment.description, money(&adjustment.amount, currency)),
+                10.0,
+                Mm(25.0),
+                Mm(y_pos),
+                &regular_font,
+            );
+            y_pos -= 8.0;
+        }
+        y_pos -= 7.0;
+    }
+
+    // Tax summary
+    if let Some(tax) = &job.tax {
+        ensure_room(&doc, &mut current_layer, &mut y_pos);
+        current_layer.use_text("Tax Summary:", 14.0, Mm(20.0), Mm(y_pos), &font);
+        y_pos -= 15.0;
+
+        ensure_room(&doc, &mut current_layer, &mut y_pos);
+        current_layer.use_text(&format!("Net: {}", money(&tax.net, currency)), 10.0, Mm(25.0), Mm(y_pos), &regular_font);
+        y_pos -= 8.0;
+
+        for line in &tax.lines {
+            ensure_room(&doc, &mut current_layer, &mut y_pos);
+            current_layer.use_text(
+                &format!("{} ({}): {}", line.name, percentage(&line.rate), money(&line.amount, currency)),
+                10.0,
+                Mm(25.0),
+                Mm(y_pos),
+                &regular_font,
+            );
+            y_pos -= 8.0;
+        }
+
+        ensure_room(&doc, &mut current_layer, &mut y_pos);
+        current_layer.use_text(&format!("Gross: {}", money(&tax.gross, currency)), 10.0, Mm(25.0), Mm(y_pos), &font);
+        y_pos -= 10.0;
+
+        if let Some(note) = &tax.note {
+            ensure_room(&doc, &mut current_layer, &mut y_pos);
+            current_layer.use_text(note, 9.0, Mm(25.0), Mm(y_pos), &regular_font);
+            y_pos -= 8.0;
+        }
+
+        if let Some(company_tax_id) = &tax.company_tax_id {
+            ensure_room(&doc, &mut current_layer, &mut y_pos);
+            current_layer.use_text(&format!("Our tax ID: {}", company_tax_id), 9.0, Mm(25.0), Mm(y_pos), &regular_font);
+            y_pos -= 8.0;
+        }
+
+        if let Some(customer_tax_id) = &tax.customer_tax_id {
+            ensure_room(&doc, &mut current_layer, &mut y_pos);
+            current_layer.use_text(&format!("Customer tax ID: {}", customer_tax_id), 9.0, Mm(25.0), Mm(y_pos), &regular_font);
+            y_pos -= 8.0;
+        }
+        y_pos -= 7.0;
+    }
+
+    // Notes for the customer
+    if !comments.is_empty() {
+        ensure_room(&doc, &mut current_layer, &mut y_pos);
+        current_layer.use_text("Notes:", 14.0, Mm(20.0), Mm(y_pos), &font);
+        y_pos -= 15.0;
+
+        for comment in comments {
+            for line in wrap_text(&comment.body, NOTE_LINE_CHARS) {
+                ensure_room(&doc, &mut current_layer, &mut y_pos);
+                current_layer.use_text(&line, 10.0, Mm(25.0), Mm(y_pos), &regular_font);
+                y_pos -= 6.0;
+            }
+            y_pos -= 4.0;
+        }
+    }
+
+    doc.save_to_bytes()
+        .map_err(|e| AppError::InternalServerError(format!("PDF generation error: {}", e)))
+}
+
+/// Side of the QR code on a job ticket.
+const TICKET_QR_MM: f32 = 40.0;
+
+/// Lowest a line is written before moving to a new page.
+const PAGE_BOTTOM_MM: f32 = 20.0;
+
+/// Characters of the job title that fit on one line at 14pt beside the QR
+/// code.
+const TICKET_TITLE_CHARS: usize = 50;
+
+/// Writes ticket lines down the page, starting a new page when one is full.
+struct TicketWriter<'a> {
+    doc: &'a PdfDocumentReference,
+    layer: PdfLayerReference,
+    y_pos: f32,
+}
+
+impl TicketWriter<'_> {
+    fn heading(&mut self, text: &str, font: &IndirectFontRef) {
+        self.y_pos -= 4.0;
+        self.line(text, 13.0, 20.0, 9.0, font);
+    }
+
+    fn line(&mut self, text: &str, size: f32, x: f32, advance: f32, font: &IndirectFontRef) {
+        self.ensure_room();
+        self.layer.use_text(text, size, Mm(x), Mm(self.y_pos), font);
+        self.y_pos -= advance;
+    }
+
+    /// A line with a box to tick off when the step is done.
+    fn checkbox_line(&mut self, text: &str, font: &IndirectFontRef) {
+        self.ensure_room();
+        let bottom = self.y_pos - 0.5;
+        self.layer.add_line(Line {
+            points: rectangle(25.0, bottom, 29.0, bottom + 4.0),
+            is_closed: true,
+        });
+        self.line(text, 10.0, 32.0, 7.0, font);
+    }
+
+    fn ensure_room(&mut self) {
+        ensure_room(self.doc, &mut self.layer, &mut self.y_pos);
+    }
+}
+
+/// Moves `layer` to a new A4 page once `y_pos` is below the bottom margin.
+fn ensure_room(doc: &PdfDocumentReference, layer: &mut PdfLayerReference, y_pos: &mut f32) {
+    if *y_pos < PAGE_BOTTOM_MM {
+        let (page, new_layer) = doc.add_page(Mm(210.0), Mm(297.0), "Layer 1");
+        *layer = doc.get_page(page).get_layer(new_layer);
+        *y_pos = 277.0;
+    }
+}
+
+fn generate_job_ticket(job: &Job, branding: &crate::models::BrandingSettings, link: &str) -> Result<Vec<u8>, AppError> {
+    let (doc, page1, layer1) = PdfDocument::new(format!("Job Ticket - {}", job.title), Mm(210.0), Mm(297.0), "Layer 1");
+    let layer = doc.get_page(page1).get_layer(layer1);
+
+    let font = doc.add_builtin_font(BuiltinFont::HelveticaBold)
+        .map_err(|e| AppError::InternalServerError(format!("PDF font error: {}", e)))?;
+    let regular_font = doc.add_builtin_font(BuiltinFont::Helvetica)
+        .map_err(|e| AppError::InternalServerError(format!("PDF font error: {}", e)))?;
+
+    draw_qr_code(&layer, link, 190.0 - TICKET_QR_MM, 287.0 - TICKET_QR_MM, TICKET_QR_MM)?;
+    layer.use_text(&job.id.to_string()[..8], 9.0, Mm(190.0 - TICKET_QR_MM), Mm(242.0), &regular_font);
+
+    layer.use_text(&branding.company_name, 12.0, Mm(20.0), Mm(280.0), &regular_font);
+    layer.use_text("JOB TICKET", 24.0, Mm(20.0), Mm(268.0), &font);
+
+    let mut ticket = TicketWriter { doc: &doc, layer, y_pos: 256.0 };
+    for line in wrap_text(&job.title, TICKET_TITLE_CHARS) {
+        ticket.line(&line, 14.0, 20.0, 7.0, &font);
+    }
+    ticket.line(&format!("Job {} - revision {}", job.id, job.revision), 9.0, 20.0, 12.0, &regular_font);
+
+    let due = match (job.promised_date, job.terms.requested_delivery_date) {
+        (Some(promised), _) => format!("{} (promised)", promised.format("%a %d %b %Y")),
+        (None, Some(requested)) => format!("{} (requested)", requested.format("%a %d %b %Y")),
+        (None, None) => "Not set".to_string(),
+    };
+    ticket.line(&format!("Due: {}", due), 12.0, 20.0, 7.0, &font);
+    ticket.line(&format!("Quantity: {}", job.quantity), 12.0, 20.0, 7.0, &font);
+    ticket.line(&format!("Job type: {}", job.job_type), 10.0, 20.0, 6.0, &regular_font);
+    if let Some(process) = job.print_process {
+        ticket.line(&format!("Process: {}", process), 10.0, 20.0, 6.0, &regular_font);
+    }
+
+    // Specifications
+    let specifications = &job.specifications;
+    let colors = &specifications.colors;
+    ticket.heading("Specifications", &font);
+    ticket.line(&format!("Finished size: {}", specifications.paper_size), 10.0, 25.0, 6.0, &regular_font);
+    let weight = specifications.paper_weight.as_deref().map(|weight| format!(", {}", weight)).unwrap_or_default();
+    ticket.line(&format!("Paper: {}{}", specifications.paper_type, weight), 10.0, 25.0, 6.0, &regular_font);
+    let mut inks = format!("Colours: {}/{}", colors.front_colors, colors.back_colors);
+    if !colors.spot_colors.is_empty() {
+        inks.push_str(&format!(", spot {}", colors.spot_colors.join(", ")));
+    }
+    ticket.line(&inks, 10.0, 25.0, 6.0, &regular_font);
+    if let Some(pages) = specifications.pages {
+        ticket.line(&format!("Pages: {}", pages), 10.0, 25.0, 6.0, &regular_font);
+    }
+    if let Some(book) = &specifications.book {
+        ticket.line(&format!("Binding: {}", book.binding_method), 10.0, 25.0, 6.0, &regular_font);
+    } else if let Some(binding) = &specifications.binding {
+        ticket.line(&format!("Binding: {}", binding), 10.0, 25.0, 6.0, &regular_font);
+    }
+    if let Some(lamination) = &specifications.lamination {
+        ticket.line(&format!("Lamination: {}, {} side(s)", lamination.film, lamination.sides.count()), 10.0, 25.0, 6.0, &regular_font);
+    }
+    if let Some(large_format) = &specifications.large_format {
+        ticket.line(
+            &format!("Print size: {} x {} cm on {}", large_format.width_cm, large_format.height_cm, large_format.media_type),
+            10.0,
+            25.0,
+            6.0,
+            &regular_font,
+        );
+    }
+
+    // Stock and imposition, as costed
+    let stock: Vec<_> = job
+        .cost_breakdown
+        .lines
+        .iter()
+        .filter(|line| line.category == CostCategory::Paper)
+        .collect();
+    if !stock.is_empty() {
+        ticket.heading("Stock & Imposition", &font);
+        for line in stock {
+            let section = line.section.as_deref().map(|section| format!("{}: ", section)).unwrap_or_default();
+            let ups = line
+                .explanation
+                .as_ref()
+                .and_then(|explanation| explanation.inputs.iter().find(|input| input.name == "ups"))
+                .map(|ups| format!(", {}-up", ups.value.normalized()))
+                .unwrap_or_default();
+            ticket.line(&format!("{}{}{}", section, line.description, ups), 10.0, 25.0, 6.0, &regular_font);
+        }
+    }
+
+    // Production route
+    if !job.cost_breakdown.machine_hours.is_empty() {
+        ticket.heading("Route", &font);
+        for step in &job.cost_breakdown.machine_hours {
+            ticket.checkbox_line(&format!("{} - {:.1} h", step.machine_name, step.hours), &regular_font);
+        }
+    }
+
+    // Finishing
+    let finishing: Vec<String> = job
+        .cost_breakdown
+        .lines
+        .iter()
+        .filter(|line| matches!(line.category, CostCategory::Binding | CostCategory::Finishing))
+        .map(|line| line.description.clone())
+        .filter(|description| !description.is_empty())
+        .collect();
+    let finishing = if finishing.is_empty() { specifications.finishing.clone() } else { finishing };
+    if !finishing.is_empty() {
+        ticket.heading("Finishing", &font);
+        for step in &finishing {
+            ticket.checkbox_line(step, &regular_font);
+        }
+    }
+
+    if let Some(requirements) = specifications.special_requirements.as_deref().filter(|text| !text.trim().is_empty()) {
+        ticket.heading("Special Requirements", &font);
+        for line in wrap_text(requirements, NOTE_LINE_CHARS) {
+            ticket.line(&line, 10.0, 25.0, 6.0, &regular_font);
+        }
+    }
+
+    ticket.heading("Sign-off", &font);
+    ticket.line("Operator: ____________________    Date: ____________    Checked by: ____________________", 10.0, 25.0, 6.0, &regular_font);
 
     doc.save_to_bytes()
         .map_err(|e| AppError::InternalServerError(format!("PDF generation error: {}", e)))
 }
 
+/// Draws `data` as a QR code `size` mm square with its lower left corner at
+/// (`x`, `y`), including the quiet zone scanners need around it.
+fn draw_qr_code(layer: &PdfLayerReference, data: &str, x: f32, y: f32, size: f32) -> Result<(), AppError> {
+    const QUIET_ZONE_MODULES: usize = 4;
+
+    let code = QrCode::new(data.as_bytes())
+        .map_err(|e| AppError::InternalServerError(format!("QR code error: {}", e)))?;
+    let width = code.width();
+    let module = size / (width + 2 * QUIET_ZONE_MODULES) as f32;
+
+    for (index, color) in code.to_colors().into_iter().enumerate() {
+        if color != QrColor::Dark {
+            continue;
+        }
+        let column = (index % width + QUIET_ZONE_MODULES) as f32;
+        // Rows run top to bottom; PDF y runs bottom to top
+        let row = (index / width + QUIET_ZONE_MODULES) as f32;
+        let left = x + column * module;
+        let top = y + size - row * module;
+        layer.add_polygon(Polygon {
+            rings: vec![rectangle(left, top - module, left + module, top)],
+            mode: PolygonMode::Fill,
+            winding_order: WindingOrder::NonZero,
+        });
+    }
+    Ok(())
+}
+
+/// Corners of a rectangle in mm, for a closed line or polygon.
+fn rectangle(left: f32, bottom: f32, right: f32, top: f32) -> Vec<(Point, bool)> {
+    vec![
+        (Point::new(Mm(left), Mm(bottom)), false),
+        (Point::new(Mm(right), Mm(bottom)), false),
+        (Point::new(Mm(right), Mm(top)), false),
+        (Point::new(Mm(left), Mm(top)), false),
+    ]
+}
+
 fn generate_job_excel(
     job: &crate::models::Job,
     branding: &crate::models::BrandingSettings,
+    currency: Currency,
 ) -> Result<Vec<u8>, AppError> {
     let mut workbook = Workbook::new();
     let worksheet = workbook.add_worksheet();
@@ -109,6 +466,8 @@ fn generate_job_excel(
     // Header format
     let header_format = Format::new().set_bold().set_font_size(14);
     let bold_format = Format::new().set_bold();
+    let money_format = Format::new().set_num_format(money_number_format(currency));
+    let bold_money_format = money_format.clone().set_bold();
 
     // Company header
     worksheet.write_string_with_format(0, 0, &branding.company_name, &header_format)
@@ -145,46 +504,150 @@ fn generate_job_excel(
     row += 1;
     worksheet.write_string(row, 0, "Paper Cost:")
         .map_err(|e| AppError::InternalServerError(format!("Excel write error: {}", e)))?;
-    worksheet.write_number(row, 1, job.cost_breakdown.paper_cost.to_string().parse::<f64>().unwrap_or(0.0))
+    worksheet.write_number_with_format(row, 1, job.cost_breakdown.paper_cost.to_string().parse::<f64>().unwrap_or(0.0), &money_format)
         .map_err(|e| AppError::InternalServerError(format!("Excel write error: {}", e)))?;
     
     row += 1;
     worksheet.write_string(row, 0, "Plate Cost:")
         .map_err(|e| AppError::InternalServerError(format!("Excel write error: {}", e)))?;
-    worksheet.write_number(row, 1, job.cost_breakdown.plate_cost.to_string().parse::<f64>().unwrap_or(0.0))
+    worksheet.write_number_with_format(row, 1, job.cost_breakdown.plate_cost.to_string().parse::<f64>().unwrap_or(0.0), &money_format)
         .map_err(|e| AppError::InternalServerError(format!("Excel write error: {}", e)))?;
     
     row += 1;
     worksheet.write_string(row, 0, "Labor Cost:")
         .map_err(|e| AppError::InternalServerError(format!("Excel write error: {}", e)))?;
-    worksheet.write_number(row, 1, job.cost_breakdown.labor_cost.to_string().parse::<f64>().unwrap_or(0.0))
+    worksheet.write_number_with_format(row, 1, job.cost_breakdown.labor_cost.to_string().parse::<f64>().unwrap_or(0.0), &money_format)
         .map_err(|e| AppError::InternalServerError(format!("Excel write error: {}", e)))?;
     
     row += 1;
     worksheet.write_string(row, 0, "Binding Cost:")
         .map_err(|e| AppError::InternalServerError(format!("Excel write error: {}", e)))?;
-    worksheet.write_number(row, 1, job.cost_breakdown.binding_cost.to_string().parse::<f64>().unwrap_or(0.0))
+    worksheet.write_number_with_format(row, 1, job.cost_breakdown.binding_cost.to_string().parse::<f64>().unwrap_or(0.0), &money_format)
         .map_err(|e| AppError::InternalServerError(format!("Excel write error: {}", e)))?;
     
     row += 1;
     worksheet.write_string(row, 0, "Overhead:")
         .map_err(|e| AppError::InternalServerError(format!("Excel write error: {}", e)))?;
-    worksheet.write_number(row, 1, job.cost_breakdown.overhead.to_string().parse::<f64>().unwrap_or(0.0))
+    worksheet.write_number_with_format(row, 1, job.cost_breakdown.overhead.to_string().parse::<f64>().unwrap_or(0.0), &money_format)
         .map_err(|e| AppError::InternalServerError(format!("Excel write error: {}", e)))?;
 
     // Total
     row += 2;
     worksheet.write_string_with_format(row, 0, "Total Cost:", &bold_format)
         .map_err(|e| AppError::InternalServerError(format!("Excel write error: {}", e)))?;
-    worksheet.write_number_with_format(row, 1, job.total_cost.to_string().parse::<f64>().unwrap_or(0.0), &bold_format)
+    worksheet.write_number_with_format(row, 1, job.total_cost.to_string().parse::<f64>().unwrap_or(0.0), &bold_money_format)
         .map_err(|e| AppError::InternalServerError(format!("Excel write error: {}", e)))?;
     
     row += 1;
     worksheet.write_string_with_format(row, 0, "Unit Cost:", &bold_format)
         .map_err(|e| AppError::InternalServerError(format!("Excel write error: {}", e)))?;
-    worksheet.write_number_with_format(row, 1, job.unit_cost.to_string().parse::<f64>().unwrap_or(0.0), &bold_format)
+    worksheet.write_number_with_format(row, 1, job.unit_cost.to_string().parse::<f64>().unwrap_or(0.0), &bold_money_format)
         .map_err(|e| AppError::InternalServerError(format!("Excel write error: {}", e)))?;
 
+    // Discounts and surcharges
+    let adjustments = job.pricing.as_ref().map(|pricing| pricing.adjustments.as_slice()).unwrap_or_default();
+    if !adjustments.is_empty() {
+        row += 2;
+        worksheet.write_string_with_format(row, 0, "Adjustments", &bold_format)
+            .map_err(|e| AppError::InternalServerError(format!("Excel write error: {}", e)))?;
+
+        for adjustment in adjustments {
+            row += 1;
+            worksheet.write_string(row, 0, &format!("{}:", adjustment.description))
+                .map_err(|e| AppError::InternalServerError(format!("Excel write error: {}", e)))?;
+            worksheet.write_number_with_format(row, 1, adjustment.amount.to_string().parse::<f64>().unwrap_or(0.0), &money_format)
+                .map_err(|e| AppError::InternalServerError(format!("Excel write error: {}", e)))?;
+        }
+    }
+
+    // Tax summary
+    if let Some(tax) = &job.tax {
+        row += 2;
+        worksheet.write_string_with_format(row, 0, "Tax Summary", &bold_format)
+            .map_err(|e| AppError::InternalServerError(format!("Excel write error: {}", e)))?;
+
+        row += 1;
+        worksheet.write_string(row, 0, "Net:")
+            .map_err(|e| AppError::InternalServerError(format!("Excel write error: {}", e)))?;
+        worksheet.write_number_with_format(row, 1, tax.net.to_string().parse::<f64>().unwrap_or(0.0), &money_format)
+            .map_err(|e| AppError::InternalServerError(format!("Excel write error: {}", e)))?;
+
+        for line in &tax.lines {
+            row += 1;
+            worksheet.write_string(row, 0, &format!("{} ({}):", line.name, percentage(&line.rate)))
+                .map_err(|e| AppError::InternalServerError(format!("Excel write error: {}", e)))?;
+            worksheet.write_number_with_format(row, 1, line.amount.to_string().parse::<f64>().unwrap_or(0.0), &money_format)
+                .map_err(|e| AppError::InternalServerError(format!("Excel write error: {}", e)))?;
+        }
+
+        row += 1;
+        worksheet.write_string_with_format(row, 0, "Gross:", &bold_format)
+            .map_err(|e| AppError::InternalServerError(format!("Excel write error: {}", e)))?;
+        worksheet.write_number_with_format(row, 1, tax.gross.to_string().parse::<f64>().unwrap_or(0.0), &bold_money_format)
+            .map_err(|e| AppError::InternalServerError(format!("Excel write error: {}", e)))?;
+
+        if let Some(note) = &tax.note {
+            row += 1;
+            worksheet.write_string(row, 0, note)
+                .map_err(|e| AppError::InternalServerError(format!("Excel write error: {}", e)))?;
+        }
+
+        if let Some(company_tax_id) = &tax.company_tax_id {
+            row += 1;
+            worksheet.write_string(row, 0, "Our tax ID:")
+                .map_err(|e| AppError::InternalServerError(format!("Excel write error: {}", e)))?;
+            worksheet.write_string(row, 1, company_tax_id)
+                .map_err(|e| AppError::InternalServerError(format!("Excel write error: {}", e)))?;
+        }
+
+        if let Some(customer_tax_id) = &tax.customer_tax_id {
+            row += 1;
+            worksheet.write_string(row, 0, "Customer tax ID:")
+                .map_err(|e| AppError::InternalServerError(format!("Excel write error: {}", e)))?;
+            worksheet.write_string(row, 1, customer_tax_id)
+                .map_err(|e| AppError::InternalServerError(format!("Excel write error: {}", e)))?;
+        }
+    }
+
     workbook.save_to_buffer()
         .map_err(|e| AppError::InternalServerError(format!("Excel generation error: {}", e)))
 }
+
+/// Splits text into lines of at most `width` characters, breaking between
+/// words and keeping the text's own line breaks.
+fn wrap_text(text: &str, width: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    for paragraph in text.lines() {
+        let mut line = String::new();
+        for word in paragraph.split_whitespace() {
+            if !line.is_empty() && line.chars().count() + 1 + word.chars().count() > width {
+                lines.push(std::mem::take(&mut line));
+            }
+            if !line.is_empty() {
+                line.push(' ');
+            }
+            line.push_str(word);
+        }
+        lines.push(line);
+    }
+    lines
+}
+
+/// An amount with its currency code, to the currency's minor units, e.g.
+/// `12500 XAF` or `84.10 EUR`.
+fn money(amount: &bigdecimal::BigDecimal, currency: Currency) -> String {
+    format!("{:.*} {}", currency.minor_units() as usize, amount, currency.code())
+}
+
+/// Excel number format showing amounts like [`money`].
+fn money_number_format(currency: Currency) -> String {
+    match currency.minor_units() {
+        0 => format!("#,##0 \"{}\"", currency.code()),
+        minor_units => format!("#,##0.{} \"{}\"", "0".repeat(minor_units as usize), currency.code()),
+    }
+}
+
+/// A rate fraction as a percentage label, e.g. `0.1925` as `19.25%`.
+fn percentage(rate: &bigdecimal::BigDecimal) -> String {
+    format!("{}%", (rate * bigdecimal::BigDecimal::from(100)).normalized())
+}
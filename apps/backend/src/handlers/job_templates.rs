@@ -0,0 +1,116 @@
+use axum::{
+    extract::{Path, State},
+    http::{HeaderMap, StatusCode},
+    response::Json,
+};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::{
+    handlers::{auth::require_user, jobs},
+    models::{
+        CreateJobFromTemplateRequest, CreateJobRequest, CreateJobTemplateRequest, Job, JobTemplate,
+        UpdateJobTemplateRequest,
+    },
+    services::job_template_service::JobTemplateService,
+    utils::errors::AppError,
+    AppState,
+};
+
+pub async fn list_templates(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> Result<Json<Vec<JobTemplate>>, AppError> {
+    let (user_id, _) = require_user(&headers, &state.config.jwt_secret)?;
+
+    let templates = JobTemplateService::new(&state.db.connection)
+        .list_templates(user_id)
+        .await?;
+    Ok(Json(templates))
+}
+
+pub async fn create_template(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(payload): Json<CreateJobTemplateRequest>,
+) -> Result<Json<JobTemplate>, AppError> {
+    let (user_id, manager) = require_user(&headers, &state.config.jwt_secret)?;
+    payload
+        .validate()
+        .map_err(|e| AppError::ValidationError(e.to_string()))?;
+
+    let template = JobTemplateService::new(&state.db.connection)
+        .create_template(user_id, manager, payload)
+        .await?;
+    Ok(Json(template))
+}
+
+pub async fn get_template(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(template_id): Path<Uuid>,
+) -> Result<Json<JobTemplate>, AppError> {
+    let (user_id, _) = require_user(&headers, &state.config.jwt_secret)?;
+
+    JobTemplateService::new(&state.db.connection)
+        .find_by_id(template_id, user_id)
+        .await?
+        .map(Json)
+        .ok_or(AppError::NotFound("Template not found".to_string()))
+}
+
+pub async fn update_template(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(template_id): Path<Uuid>,
+    Json(payload): Json<UpdateJobTemplateRequest>,
+) -> Result<Json<JobTemplate>, AppError> {
+    let (user_id, manager) = require_user(&headers, &state.config.jwt_secret)?;
+    payload
+        .validate()
+        .map_err(|e| AppError::ValidationError(e.to_string()))?;
+
+    let template = JobTemplateService::new(&state.db.connection)
+        .update_template(template_id, user_id, manager, payload)
+        .await?;
+    Ok(Json(template))
+}
+
+pub async fn delete_template(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(template_id): Path<Uuid>,
+) -> Result<StatusCode, AppError> {
+    let (user_id, manager) = require_user(&headers, &state.config.jwt_secret)?;
+
+    JobTemplateService::new(&state.db.connection)
+        .delete_template(template_id, user_id, manager)
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Creates and prices a job from a template, with the request's quantity
+/// and specifications taking precedence.
+pub async fn create_job_from_template(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(template_id): Path<Uuid>,
+    Json(payload): Json<CreateJobFromTemplateRequest>,
+) -> Result<Json<Job>, AppError> {
+    let (user_id, _) = require_user(&headers, &state.config.jwt_secret)?;
+
+    let template = JobTemplateService::new(&state.db.connection)
+        .find_by_id(template_id, user_id)
+        .await?
+        .ok_or(AppError::NotFound("Template not found".to_string()))?;
+
+    let request = CreateJobRequest {
+        title: payload.title,
+        job_type: template.job_type,
+        quantity: payload.quantity.unwrap_or(template.quantity),
+        specifications: payload.specifications.unwrap_or(template.specifications),
+        customer_id: payload.customer_id,
+        terms: payload.terms,
+    };
+    jobs::create_job(State(state), Json(request)).await
+}
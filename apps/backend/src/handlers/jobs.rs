@@ -1,6 +1,6 @@
 use axum::{
-    extract::{Path, Query, State},
-    http::StatusCode,
+    extract::{Multipart, Path, Query, State},
+    http::{HeaderMap, StatusCode},
     response::Json,
     Extension,
 };
@@ -8,12 +8,20 @@ use uuid::Uuid;
 use std::sync::{Arc, Mutex};
 use std::collections::HashMap;
 use once_cell::sync::Lazy;
+use validator::Validate;
 
 use crate::{
     models::{
-        CreateJobRequest, Job, JobListQuery, JobListResponse, UpdateJobRequest, UserInfo,
+        CostCalculationQuery, CostCalculationRequest, CostCalculationResponse, CreateJobRequest, CreateJobTemplateRequest,
+        Currency, DuplicateJobRequest, ImportFormat, ImportRowError, ImportedJob, Job, JobDetail, JobImportQuery, JobImportReport,
+        JobListQuery, JobListResponse, JobRevision, JobSpecifications, JobStatus, JobTemplate, JobType, QuoteTerms,
+        RevisionDiff, RevisionDiffQuery, SaveJobAsTemplateRequest, UpdateJobRequest, UserInfo,
+    },
+    handlers::{auth::{require_manager, require_user}, costing},
+    services::{
+        attachment_service::AttachmentService, comment_service::CommentService, job_service::JobService, costing_service::CostingService, currency_settings_service::CurrencySettingsService,
+        job_import_service, job_template_service::JobTemplateService,
     },
-    services::{job_service::JobService, costing_service::CostingService},
     utils::errors::AppError,
     AppState,
 };
@@ -23,6 +31,16 @@ static JOB_STORAGE: Lazy<Arc<Mutex<HashMap<Uuid, Job>>>> = Lazy::new(|| {
     Arc::new(Mutex::new(HashMap::new()))
 });
 
+/// Days a reorder keeps the original job's price when none is given.
+const DEFAULT_PRICE_HOLD_DAYS: i64 = 30;
+
+// Quote revisions per job, oldest first, stored alongside the jobs
+type RevisionStorage = HashMap<Uuid, Vec<JobRevision>>;
+
+static REVISION_STORAGE: Lazy<Arc<Mutex<RevisionStorage>>> = Lazy::new(|| {
+    Arc::new(Mutex::new(HashMap::new()))
+});
+
 pub async fn list_jobs(
     Query(query): Query<JobListQuery>,
 ) -> Result<Json<JobListResponse>, AppError> {
@@ -56,87 +74,520 @@ pub async fn create_job(
     State(state): State<AppState>,
     Json(payload): Json<CreateJobRequest>,
 ) -> Result<Json<Job>, AppError> {
-    use crate::models::{JobStatus, CostCalculationRequest};
-    use crate::handlers::costing;
+    let cost_data = quote(
+        &state,
+        payload.job_type,
+        payload.quantity,
+        &payload.specifications,
+        payload.customer_id,
+        &payload.terms,
+    )
+    .await?;
+    let (parameters_version, currency, exchange_rate) = snapshot_context(&cost_data)?;
+    let job = new_job(payload, cost_data);
     
-    // Calculate cost for the job
-    let cost_request = CostCalculationRequest {
-        job_type: payload.job_type.clone(),
-        quantity: payload.quantity,
-        specifications: payload.specifications.clone(),
-        currency: None, // Default to USD
-    };
-    
-    // Use the costing handler to calculate costs
-    let cost_response = costing::calculate_cost(Json(cost_request)).await?;
-    let cost_data = cost_response.0;
+    // Store the job in memory
+    let mut storage = JOB_STORAGE.lock().map_err(|_| AppError::InternalServerError("Failed to access job storage".to_string()))?;
+    storage.insert(job.id, job.clone());
+    record_revision(&job, parameters_version, currency, exchange_rate)?;
     
-    let job_id = Uuid::new_v4();
+    Ok(Json(job))
+}
+
+/// A draft job for `request`, priced by `cost_data`.
+fn new_job(request: CreateJobRequest, cost_data: CostCalculationResponse) -> Job {
     let user_id = Uuid::new_v4(); // In a real app, this would come from authentication
     
-    let job = Job {
-        id: job_id,
+    Job {
+        id: Uuid::new_v4(),
         user_id,
-        title: payload.title,
-        job_type: payload.job_type,
-        specifications: payload.specifications,
-        quantity: payload.quantity,
+        title: request.title,
+        job_type: request.job_type,
+        specifications: request.specifications,
+        quantity: request.quantity,
         status: JobStatus::Draft,
+        print_process: cost_data.process,
         total_cost: cost_data.total_cost,
         unit_cost: cost_data.unit_cost,
+        pricing: cost_data.pricing,
+        tax: cost_data.tax,
+        customer_id: request.customer_id,
+        terms: request.terms,
+        revision: 1,
+        reorder_of: None,
+        price_held_until: None,
+        promised_date: None,
+        margin_approved_by: None,
         cost_breakdown: cost_data.cost_breakdown,
         created_at: chrono::Utc::now(),
         updated_at: chrono::Utc::now(),
-    };
-    
-    // Store the job in memory
-    let mut storage = JOB_STORAGE.lock().map_err(|_| AppError::InternalServerError("Failed to access job storage".to_string()))?;
-    storage.insert(job_id, job.clone());
-    
-    Ok(Json(job))
+    }
+}
+
+/// Imports jobs from a CSV or XLSX upload in the `file` field. Every row is
+/// validated and costed with the current engine; unless `dryRun` is set,
+/// the valid rows are then created together and the others reported.
+pub async fn import_jobs(
+    State(state): State<AppState>,
+    Query(query): Query<JobImportQuery>,
+    mut multipart: Multipart,
+) -> Result<Json<JobImportReport>, AppError> {
+    let (format, bytes) = read_upload(&mut multipart).await?;
+    let rows = job_import_service::read_rows(&bytes, format)?;
+
+    let mut errors = Vec::new();
+    let mut priced = Vec::new();
+    for row in &rows {
+        let request = match job_import_service::parse_row(row) {
+            Ok(request) => request,
+            Err(row_errors) => {
+                errors.extend(row_errors);
+                continue;
+            }
+        };
+        let cost_data = quote(&state, request.job_type, request.quantity, &request.specifications, request.customer_id, &request.terms).await;
+        match cost_data {
+            Ok(cost_data) => priced.push((row.row, request, cost_data)),
+            // The row cannot be priced as specified
+            Err(AppError::ValidationError(message) | AppError::BadRequest(message) | AppError::NotFound(message)) => {
+                errors.push(ImportRowError { row: row.row, column: None, message });
+            }
+            Err(error) => return Err(error),
+        }
+    }
+
+    let jobs: Vec<(usize, Job, (i32, Currency, f64))> = priced
+        .into_iter()
+        .map(|(row, request, cost_data)| {
+            let context = snapshot_context(&cost_data)?;
+            Ok((row, new_job(request, cost_data), context))
+        })
+        .collect::<Result<_, AppError>>()?;
+
+    if !query.dry_run {
+        // All valid rows go in under one lock, so no one sees half an import
+        let mut storage = JOB_STORAGE.lock().map_err(|_| AppError::InternalServerError("Failed to access job storage".to_string()))?;
+        for (_, job, (parameters_version, currency, exchange_rate)) in &jobs {
+            storage.insert(job.id, job.clone());
+            record_revision(job, *parameters_version, *currency, *exchange_rate)?;
+        }
+    }
+
+    Ok(Json(JobImportReport {
+        dry_run: query.dry_run,
+        rows: rows.len(),
+        imported: jobs
+            .into_iter()
+            .map(|(row, job, _)| ImportedJob {
+                row,
+                job_id: (!query.dry_run).then_some(job.id),
+                title: job.title,
+                job_type: job.job_type,
+                quantity: job.quantity,
+                total_cost: job.total_cost,
+            })
+            .collect(),
+        errors,
+    }))
+}
+
+async fn read_upload(multipart: &mut Multipart) -> Result<(ImportFormat, Vec<u8>), AppError> {
+    while let Some(field) = multipart
+        .next_field()
+        .await
+        .map_err(|e| AppError::BadRequest(e.to_string()))?
+    {
+        if field.name() != Some("file") {
+            continue;
+        }
+        let format = field
+            .file_name()
+            .and_then(ImportFormat::from_file_name)
+            .or_else(|| field.content_type().and_then(ImportFormat::from_content_type))
+            .ok_or(AppError::BadRequest("Upload a .csv or .xlsx file".to_string()))?;
+        let bytes = field.bytes().await.map_err(|e| AppError::BadRequest(e.to_string()))?;
+        return Ok((format, bytes.to_vec()));
+    }
+    Err(AppError::BadRequest("Missing file field".to_string()))
 }
 
 pub async fn get_job(
+    State(state): State<AppState>,
     Path(job_id): Path<Uuid>,
-) -> Result<Json<Job>, AppError> {
+) -> Result<Json<JobDetail>, AppError> {
+    let job = find_job(job_id)?;
+    let comments = CommentService::new(&state.db.connection)
+        .list_for_job(job_id, false)
+        .await?;
+    Ok(Json(JobDetail { job, comments }))
+}
+
+/// Approved and in-production jobs, the workload the scheduler places on
+/// machine capacity.
+pub(crate) fn scheduled_jobs() -> Result<Vec<Job>, AppError> {
     let storage = JOB_STORAGE.lock().map_err(|_| AppError::InternalServerError("Failed to access job storage".to_string()))?;
-    
+
+    Ok(storage
+        .values()
+        .filter(|job| matches!(job.status, JobStatus::Approved | JobStatus::InProduction))
+        .cloned()
+        .collect())
+}
+
+/// The job with this id, for handlers that hang data off a job.
+pub(crate) fn find_job(job_id: Uuid) -> Result<Job, AppError> {
+    let storage = JOB_STORAGE.lock().map_err(|_| AppError::InternalServerError("Failed to access job storage".to_string()))?;
+
     match storage.get(&job_id) {
-        Some(job) => Ok(Json(job.clone())),
+        Some(job) => Ok(job.clone()),
         None => Err(AppError::NotFound("Job not found".to_string())),
     }
 }
 
 pub async fn update_job(
+    State(state): State<AppState>,
     Path(job_id): Path<Uuid>,
     Json(payload): Json<UpdateJobRequest>,
 ) -> Result<Json<Job>, AppError> {
+    let current = {
+        let storage = JOB_STORAGE.lock().map_err(|_| AppError::InternalServerError("Failed to access job storage".to_string()))?;
+        storage.get(&job_id).cloned().ok_or(AppError::NotFound("Job not found".to_string()))?
+    };
+
+    // Quantity, specification or terms changes are re-priced into a new revision
+    let requote = if payload.quantity.is_some() || payload.specifications.is_some() || payload.terms.is_some() {
+        let quantity = payload.quantity.unwrap_or(current.quantity);
+        let specifications = payload.specifications.unwrap_or(current.specifications);
+        let terms = payload.terms.unwrap_or(current.terms);
+        let cost_data = quote(&state, current.job_type, quantity, &specifications, current.customer_id, &terms).await?;
+        Some((quantity, specifications, terms, cost_data))
+    } else {
+        None
+    };
+
     let mut storage = JOB_STORAGE.lock().map_err(|_| AppError::InternalServerError("Failed to access job storage".to_string()))?;
-    
-    match storage.get_mut(&job_id) {
-        Some(job) => {
-            // Update job fields if provided
-            if let Some(title) = payload.title {
-                job.title = title;
-            }
-            if let Some(status) = payload.status {
-                job.status = status;
-            }
-            job.updated_at = chrono::Utc::now();
-            
-            Ok(Json(job.clone()))
+    let stored = storage.get_mut(&job_id).ok_or(AppError::NotFound("Job not found".to_string()))?;
+    // The quote was priced from `current` without the lock held
+    if requote.is_some() && stored.updated_at != current.updated_at {
+        return Err(AppError::Conflict(
+            "The job was changed while it was being re-costed; reload it and try again".to_string(),
+        ));
+    }
+
+    // Changes go on a copy so a rejected update leaves the job untouched
+    let mut job = stored.clone();
+    if let Some(title) = payload.title {
+        job.title = title;
+    }
+    let revision_context = match requote {
+        Some((quantity, specifications, terms, cost_data)) => {
+            let context = snapshot_context(&cost_data)?;
+            job.quantity = quantity;
+            job.specifications = specifications;
+            job.terms = terms;
+            apply_quote(&mut job, cost_data);
+            Some(context)
+        }
+        None => None,
+    };
+    if let Some(promised_date) = payload.promised_date {
+        job.promised_date = Some(promised_date);
+    }
+    if let Some(status) = payload.status {
+        if matches!(status, JobStatus::Quoted | JobStatus::Approved) && needs_margin_approval(&job) {
+            return Err(AppError::Forbidden(
+                "Margin is below the minimum; a manager must approve it before quoting".to_string(),
+            ));
+        }
+        if matches!(status, JobStatus::Quoted | JobStatus::Approved) && price_hold_expired(&job) {
+            return Err(AppError::ValidationError(
+                "The price held from the original job has expired; re-cost the job before quoting".to_string(),
+            ));
         }
+        job.status = status;
+    }
+    job.updated_at = chrono::Utc::now();
+
+    if let Some((parameters_version, currency, exchange_rate)) = revision_context {
+        job.revision += 1;
+        record_revision(&job, parameters_version, currency, exchange_rate)?;
+    }
+    *stored = job.clone();
+
+    Ok(Json(job))
+}
+
+/// Copies a job's specifications and customer into a new draft linked to
+/// it as a reorder, either re-costed with current parameters or at the
+/// original price for a limited period.
+pub async fn duplicate_job(
+    State(state): State<AppState>,
+    Path(job_id): Path<Uuid>,
+    Json(payload): Json<DuplicateJobRequest>,
+) -> Result<Json<Job>, AppError> {
+    let hold_days = payload.hold_days.unwrap_or(DEFAULT_PRICE_HOLD_DAYS);
+    if hold_days <= 0 {
+        return Err(AppError::ValidationError("Hold days must be greater than zero".to_string()));
+    }
+
+    let original = {
+        let storage = JOB_STORAGE.lock().map_err(|_| AppError::InternalServerError("Failed to access job storage".to_string()))?;
+        storage.get(&job_id).cloned().ok_or(AppError::NotFound("Job not found".to_string()))?
+    };
+
+    let now = chrono::Utc::now();
+    let mut job = Job {
+        id: Uuid::new_v4(),
+        title: payload.title.unwrap_or_else(|| original.title.clone()),
+        status: JobStatus::Draft,
+        revision: 1,
+        reorder_of: Some(original.id),
+        price_held_until: None,
+        promised_date: None,
+        margin_approved_by: None,
+        created_at: now,
+        updated_at: now,
+        ..original.clone()
+    };
+
+    let (parameters_version, currency, exchange_rate) = if payload.recost {
+        // The original delivery date has passed and would read as a rush
+        job.terms.requested_delivery_date = None;
+        let cost_data = quote(&state, job.job_type, job.quantity, &job.specifications, job.customer_id, &job.terms).await?;
+        let context = snapshot_context(&cost_data)?;
+        apply_quote(&mut job, cost_data);
+        context
+    } else {
+        job.price_held_until = Some(now + chrono::Duration::days(hold_days));
+        let revisions = REVISION_STORAGE.lock().map_err(|_| AppError::InternalServerError("Failed to access revision storage".to_string()))?;
+        revisions
+            .get(&original.id)
+            .and_then(|snapshots| snapshots.last())
+            .map(|snapshot| (snapshot.parameters_version, snapshot.currency, snapshot.exchange_rate))
+            // Every stored job has at least the revision it was created with
+            .ok_or(AppError::InternalServerError("Job has no recorded revision".to_string()))?
+    };
+
+    let mut storage = JOB_STORAGE.lock().map_err(|_| AppError::InternalServerError("Failed to access job storage".to_string()))?;
+    storage.insert(job.id, job.clone());
+    record_revision(&job, parameters_version, currency, exchange_rate)?;
+
+    Ok(Json(job))
+}
+
+/// The original order and every reorder descended from it, oldest first.
+pub async fn reorder_chain(
+    Path(job_id): Path<Uuid>,
+) -> Result<Json<Vec<Job>>, AppError> {
+    let storage = JOB_STORAGE.lock().map_err(|_| AppError::InternalServerError("Failed to access job storage".to_string()))?;
+
+    let mut root = storage.get(&job_id).ok_or(AppError::NotFound("Job not found".to_string()))?;
+    while let Some(parent) = root.reorder_of.and_then(|parent_id| storage.get(&parent_id)) {
+        root = parent;
+    }
+
+    let mut chain = vec![root.clone()];
+    let mut next = 0;
+    while next < chain.len() {
+        let parent_id = chain[next].id;
+        chain.extend(storage.values().filter(|job| job.reorder_of == Some(parent_id)).cloned());
+        next += 1;
+    }
+    chain.sort_by_key(|job| job.created_at);
+
+    Ok(Json(chain))
+}
+
+/// Prices a job in the company base currency, keeping the explanation
+/// trace with the job for later audits.
+async fn quote(
+    state: &AppState,
+    job_type: JobType,
+    quantity: i32,
+    specifications: &JobSpecifications,
+    customer_id: Option<Uuid>,
+    terms: &QuoteTerms,
+) -> Result<CostCalculationResponse, AppError> {
+    // Jobs are always stored in the company base currency
+    let base_currency = CurrencySettingsService::new(&state.db.connection, &state.config)
+        .get_settings()
+        .await?
+        .base_currency;
+
+    let cost_request = CostCalculationRequest {
+        job_type,
+        quantity,
+        specifications: specifications.clone(),
+        currency: Some(base_currency),
+        customer_id,
+        terms: terms.clone(),
+    };
+
+    // Use the costing handler to calculate costs
+    let explain = Query(CostCalculationQuery { explain: Some(true) });
+    let cost_response = costing::calculate_cost(State(state.clone()), explain, HeaderMap::new(), Json(cost_request)).await?;
+    Ok(cost_response.0)
+}
+
+/// Replaces the job's price with a fresh quote.
+fn apply_quote(job: &mut Job, cost_data: CostCalculationResponse) {
+    job.print_process = cost_data.process;
+    job.total_cost = cost_data.total_cost;
+    job.unit_cost = cost_data.unit_cost;
+    job.pricing = cost_data.pricing;
+    job.tax = cost_data.tax;
+    job.cost_breakdown = cost_data.cost_breakdown;
+    job.price_held_until = None;
+    // An approval was for the old price
+    job.margin_approved_by = None;
+}
+
+/// Parameter version, currency and exchange rate a quote was priced with.
+/// Revisions must name the stored parameter version, so a quote without
+/// one is rejected rather than given a placeholder.
+fn snapshot_context(cost_data: &CostCalculationResponse) -> Result<(i32, Currency, f64), AppError> {
+    let parameters_version = cost_data
+        .parameters_version
+        .ok_or(AppError::InternalServerError("Quote has no cost parameter version".to_string()))?;
+    Ok((
+        parameters_version,
+        cost_data.currency.unwrap_or_default(),
+        cost_data.exchange_rate.unwrap_or(1.0),
+    ))
+}
+
+fn record_revision(job: &Job, parameters_version: i32, currency: Currency, exchange_rate: f64) -> Result<(), AppError> {
+    let mut revisions = REVISION_STORAGE.lock().map_err(|_| AppError::InternalServerError("Failed to access revision storage".to_string()))?;
+    revisions
+        .entry(job.id)
+        .or_default()
+        .push(JobRevision::of(job, job.revision, parameters_version, currency, exchange_rate));
+    Ok(())
+}
+
+pub async fn list_revisions(
+    Path(job_id): Path<Uuid>,
+) -> Result<Json<Vec<JobRevision>>, AppError> {
+    let revisions = REVISION_STORAGE.lock().map_err(|_| AppError::InternalServerError("Failed to access revision storage".to_string()))?;
+
+    match revisions.get(&job_id) {
+        Some(job_revisions) => Ok(Json(job_revisions.clone())),
         None => Err(AppError::NotFound("Job not found".to_string())),
     }
 }
 
+pub async fn get_revision(
+    Path((job_id, revision)): Path<(Uuid, i32)>,
+) -> Result<Json<JobRevision>, AppError> {
+    let revisions = REVISION_STORAGE.lock().map_err(|_| AppError::InternalServerError("Failed to access revision storage".to_string()))?;
+
+    Ok(Json(find_revision(&revisions, job_id, revision)?.clone()))
+}
+
+/// Field-level specification changes and per-line cost changes between two
+/// revisions of a job.
+pub async fn diff_revisions(
+    Path(job_id): Path<Uuid>,
+    Query(query): Query<RevisionDiffQuery>,
+) -> Result<Json<RevisionDiff>, AppError> {
+    let revisions = REVISION_STORAGE.lock().map_err(|_| AppError::InternalServerError("Failed to access revision storage".to_string()))?;
+
+    let from = find_revision(&revisions, job_id, query.from)?;
+    let to = find_revision(&revisions, job_id, query.to)?;
+    Ok(Json(from.diff(to)))
+}
+
+fn find_revision(
+    revisions: &RevisionStorage,
+    job_id: Uuid,
+    revision: i32,
+) -> Result<&JobRevision, AppError> {
+    revisions
+        .get(&job_id)
+        .ok_or(AppError::NotFound("Job not found".to_string()))?
+        .iter()
+        .find(|snapshot| snapshot.revision == revision)
+        .ok_or_else(|| AppError::NotFound(format!("Revision {} not found", revision)))
+}
+
+/// Lets a below-minimum margin go out as a quote. Managers and admins only.
+pub async fn approve_margin(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(job_id): Path<Uuid>,
+) -> Result<Json<Job>, AppError> {
+    let manager_id = require_manager(&headers, &state.config.jwt_secret)?;
+
+    let mut storage = JOB_STORAGE.lock().map_err(|_| AppError::InternalServerError("Failed to access job storage".to_string()))?;
+    let job = storage
+        .get_mut(&job_id)
+        .ok_or(AppError::NotFound("Job not found".to_string()))?;
+
+    job.margin_approved_by = Some(manager_id);
+    job.updated_at = chrono::Utc::now();
+
+    Ok(Json(job.clone()))
+}
+
+/// Saves the job's type, quantity and specifications as a template.
+pub async fn save_as_template(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(job_id): Path<Uuid>,
+    Json(payload): Json<SaveJobAsTemplateRequest>,
+) -> Result<Json<JobTemplate>, AppError> {
+    let (user_id, manager) = require_user(&headers, &state.config.jwt_secret)?;
+    payload
+        .validate()
+        .map_err(|e| AppError::ValidationError(e.to_string()))?;
+
+    let job = {
+        let storage = JOB_STORAGE.lock().map_err(|_| AppError::InternalServerError("Failed to access job storage".to_string()))?;
+        storage.get(&job_id).cloned().ok_or(AppError::NotFound("Job not found".to_string()))?
+    };
+
+    let request = CreateJobTemplateRequest {
+        name: payload.name,
+        description: payload.description,
+        job_type: job.job_type,
+        quantity: job.quantity,
+        specifications: job.specifications,
+        scope: payload.scope,
+    };
+    let template = JobTemplateService::new(&state.db.connection)
+        .create_template(user_id, manager, request)
+        .await?;
+    Ok(Json(template))
+}
+
+fn price_hold_expired(job: &Job) -> bool {
+    job.price_held_until.is_some_and(|until| until < chrono::Utc::now())
+}
+
+fn needs_margin_approval(job: &Job) -> bool {
+    let below_minimum = job.pricing.as_ref().map(|pricing| pricing.requires_approval).unwrap_or(false);
+    below_minimum && job.margin_approved_by.is_none()
+}
+
 pub async fn delete_job(
+    State(state): State<AppState>,
     Path(job_id): Path<Uuid>,
 ) -> Result<StatusCode, AppError> {
-    let mut storage = JOB_STORAGE.lock().map_err(|_| AppError::InternalServerError("Failed to access job storage".to_string()))?;
-    
-    match storage.remove(&job_id) {
-        Some(_) => Ok(StatusCode::NO_CONTENT),
-        None => Err(AppError::NotFound("Job not found".to_string())),
+    {
+        let mut storage = JOB_STORAGE.lock().map_err(|_| AppError::InternalServerError("Failed to access job storage".to_string()))?;
+
+        if storage.remove(&job_id).is_none() {
+            return Err(AppError::NotFound("Job not found".to_string()));
+        }
+        if let Ok(mut revisions) = REVISION_STORAGE.lock() {
+            revisions.remove(&job_id);
+        }
     }
+
+    AttachmentService::new(&state.db.connection, state.files.as_ref(), state.config.max_attachment_bytes)
+        .delete_for_job(job_id)
+        .await?;
+    CommentService::new(&state.db.connection).delete_for_job(job_id).await?;
+
+    Ok(StatusCode::NO_CONTENT)
 }
@@ -0,0 +1,78 @@
+use axum::{
+    extract::{Path, State},
+    http::{HeaderMap, StatusCode},
+    response::Json,
+};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::{
+    handlers::auth::require_manager,
+    models::{CreateMachineRequest, Machine, UpdateMachineRequest},
+    services::machine_service::MachineService,
+    utils::errors::AppError,
+    AppState,
+};
+
+pub async fn list_machines(
+    State(state): State<AppState>,
+) -> Result<Json<Vec<Machine>>, AppError> {
+    let machines = MachineService::new(&state.db.connection).list_machines().await?;
+    Ok(Json(machines))
+}
+
+pub async fn create_machine(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(payload): Json<CreateMachineRequest>,
+) -> Result<Json<Machine>, AppError> {
+    require_manager(&headers, &state.config.jwt_secret)?;
+    payload
+        .validate()
+        .map_err(|e| AppError::ValidationError(e.to_string()))?;
+
+    let machine = MachineService::new(&state.db.connection)
+        .create_machine(payload)
+        .await?;
+    Ok(Json(machine))
+}
+
+pub async fn get_machine(
+    State(state): State<AppState>,
+    Path(machine_id): Path<Uuid>,
+) -> Result<Json<Machine>, AppError> {
+    MachineService::new(&state.db.connection)
+        .find_by_id(machine_id)
+        .await?
+        .map(Json)
+        .ok_or(AppError::NotFound("Machine not found".to_string()))
+}
+
+pub async fn update_machine(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(machine_id): Path<Uuid>,
+    Json(payload): Json<UpdateMachineRequest>,
+) -> Result<Json<Machine>, AppError> {
+    require_manager(&headers, &state.config.jwt_secret)?;
+    payload
+        .validate()
+        .map_err(|e| AppError::ValidationError(e.to_string()))?;
+
+    let machine = MachineService::new(&state.db.connection)
+        .update_machine(machine_id, payload)
+        .await?;
+    Ok(Json(machine))
+}
+
+pub async fn delete_machine(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(machine_id): Path<Uuid>,
+) -> Result<StatusCode, AppError> {
+    require_manager(&headers, &state.config.jwt_secret)?;
+    MachineService::new(&state.db.connection)
+        .delete_machine(machine_id)
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -1,6 +1,12 @@
+pub mod attachments;
 pub mod auth;
+pub mod comments;
 pub mod costing;
 pub mod currency;
+pub mod customers;
 pub mod jobs;
+pub mod job_templates;
+pub mod machines;
+pub mod schedule;
 pub mod settings;
 pub mod export;
@@ -0,0 +1,78 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::{HeaderMap, StatusCode},
+    response::Json,
+};
+use chrono::NaiveDate;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::{
+    handlers::{auth::require_manager, jobs},
+    models::{CreateHolidayRequest, Holiday, JobSchedule, ProductionSchedule, ScheduleQuery},
+    services::schedule_service::{ScheduleService, MAX_CALENDAR_DAYS},
+    utils::errors::AppError,
+    AppState,
+};
+
+const DEFAULT_CALENDAR_DAYS: i64 = 14;
+
+/// What runs on which machine each day, with the planned completion of
+/// every approved and in-production job.
+pub async fn get_schedule(
+    State(state): State<AppState>,
+    Query(query): Query<ScheduleQuery>,
+) -> Result<Json<ProductionSchedule>, AppError> {
+    let days = query.days.unwrap_or(DEFAULT_CALENDAR_DAYS);
+    if !(1..=MAX_CALENDAR_DAYS).contains(&days) {
+        return Err(AppError::ValidationError(format!("Days must be between 1 and {}", MAX_CALENDAR_DAYS)));
+    }
+    let from = query.from.unwrap_or_else(|| chrono::Utc::now().date_naive());
+
+    let schedule = ScheduleService::new(&state.db.connection)
+        .production_schedule(jobs::scheduled_jobs()?, from, days)
+        .await?;
+    Ok(Json(schedule))
+}
+
+/// When the job would start and finish behind the current workload. Jobs
+/// not yet approved are estimated as if they were approved today.
+pub async fn get_job_schedule(
+    State(state): State<AppState>,
+    Path(job_id): Path<Uuid>,
+) -> Result<Json<JobSchedule>, AppError> {
+    let job = jobs::find_job(job_id)?;
+    let estimate = ScheduleService::new(&state.db.connection)
+        .estimate(jobs::scheduled_jobs()?, job, chrono::Utc::now().date_naive())
+        .await?;
+    Ok(Json(estimate))
+}
+
+pub async fn list_holidays(State(state): State<AppState>) -> Result<Json<Vec<Holiday>>, AppError> {
+    let holidays = ScheduleService::new(&state.db.connection).list_holidays().await?;
+    Ok(Json(holidays))
+}
+
+pub async fn create_holiday(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(payload): Json<CreateHolidayRequest>,
+) -> Result<(StatusCode, Json<Holiday>), AppError> {
+    require_manager(&headers, &state.config.jwt_secret)?;
+    payload
+        .validate()
+        .map_err(|e| AppError::ValidationError(e.to_string()))?;
+
+    let holiday = ScheduleService::new(&state.db.connection).add_holiday(payload).await?;
+    Ok((StatusCode::CREATED, Json(holiday)))
+}
+
+pub async fn delete_holiday(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(date): Path<NaiveDate>,
+) -> Result<StatusCode, AppError> {
+    require_manager(&headers, &state.config.jwt_secret)?;
+    ScheduleService::new(&state.db.connection).delete_holiday(date).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
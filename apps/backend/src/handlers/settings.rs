@@ -1,64 +1,79 @@
 use axum::{
     extract::State,
+    http::HeaderMap,
     response::Json,
     Extension,
 };
 use validator::Validate;
 
 use crate::{
+    handlers::auth::require_manager,
     models::{
-        BrandingSettings, CostParameters, UpdateBrandingRequest, UpdateCostParametersRequest,
-        UserInfo, UserRole,
+        BrandingSettings, CostParameters, FinishingOperation, TaxSettings, UpdateBrandingRequest,
+        UpdateCostParametersRequest, UpdateTaxSettingsRequest, UserInfo, UserRole,
+    },
+    services::{
+        branding_service::BrandingService, cost_parameters_service::CostParametersService,
+        tax_settings_service::TaxSettingsService,
     },
-    services::{branding_service::BrandingService, cost_parameters_service::CostParametersService},
     utils::errors::AppError,
     AppState,
 };
 
-pub async fn get_cost_parameters() -> Result<Json<CostParameters>, AppError> {
-    // Placeholder implementation
-    use crate::models::CostParameters;
-    use bigdecimal::BigDecimal;
-    use std::str::FromStr;
-    
-    let parameters = CostParameters {
-        id: uuid::Uuid::new_v4(),
-        paper_cost_per_sheet: BigDecimal::from_str("0.10").unwrap(),
-        plate_cost_per_job: BigDecimal::from_str("25.00").unwrap(),
-        labor_cost_per_hour: BigDecimal::from_str("15.00").unwrap(),
-        binding_cost_per_unit: BigDecimal::from_str("0.50").unwrap(),
-        overhead_percentage: BigDecimal::from_str("0.15").unwrap(),
-        profit_margin_percentage: BigDecimal::from_str("0.20").unwrap(),
-        created_at: chrono::Utc::now(),
-        updated_at: chrono::Utc::now(),
-    };
-    
+pub async fn get_cost_parameters(
+    State(state): State<AppState>,
+) -> Result<Json<CostParameters>, AppError> {
+    let parameters = CostParametersService::new(&state.db.connection)
+        .get_current_parameters()
+        .await?;
+
     Ok(Json(parameters))
 }
 
 pub async fn update_cost_parameters(
+    State(state): State<AppState>,
+    headers: HeaderMap,
     Json(payload): Json<UpdateCostParametersRequest>,
 ) -> Result<Json<CostParameters>, AppError> {
-    use crate::models::CostParameters;
-    use bigdecimal::BigDecimal;
-    use std::str::FromStr;
-    
-    // Return updated parameters (in a real app, this would save to database)
-    let parameters = CostParameters {
-        id: uuid::Uuid::new_v4(),
-        paper_cost_per_sheet: payload.paper_cost_per_sheet.unwrap_or_else(|| BigDecimal::from_str("0.10").unwrap()),
-        plate_cost_per_job: payload.plate_cost_per_job.unwrap_or_else(|| BigDecimal::from_str("25.00").unwrap()),
-        labor_cost_per_hour: payload.labor_cost_per_hour.unwrap_or_else(|| BigDecimal::from_str("15.00").unwrap()),
-        binding_cost_per_unit: payload.binding_cost_per_unit.unwrap_or_else(|| BigDecimal::from_str("0.50").unwrap()),
-        overhead_percentage: payload.overhead_percentage.unwrap_or_else(|| BigDecimal::from_str("0.15").unwrap()),
-        profit_margin_percentage: payload.profit_margin_percentage.unwrap_or_else(|| BigDecimal::from_str("0.20").unwrap()),
-        created_at: chrono::Utc::now(),
-        updated_at: chrono::Utc::now(),
-    };
-    
+    require_manager(&headers, &state.config.jwt_secret)?;
+    let parameters = CostParametersService::new(&state.db.connection)
+        .update_parameters(payload)
+        .await?;
+
     Ok(Json(parameters))
 }
 
+pub async fn get_finishing_operations(
+    State(state): State<AppState>,
+) -> Result<Json<Vec<FinishingOperation>>, AppError> {
+    let parameters = CostParametersService::new(&state.db.connection)
+        .get_current_parameters()
+        .await?;
+
+    Ok(Json(parameters.finishing_operations))
+}
+
+pub async fn get_tax_settings(
+    State(state): State<AppState>,
+) -> Result<Json<TaxSettings>, AppError> {
+    let settings = TaxSettingsService::new(&state.db.connection).get_settings().await?;
+
+    Ok(Json(settings))
+}
+
+pub async fn update_tax_settings(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(payload): Json<UpdateTaxSettingsRequest>,
+) -> Result<Json<TaxSettings>, AppError> {
+    require_manager(&headers, &state.config.jwt_secret)?;
+    let settings = TaxSettingsService::new(&state.db.connection)
+        .update_settings(payload)
+        .await?;
+
+    Ok(Json(settings))
+}
+
 pub async fn get_branding() -> Result<Json<BrandingSettings>, AppError> {
     // Placeholder implementation
     use crate::models::BrandingSettings;
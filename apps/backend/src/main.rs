@@ -1,5 +1,5 @@
 use axum::{
-    extract::State,
+    extract::{DefaultBodyLimit, State},
     http::StatusCode,
     response::Json,
     routing::{get, post, put, delete},
@@ -21,11 +21,19 @@ mod utils;
 
 use config::Config;
 use db::Database;
+use services::file_store::{self, FileStore};
+
+/// Body limit for batch costing, which carries thousands of specifications.
+const BATCH_BODY_LIMIT: usize = 16 * 1024 * 1024;
+
+/// Room for the multipart framing around an attachment upload.
+const UPLOAD_OVERHEAD: usize = 64 * 1024;
 
 #[derive(Clone)]
 pub struct AppState {
     pub db: Database,
     pub config: Config,
+    pub files: Arc<dyn FileStore>,
 }
 
 #[derive(Serialize)]
@@ -89,7 +97,11 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     //     tracing::error!("Failed to run database migrations: {}", e);
     //     e
     // })?;
-    let app_state = AppState { db, config };
+    let files = file_store::from_config(&config)?;
+    info!("File storage: {}", config.file_storage);
+
+    let upload_limit = config.max_attachment_bytes + UPLOAD_OVERHEAD;
+    let app_state = AppState { db, config, files };
 
     // Build our application with routes
     let app = Router::new()
@@ -99,22 +111,77 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .route("/api/auth/me", get(handlers::auth::me))
         .route("/api/jobs", get(handlers::jobs::list_jobs))
         .route("/api/jobs", post(handlers::jobs::create_job))
+        .route("/api/jobs/import", post(handlers::jobs::import_jobs))
         .route("/api/jobs/:id", get(handlers::jobs::get_job))
         .route("/api/jobs/:id", put(handlers::jobs::update_job))
         .route("/api/jobs/:id", delete(handlers::jobs::delete_job))
+        .route("/api/jobs/:id/approve-margin", post(handlers::jobs::approve_margin))
+        .route("/api/jobs/:id/revisions", get(handlers::jobs::list_revisions))
+        .route("/api/jobs/:id/revisions/diff", get(handlers::jobs::diff_revisions))
+        .route("/api/jobs/:id/revisions/:revision", get(handlers::jobs::get_revision))
+        .route("/api/jobs/:id/template", post(handlers::jobs::save_as_template))
+        .route("/api/jobs/:id/duplicate", post(handlers::jobs::duplicate_job))
+        .route("/api/jobs/:id/reorders", get(handlers::jobs::reorder_chain))
+        .route("/api/jobs/:id/schedule", get(handlers::schedule::get_job_schedule))
+        .route(
+            "/api/jobs/:id/attachments",
+            post(handlers::attachments::upload_attachment).layer(DefaultBodyLimit::max(upload_limit)),
+        )
+        .route("/api/jobs/:id/attachments", get(handlers::attachments::list_attachments))
+        .route("/api/jobs/:id/attachments/:attachment_id", get(handlers::attachments::download_attachment))
+        .route("/api/jobs/:id/attachments/:attachment_id", delete(handlers::attachments::delete_attachment))
+        .route("/api/jobs/:id/attachments/:attachment_id/analysis", get(handlers::attachments::analyze_attachment))
+        .route("/api/jobs/:id/comments", get(handlers::comments::list_comments))
+        .route("/api/jobs/:id/comments", post(handlers::comments::create_comment))
+        .route("/api/jobs/:id/comments/:comment_id", put(handlers::comments::update_comment))
+        .route("/api/jobs/:id/comments/:comment_id", delete(handlers::comments::delete_comment))
+        .route("/api/jobs/:id/comments/:comment_id/history", get(handlers::comments::comment_history))
+        .route("/api/comments/mentions", get(handlers::comments::my_mentions))
+        .route("/api/job-templates", get(handlers::job_templates::list_templates))
+        .route("/api/job-templates", post(handlers::job_templates::create_template))
+        .route("/api/job-templates/:id", get(handlers::job_templates::get_template))
+        .route("/api/job-templates/:id", put(handlers::job_templates::update_template))
+        .route("/api/job-templates/:id", delete(handlers::job_templates::delete_template))
+        .route("/api/job-templates/:id/jobs", post(handlers::job_templates::create_job_from_template))
         .route("/api/cost/calculate", post(handlers::costing::calculate_cost))
         .route("/api/cost/preview", post(handlers::costing::preview_cost))
         .route("/api/cost/quick", post(handlers::costing::quick_calculate))
+        .route("/api/cost/budget", post(handlers::costing::fit_budget))
+        .route(
+            "/api/cost/batch",
+            post(handlers::costing::calculate_batch).layer(DefaultBodyLimit::max(BATCH_BODY_LIMIT)),
+        )
         .route("/api/currency/supported", get(handlers::currency::get_supported_currencies))
+        .route("/api/currency/registry", get(handlers::currency::get_currency_registry))
         .route("/api/currency/rates", get(handlers::currency::get_exchange_rates))
         .route("/api/currency/convert", get(handlers::currency::convert_currency))
         .route("/api/currency/settings", get(handlers::currency::get_currency_settings))
+        .route("/api/currency/settings", put(handlers::currency::update_currency_settings))
+        .route("/api/currency/preference", get(handlers::currency::get_currency_preference))
+        .route("/api/currency/preference", put(handlers::currency::update_currency_preference))
+        .route("/api/customers", get(handlers::customers::list_customers))
+        .route("/api/customers", post(handlers::customers::create_customer))
+        .route("/api/customers/:id", get(handlers::customers::get_customer))
+        .route("/api/customers/:id", put(handlers::customers::update_customer))
+        .route("/api/machines", get(handlers::machines::list_machines))
+        .route("/api/machines", post(handlers::machines::create_machine))
+        .route("/api/machines/:id", get(handlers::machines::get_machine))
+        .route("/api/machines/:id", put(handlers::machines::update_machine))
+        .route("/api/machines/:id", delete(handlers::machines::delete_machine))
+        .route("/api/schedule", get(handlers::schedule::get_schedule))
+        .route("/api/schedule/holidays", get(handlers::schedule::list_holidays))
+        .route("/api/schedule/holidays", post(handlers::schedule::create_holiday))
+        .route("/api/schedule/holidays/:date", delete(handlers::schedule::delete_holiday))
         .route("/api/settings/cost-parameters", get(handlers::settings::get_cost_parameters))
         .route("/api/settings/cost-parameters", put(handlers::settings::update_cost_parameters))
+        .route("/api/settings/finishing-operations", get(handlers::settings::get_finishing_operations))
+        .route("/api/settings/tax", get(handlers::settings::get_tax_settings))
+        .route("/api/settings/tax", put(handlers::settings::update_tax_settings))
         .route("/api/settings/branding", get(handlers::settings::get_branding))
         .route("/api/settings/branding", put(handlers::settings::update_branding))
         .route("/api/export/pdf/:job_id", post(handlers::export::export_pdf))
         .route("/api/export/excel/:job_id", post(handlers::export::export_excel))
+        .route("/api/export/ticket/:job_id", post(handlers::export::export_ticket))
         .layer(CorsLayer::permissive())
         .with_state(app_state);
 
@@ -0,0 +1,78 @@
+use bigdecimal::BigDecimal;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DiscountKind {
+    /// `value` is a fraction of the selling price.
+    Percentage,
+    /// `value` is an amount in the base currency.
+    Fixed,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Discount {
+    pub kind: DiscountKind,
+    pub value: BigDecimal,
+}
+
+impl Discount {
+    /// Amount taken off `price`, never more than the price itself.
+    pub fn amount(&self, price: &BigDecimal) -> BigDecimal {
+        let amount = match self.kind {
+            DiscountKind::Percentage => price * &self.value,
+            DiscountKind::Fixed => self.value.clone(),
+        };
+        amount.min(price.clone())
+    }
+}
+
+/// Commercial terms a quote is requested under.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct QuoteTerms {
+    #[serde(rename = "promoCode")]
+    pub promo_code: Option<String>,
+    /// Date the customer needs the job; earlier than the estimate means a
+    /// rush surcharge.
+    #[serde(rename = "requestedDeliveryDate")]
+    pub requested_delivery_date: Option<NaiveDate>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AdjustmentKind {
+    VolumeDiscount,
+    CustomerDiscount,
+    PromoDiscount,
+    RushSurcharge,
+    SmallQuantitySurcharge,
+    MinimumOrderCharge,
+}
+
+/// One commercial rule applied to a quote. Discounts carry a negative
+/// amount.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AdjustmentLine {
+    pub kind: AdjustmentKind,
+    pub description: String,
+    /// Fraction of the selling price, for percentage rules.
+    pub rate: Option<BigDecimal>,
+    pub amount: BigDecimal,
+}
+
+impl AdjustmentLine {
+    pub fn new(kind: AdjustmentKind, description: impl Into<String>, amount: BigDecimal) -> Self {
+        Self {
+            kind,
+            description: description.into(),
+            rate: None,
+            amount,
+        }
+    }
+
+    pub fn with_rate(mut self, rate: BigDecimal) -> Self {
+        self.rate = Some(rate);
+        self
+    }
+}
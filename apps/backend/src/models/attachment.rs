@@ -0,0 +1,121 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AttachmentKind {
+    /// Print-ready files supplied by the customer.
+    Artwork,
+    /// Proofs sent to the customer for sign-off.
+    Proof,
+    /// Samples, mock-ups and other reference images.
+    Reference,
+    Other,
+}
+
+impl std::fmt::Display for AttachmentKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AttachmentKind::Artwork => write!(f, "artwork"),
+            AttachmentKind::Proof => write!(f, "proof"),
+            AttachmentKind::Reference => write!(f, "reference"),
+            AttachmentKind::Other => write!(f, "other"),
+        }
+    }
+}
+
+impl std::str::FromStr for AttachmentKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "artwork" => Ok(AttachmentKind::Artwork),
+            "proof" => Ok(AttachmentKind::Proof),
+            "reference" => Ok(AttachmentKind::Reference),
+            "other" => Ok(AttachmentKind::Other),
+            _ => Err(format!("Invalid attachment kind: {}", s)),
+        }
+    }
+}
+
+/// A file kept with a job. The content lives in the file store under
+/// `storage_key`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JobAttachment {
+    pub id: Uuid,
+    #[serde(rename = "jobId")]
+    pub job_id: Uuid,
+    pub kind: AttachmentKind,
+    pub filename: String,
+    #[serde(rename = "mimeType")]
+    pub mime_type: String,
+    #[serde(rename = "sizeBytes")]
+    pub size_bytes: i64,
+    /// Hex SHA-256 of the content.
+    pub checksum: String,
+    #[serde(rename = "uploadedBy")]
+    pub uploaded_by: Option<Uuid>,
+    #[serde(skip)]
+    pub storage_key: String,
+    #[serde(rename = "createdAt")]
+    pub created_at: DateTime<Utc>,
+}
+
+/// Width and height of a PDF page box, in mm, as the page is displayed.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PageBox {
+    #[serde(rename = "widthMm")]
+    pub width_mm: f64,
+    #[serde(rename = "heightMm")]
+    pub height_mm: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ArtworkWarningKind {
+    /// The page count does not fill whole signatures.
+    SignatureMultiple,
+    MissingBleed,
+    /// Bleed is present but narrower than the press needs.
+    InsufficientBleed,
+    MixedPageSizes,
+    /// The trim size differs from the job's paper size.
+    SizeMismatch,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArtworkWarning {
+    pub kind: ArtworkWarningKind,
+    pub message: String,
+}
+
+/// What a PDF attachment says about the job: its page count and trim, and
+/// the specification values it suggests. Sizes are taken from the first
+/// page.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArtworkAnalysis {
+    #[serde(rename = "attachmentId")]
+    pub attachment_id: Uuid,
+    #[serde(rename = "pageCount")]
+    pub page_count: i32,
+    #[serde(rename = "mediaBox")]
+    pub media_box: PageBox,
+    /// The TrimBox, or the CropBox or MediaBox when the file has none.
+    #[serde(rename = "trimBox")]
+    pub trim_box: PageBox,
+    #[serde(rename = "bleedBox")]
+    pub bleed_box: Option<PageBox>,
+    /// Narrowest bleed on any side of the trim.
+    #[serde(rename = "bleedMm")]
+    pub bleed_mm: f64,
+    #[serde(rename = "hasBleed")]
+    pub has_bleed: bool,
+    #[serde(rename = "suggestedPages")]
+    pub suggested_pages: i32,
+    /// A named size such as "A4" when the trim matches one, otherwise
+    /// "<width>x<height>mm".
+    #[serde(rename = "suggestedPaperSize")]
+    pub suggested_paper_size: String,
+    pub warnings: Vec<ArtworkWarning>,
+}
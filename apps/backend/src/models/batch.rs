@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::{CostCalculationRequest, CostCalculationResponse};
+
+/// Quotes priced together against one snapshot of parameters and rates,
+/// e.g. every size, stock and quantity of a web shop product.
+#[derive(Debug, Deserialize)]
+pub struct BatchCostRequest {
+    pub items: Vec<CostCalculationRequest>,
+}
+
+/// Outcome for the item at `index` in the request: a result or an error.
+#[derive(Debug, Serialize)]
+pub struct BatchCostItem {
+    pub index: usize,
+    pub result: Option<CostCalculationResponse>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BatchCostResponse {
+    /// Version of the cost parameters every item was priced with.
+    #[serde(rename = "parametersVersion")]
+    pub parameters_version: Option<i32>,
+    pub succeeded: usize,
+    pub failed: usize,
+    /// In request order.
+    pub items: Vec<BatchCostItem>,
+}
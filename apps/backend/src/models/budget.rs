@@ -0,0 +1,91 @@
+use bigdecimal::BigDecimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::models::{CostBreakdown, Currency, JobSpecifications, JobType, PriceSummary, PrintProcess, QuoteTerms, TaxSummary};
+
+/// A job and a target price; the costing engine searches for the largest
+/// quantity, and optionally other stocks and finishes, that fit it.
+#[derive(Debug, Deserialize)]
+pub struct BudgetRequest {
+    #[serde(rename = "jobType")]
+    pub job_type: JobType,
+    pub specifications: JobSpecifications,
+    pub budget: BigDecimal,
+    /// Currency the budget is given in; falls back to the customer, user and
+    /// company defaults when omitted.
+    pub currency: Option<Currency>,
+    #[serde(rename = "customerId")]
+    pub customer_id: Option<Uuid>,
+    #[serde(flatten)]
+    pub terms: QuoteTerms,
+    /// Compare the budget with the price including tax.
+    #[serde(rename = "includesTax", default)]
+    pub includes_tax: bool,
+    /// Paper stocks to try besides the one in `specifications`.
+    #[serde(default)]
+    pub stocks: Vec<StockOption>,
+    /// Also try without lamination and without each finishing operation.
+    #[serde(rename = "varyFinishing", default)]
+    pub vary_finishing: bool,
+    /// Number of options returned; 5 when omitted.
+    pub limit: Option<usize>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct StockOption {
+    #[serde(rename = "paperType")]
+    pub paper_type: String,
+    #[serde(rename = "paperWeight")]
+    pub paper_weight: Option<String>,
+}
+
+/// A specification variant and the most copies of it the budget buys.
+#[derive(Debug, Clone, Serialize)]
+pub struct BudgetOption {
+    /// What differs from the requested specification.
+    pub description: String,
+    pub quantity: i32,
+    pub specifications: JobSpecifications,
+    pub process: PrintProcess,
+    #[serde(rename = "costBreakdown")]
+    pub cost_breakdown: CostBreakdown,
+    /// Quoted price before tax.
+    #[serde(rename = "totalCost")]
+    pub total_cost: BigDecimal,
+    #[serde(rename = "unitCost")]
+    pub unit_cost: BigDecimal,
+    pub pricing: PriceSummary,
+    pub tax: TaxSummary,
+    /// Budget left after this option.
+    pub remaining: BigDecimal,
+    /// Quantity from which offset becomes cheaper than digital, when both
+    /// are eligible and the lines cross.
+    #[serde(rename = "crossoverQuantity")]
+    pub crossover_quantity: Option<i32>,
+}
+
+impl BudgetOption {
+    /// Multiplies the amounts by `factor`, e.g. an exchange rate.
+    pub fn scaled(&self, factor: &BigDecimal) -> BudgetOption {
+        BudgetOption {
+            cost_breakdown: self.cost_breakdown.scaled(factor),
+            total_cost: &self.total_cost * factor,
+            unit_cost: &self.unit_cost * factor,
+            pricing: self.pricing.scaled(factor),
+            tax: self.tax.scaled(factor),
+            remaining: &self.remaining * factor,
+            ..self.clone()
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct BudgetResponse {
+    pub budget: BigDecimal,
+    pub currency: Currency,
+    #[serde(rename = "exchangeRate")]
+    pub exchange_rate: f64,
+    /// Best options first: most copies, then lowest price.
+    pub options: Vec<BudgetOption>,
+}
@@ -0,0 +1,79 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::models::Job;
+
+/// A note on a job. Internal comments stay with staff; customer-visible
+/// ones may be printed on the quote.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JobComment {
+    pub id: Uuid,
+    #[serde(rename = "jobId")]
+    pub job_id: Uuid,
+    #[serde(rename = "authorId")]
+    pub author_id: Uuid,
+    /// The author's full name, or their email when no name is on file.
+    #[serde(rename = "authorName")]
+    pub author_name: Option<String>,
+    pub body: String,
+    #[serde(rename = "customerVisible")]
+    pub customer_visible: bool,
+    /// Users named with `@` in the body.
+    pub mentions: Vec<Uuid>,
+    /// Whether the comment has been changed since it was posted.
+    pub edited: bool,
+    #[serde(rename = "createdAt")]
+    pub created_at: DateTime<Utc>,
+    #[serde(rename = "updatedAt")]
+    pub updated_at: DateTime<Utc>,
+}
+
+/// A comment as it read before one edit.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommentEdit {
+    pub id: Uuid,
+    #[serde(rename = "commentId")]
+    pub comment_id: Uuid,
+    pub body: String,
+    #[serde(rename = "customerVisible")]
+    pub customer_visible: bool,
+    #[serde(rename = "editedBy")]
+    pub edited_by: Uuid,
+    #[serde(rename = "editedAt")]
+    pub edited_at: DateTime<Utc>,
+}
+
+/// A job with its comment thread, oldest first.
+#[derive(Debug, Clone, Serialize)]
+pub struct JobDetail {
+    #[serde(flatten)]
+    pub job: Job,
+    pub comments: Vec<JobComment>,
+}
+
+/// Mention users as `@jane@example.com`, or as `@jane` when only one user's
+/// email starts with `jane@`.
+#[derive(Debug, Deserialize, Validate)]
+pub struct CreateCommentRequest {
+    #[validate(length(min = 1, max = 5000))]
+    pub body: String,
+    #[serde(rename = "customerVisible", default)]
+    pub customer_visible: bool,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct UpdateCommentRequest {
+    #[validate(length(min = 1, max = 5000))]
+    pub body: Option<String>,
+    #[serde(rename = "customerVisible")]
+    pub customer_visible: Option<bool>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct QuoteExportQuery {
+    /// Print the job's customer-visible comments on the quote.
+    #[serde(rename = "includeComments", default)]
+    pub include_comments: bool,
+}
@@ -1,36 +1,367 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use bigdecimal::BigDecimal;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::str::FromStr;
 use uuid::Uuid;
 
+use crate::models::{Discount, JobType, PrintProcess};
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CostParameters {
     pub id: Uuid,
+    /// Bumped on every update, so quotes can record what they were priced
+    /// with.
+    #[serde(default = "first_version")]
+    pub version: i32,
     #[serde(rename = "paperCostPerSheet")]
     pub paper_cost_per_sheet: BigDecimal,
-    #[serde(rename = "plateCostPerJob")]
-    pub plate_cost_per_job: BigDecimal,
+    /// Cost of one printing plate; a job needs one per colour on each form.
+    #[serde(rename = "plateCost")]
+    pub plate_cost: BigDecimal,
     #[serde(rename = "laborCostPerHour")]
     pub labor_cost_per_hour: BigDecimal,
     #[serde(rename = "bindingCostPerUnit")]
     pub binding_cost_per_unit: BigDecimal,
     #[serde(rename = "overheadPercentage")]
     pub overhead_percentage: BigDecimal,
+    /// Default profit rate, as a fraction, applied as markup or margin
+    /// according to `pricing.mode`.
     #[serde(rename = "profitMarginPercentage")]
     pub profit_margin_percentage: BigDecimal,
+    pub pricing: PricingParameters,
+    /// Press sheet the per-sheet paper cost refers to.
+    #[serde(rename = "pressSheetWidthMm")]
+    pub press_sheet_width_mm: f64,
+    #[serde(rename = "pressSheetHeightMm")]
+    pub press_sheet_height_mm: f64,
+    /// Spacing kept between items imposed on the same press sheet.
+    #[serde(rename = "gutterMm")]
+    pub gutter_mm: f64,
+    pub book: BookParameters,
+    #[serde(rename = "largeFormat")]
+    pub large_format: LargeFormatParameters,
+    pub sticker: StickerParameters,
+    #[serde(rename = "businessCard")]
+    pub business_card: BusinessCardParameters,
+    pub lamination: LaminationParameters,
+    pub ink: InkParameters,
+    pub digital: DigitalParameters,
+    pub turnaround: TurnaroundParameters,
+    pub adjustments: AdjustmentParameters,
+    /// Operations that `JobSpecifications::finishing` may reference by id.
+    #[serde(rename = "finishingOperations")]
+    pub finishing_operations: Vec<FinishingOperation>,
     #[serde(rename = "createdAt")]
     pub created_at: DateTime<Utc>,
     #[serde(rename = "updatedAt")]
     pub updated_at: DateTime<Utc>,
 }
 
+/// Parameters for [`JobType::Book`](crate::models::JobType::Book).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BookParameters {
+    /// Largest signature (pages per folded press sheet) the bindery folds.
+    #[serde(rename = "signaturePages")]
+    pub signature_pages: i32,
+    #[serde(rename = "coverCostPerSheet")]
+    pub cover_cost_per_sheet: BigDecimal,
+    /// Used when the trim size is unknown and covers cannot be imposed.
+    #[serde(rename = "coversPerSheet")]
+    pub covers_per_sheet: i32,
+    /// Used when no cover colors are specified.
+    #[serde(rename = "coverColors")]
+    pub cover_colors: i32,
+    #[serde(rename = "bindingRates")]
+    pub binding_rates: BindingRates,
+    /// Paper bulk in cm³/g, used to derive caliper from grammage.
+    #[serde(rename = "coatedPaperBulk")]
+    pub coated_paper_bulk: f64,
+    #[serde(rename = "uncoatedPaperBulk")]
+    pub uncoated_paper_bulk: f64,
+    #[serde(rename = "defaultInteriorGsm")]
+    pub default_interior_gsm: i32,
+    #[serde(rename = "caseBoardThicknessMm")]
+    pub case_board_thickness_mm: f64,
+    #[serde(rename = "saddleStitchMaxPages")]
+    pub saddle_stitch_max_pages: i32,
+    #[serde(rename = "perfectBoundMinSpineMm")]
+    pub perfect_bound_min_spine_mm: f64,
+    #[serde(rename = "caseBoundMinPages")]
+    pub case_bound_min_pages: i32,
+    #[serde(rename = "wireOMaxSpineMm")]
+    pub wire_o_max_spine_mm: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BindingRate {
+    #[serde(rename = "setupCost")]
+    pub setup_cost: BigDecimal,
+    #[serde(rename = "costPerUnit")]
+    pub cost_per_unit: BigDecimal,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BindingRates {
+    #[serde(rename = "perfectBound")]
+    pub perfect_bound: BindingRate,
+    #[serde(rename = "saddleStitch")]
+    pub saddle_stitch: BindingRate,
+    #[serde(rename = "caseBound")]
+    pub case_bound: BindingRate,
+    #[serde(rename = "wireO")]
+    pub wire_o: BindingRate,
+}
+
+/// Parameters for roll-fed [`JobType::Banner`](crate::models::JobType::Banner)
+/// and [`JobType::Poster`](crate::models::JobType::Poster) work.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LargeFormatParameters {
+    /// Media on offer; the first entry is used when a job names none.
+    pub media: Vec<MediaType>,
+    /// Ink cost at 100% coverage.
+    #[serde(rename = "inkCostPerSquareMeter")]
+    pub ink_cost_per_square_meter: BigDecimal,
+    #[serde(rename = "printSpeedSquareMetersPerHour")]
+    pub print_speed_square_meters_per_hour: f64,
+    #[serde(rename = "setupCost")]
+    pub setup_cost: BigDecimal,
+    /// Unprintable margin on each side of the roll.
+    #[serde(rename = "rollMarginCm")]
+    pub roll_margin_cm: f64,
+    /// Cutting gap between items nested on the roll.
+    #[serde(rename = "itemGapCm")]
+    pub item_gap_cm: f64,
+    #[serde(rename = "grommetCostEach")]
+    pub grommet_cost_each: BigDecimal,
+    #[serde(rename = "hemmingCostPerMeter")]
+    pub hemming_cost_per_meter: BigDecimal,
+    /// Extra media folded back on each hemmed edge.
+    #[serde(rename = "hemAllowanceCm")]
+    pub hem_allowance_cm: f64,
+    #[serde(rename = "polePocketCostPerMeter")]
+    pub pole_pocket_cost_per_meter: BigDecimal,
+    /// Extra media folded back to form each pole pocket.
+    #[serde(rename = "polePocketAllowanceCm")]
+    pub pole_pocket_allowance_cm: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MediaType {
+    pub name: String,
+    #[serde(rename = "costPerSquareMeter")]
+    pub cost_per_square_meter: BigDecimal,
+    /// Roll widths stocked for this media.
+    #[serde(rename = "rollWidthsCm")]
+    pub roll_widths_cm: Vec<f64>,
+}
+
+/// Parameters for [`JobType::Sticker`](crate::models::JobType::Sticker).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StickerParameters {
+    #[serde(rename = "dieCutSetupCost")]
+    pub die_cut_setup_cost: BigDecimal,
+    #[serde(rename = "dieCutCostPerThousand")]
+    pub die_cut_cost_per_thousand: BigDecimal,
+    #[serde(rename = "kissCutSetupCost")]
+    pub kiss_cut_setup_cost: BigDecimal,
+    #[serde(rename = "kissCutCostPerThousand")]
+    pub kiss_cut_cost_per_thousand: BigDecimal,
+    /// Self-adhesive stock costs more than the standard sheet.
+    #[serde(rename = "labelStockCostPerSheet")]
+    pub label_stock_cost_per_sheet: BigDecimal,
+}
+
+/// Parameters for gang-run [`JobType::BusinessCard`](crate::models::JobType::BusinessCard) work.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BusinessCardParameters {
+    /// Separate jobs sharing one gang sheet, and so one set of plates.
+    #[serde(rename = "jobsPerGangSheet")]
+    pub jobs_per_gang_sheet: i32,
+}
+
+/// Offset ink usage, estimated from sheet area and coverage.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InkParameters {
+    #[serde(rename = "processInkCostPerKg")]
+    pub process_ink_cost_per_kg: BigDecimal,
+    #[serde(rename = "spotInkCostPerKg")]
+    pub spot_ink_cost_per_kg: BigDecimal,
+    /// Ink laid down per color on a square meter printed at 100% coverage.
+    #[serde(rename = "gramsPerSquareMeter")]
+    pub grams_per_square_meter: f64,
+    /// Coverage per color when the job does not estimate it.
+    #[serde(rename = "defaultCoveragePercent")]
+    pub default_coverage_percent: f64,
+}
+
+/// How the profit rate turns full cost (production plus overhead) into a
+/// selling price.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProfitMode {
+    /// Profit is the rate times cost.
+    Markup,
+    /// Profit is the rate times the selling price.
+    Margin,
+}
+
+impl std::fmt::Display for ProfitMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ProfitMode::Markup => write!(f, "markup"),
+            ProfitMode::Margin => write!(f, "margin"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PricingParameters {
+    pub mode: ProfitMode,
+    /// Profit rates replacing the default for particular job types. A
+    /// customer's own rate takes precedence over these.
+    #[serde(rename = "jobTypeRates")]
+    pub job_type_rates: HashMap<JobType, BigDecimal>,
+    /// Quotes whose margin on the selling price falls below this fraction
+    /// need a manager's approval.
+    #[serde(rename = "minimumMarginPercentage")]
+    pub minimum_margin_percentage: BigDecimal,
+}
+
+/// Click charges and stock limits for the digital press.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DigitalParameters {
+    /// Charged per printed side of a press sheet.
+    #[serde(rename = "colorClickCost")]
+    pub color_click_cost: BigDecimal,
+    #[serde(rename = "monoClickCost")]
+    pub mono_click_cost: BigDecimal,
+    /// Heaviest stock the digital press feeds.
+    #[serde(rename = "maxGsm")]
+    pub max_gsm: i32,
+}
+
+/// Working days before production starts, by process, plus the shop's
+/// production hours per day.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TurnaroundParameters {
+    #[serde(rename = "offsetLeadDays")]
+    pub offset_lead_days: i32,
+    #[serde(rename = "digitalLeadDays")]
+    pub digital_lead_days: i32,
+    #[serde(rename = "wideFormatLeadDays")]
+    pub wide_format_lead_days: i32,
+    #[serde(rename = "productionHoursPerDay")]
+    pub production_hours_per_day: f64,
+}
+
+impl TurnaroundParameters {
+    pub fn lead_days(&self, process: PrintProcess) -> i32 {
+        match process {
+            PrintProcess::Offset => self.offset_lead_days,
+            PrintProcess::Digital => self.digital_lead_days,
+            PrintProcess::WideFormat => self.wide_format_lead_days,
+        }
+    }
+
+    /// Lead time plus whole production days for `production_hours`.
+    pub fn delivery_days(&self, process: PrintProcess, production_hours: f64) -> i32 {
+        let production_days = (production_hours / self.production_hours_per_day.max(1.0)).ceil() as i32;
+        self.lead_days(process) + production_days.max(1)
+    }
+}
+
+/// Commercial rules applied to the selling price after costing.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AdjustmentParameters {
+    /// Discounts by order quantity; only the highest tier reached applies.
+    #[serde(rename = "volumeDiscounts")]
+    pub volume_discounts: Vec<VolumeDiscount>,
+    #[serde(rename = "promoCodes")]
+    pub promo_codes: Vec<PromoCode>,
+    /// Quotes below this are topped up to it.
+    #[serde(rename = "minimumOrderValue")]
+    pub minimum_order_value: BigDecimal,
+    /// Fraction of the selling price added for each day a requested
+    /// delivery is earlier than the estimate.
+    #[serde(rename = "rushSurchargePerDay")]
+    pub rush_surcharge_per_day: BigDecimal,
+    #[serde(rename = "maxRushSurcharge")]
+    pub max_rush_surcharge: BigDecimal,
+    /// Orders below this quantity carry the small-quantity surcharge.
+    #[serde(rename = "smallQuantityThreshold")]
+    pub small_quantity_threshold: i32,
+    #[serde(rename = "smallQuantitySurcharge")]
+    pub small_quantity_surcharge: BigDecimal,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VolumeDiscount {
+    #[serde(rename = "minQuantity")]
+    pub min_quantity: i32,
+    /// Fraction of the selling price.
+    pub percentage: BigDecimal,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PromoCode {
+    pub code: String,
+    pub discount: Discount,
+    /// Last day the code can be used.
+    #[serde(rename = "validUntil")]
+    pub valid_until: Option<NaiveDate>,
+}
+
+/// Film prices per m² of laminated press sheet, per side.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LaminationParameters {
+    #[serde(rename = "glossCostPerSquareMeter")]
+    pub gloss_cost_per_square_meter: BigDecimal,
+    #[serde(rename = "matteCostPerSquareMeter")]
+    pub matte_cost_per_square_meter: BigDecimal,
+    #[serde(rename = "softTouchCostPerSquareMeter")]
+    pub soft_touch_cost_per_square_meter: BigDecimal,
+    #[serde(rename = "antiScuffCostPerSquareMeter")]
+    pub anti_scuff_cost_per_square_meter: BigDecimal,
+    #[serde(rename = "setupCost")]
+    pub setup_cost: BigDecimal,
+    /// Lighter uncoated stock curls and cockles under film.
+    #[serde(rename = "minUncoatedGsm")]
+    pub min_uncoated_gsm: i32,
+}
+
+/// A priced entry in the finishing catalog.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FinishingOperation {
+    pub id: String,
+    pub name: String,
+    #[serde(rename = "setupCost")]
+    pub setup_cost: BigDecimal,
+    pub rate: BigDecimal,
+    #[serde(rename = "rateBasis")]
+    pub rate_basis: RateBasis,
+    #[serde(rename = "minimumCharge")]
+    pub minimum_charge: BigDecimal,
+    /// Machine make-ready, charged at the labor rate.
+    #[serde(rename = "setupMinutes")]
+    pub setup_minutes: f64,
+    #[serde(rename = "unitsPerHour")]
+    pub units_per_hour: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RateBasis {
+    PerUnit,
+    PerThousand,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct UpdateCostParametersRequest {
     #[serde(rename = "paperCostPerSheet")]
     pub paper_cost_per_sheet: Option<BigDecimal>,
-    #[serde(rename = "plateCostPerJob")]
-    pub plate_cost_per_job: Option<BigDecimal>,
+    #[serde(rename = "plateCost")]
+    pub plate_cost: Option<BigDecimal>,
     #[serde(rename = "laborCostPerHour")]
     pub labor_cost_per_hour: Option<BigDecimal>,
     #[serde(rename = "bindingCostPerUnit")]
@@ -39,22 +370,285 @@ pub struct UpdateCostParametersRequest {
     pub overhead_percentage: Option<BigDecimal>,
     #[serde(rename = "profitMarginPercentage")]
     pub profit_margin_percentage: Option<BigDecimal>,
+    pub pricing: Option<PricingParameters>,
+    #[serde(rename = "pressSheetWidthMm")]
+    pub press_sheet_width_mm: Option<f64>,
+    #[serde(rename = "pressSheetHeightMm")]
+    pub press_sheet_height_mm: Option<f64>,
+    #[serde(rename = "gutterMm")]
+    pub gutter_mm: Option<f64>,
+    pub book: Option<BookParameters>,
+    #[serde(rename = "largeFormat")]
+    pub large_format: Option<LargeFormatParameters>,
+    pub sticker: Option<StickerParameters>,
+    #[serde(rename = "businessCard")]
+    pub business_card: Option<BusinessCardParameters>,
+    pub lamination: Option<LaminationParameters>,
+    pub ink: Option<InkParameters>,
+    pub digital: Option<DigitalParameters>,
+    pub turnaround: Option<TurnaroundParameters>,
+    pub adjustments: Option<AdjustmentParameters>,
+    #[serde(rename = "finishingOperations")]
+    pub finishing_operations: Option<Vec<FinishingOperation>>,
 }
 
 impl Default for CostParameters {
     fn default() -> Self {
-        use std::str::FromStr;
-        
         CostParameters {
             id: Uuid::new_v4(),
+            version: first_version(),
             paper_cost_per_sheet: BigDecimal::from_str("0.10").unwrap(),
-            plate_cost_per_job: BigDecimal::from_str("25.00").unwrap(),
+            plate_cost: BigDecimal::from_str("25.00").unwrap(),
             labor_cost_per_hour: BigDecimal::from_str("15.00").unwrap(),
             binding_cost_per_unit: BigDecimal::from_str("0.50").unwrap(),
             overhead_percentage: BigDecimal::from_str("0.15").unwrap(),
             profit_margin_percentage: BigDecimal::from_str("0.20").unwrap(),
+            pricing: PricingParameters::default(),
+            // SRA3
+            press_sheet_width_mm: 320.0,
+            press_sheet_height_mm: 450.0,
+            gutter_mm: 3.0,
+            book: BookParameters::default(),
+            large_format: LargeFormatParameters::default(),
+            sticker: StickerParameters::default(),
+            business_card: BusinessCardParameters::default(),
+            lamination: LaminationParameters::default(),
+            ink: InkParameters::default(),
+            digital: DigitalParameters::default(),
+            turnaround: TurnaroundParameters::default(),
+            adjustments: AdjustmentParameters::default(),
+            finishing_operations: default_finishing_operations(),
             created_at: Utc::now(),
             updated_at: Utc::now(),
         }
     }
 }
+
+fn first_version() -> i32 {
+    1
+}
+
+impl CostParameters {
+    /// Profit rate for a quote: the customer's own rate, else the job
+    /// type's, else the default.
+    pub fn profit_rate(&self, job_type: &JobType, customer_rate: Option<&BigDecimal>) -> BigDecimal {
+        customer_rate
+            .or_else(|| self.pricing.job_type_rates.get(job_type))
+            .unwrap_or(&self.profit_margin_percentage)
+            .clone()
+    }
+}
+
+impl Default for BookParameters {
+    fn default() -> Self {
+        BookParameters {
+            signature_pages: 16,
+            cover_cost_per_sheet: BigDecimal::from_str("0.35").unwrap(),
+            covers_per_sheet: 2,
+            cover_colors: 4,
+            binding_rates: BindingRates::default(),
+            coated_paper_bulk: 0.9,
+            uncoated_paper_bulk: 1.3,
+            default_interior_gsm: 80,
+            case_board_thickness_mm: 2.5,
+            saddle_stitch_max_pages: 64,
+            perfect_bound_min_spine_mm: 3.0,
+            case_bound_min_pages: 48,
+            wire_o_max_spine_mm: 32.0,
+        }
+    }
+}
+
+impl BindingRate {
+    fn new(setup_cost: &str, cost_per_unit: &str) -> Self {
+        BindingRate {
+            setup_cost: BigDecimal::from_str(setup_cost).unwrap(),
+            cost_per_unit: BigDecimal::from_str(cost_per_unit).unwrap(),
+        }
+    }
+}
+
+impl Default for BindingRates {
+    fn default() -> Self {
+        BindingRates {
+            perfect_bound: BindingRate::new("30.00", "0.50"),
+            saddle_stitch: BindingRate::new("15.00", "0.12"),
+            case_bound: BindingRate::new("80.00", "2.75"),
+            wire_o: BindingRate::new("20.00", "0.90"),
+        }
+    }
+}
+
+impl Default for LargeFormatParameters {
+    fn default() -> Self {
+        LargeFormatParameters {
+            media: vec![
+                MediaType::new("frontlit_vinyl", "4.50", &[137.0, 160.0, 320.0]),
+                MediaType::new("mesh_vinyl", "5.50", &[160.0, 320.0]),
+                MediaType::new("self_adhesive_vinyl", "6.50", &[137.0, 152.0]),
+                MediaType::new("backlit_film", "9.00", &[127.0, 152.0]),
+                MediaType::new("poster_paper", "2.20", &[91.4, 106.7, 152.4]),
+                MediaType::new("canvas", "12.00", &[111.8, 152.4]),
+            ],
+            ink_cost_per_square_meter: BigDecimal::from_str("1.80").unwrap(),
+            print_speed_square_meters_per_hour: 20.0,
+            setup_cost: BigDecimal::from_str("10.00").unwrap(),
+            roll_margin_cm: 1.0,
+            item_gap_cm: 1.0,
+            grommet_cost_each: BigDecimal::from_str("0.35").unwrap(),
+            hemming_cost_per_meter: BigDecimal::from_str("1.20").unwrap(),
+            hem_allowance_cm: 2.5,
+            pole_pocket_cost_per_meter: BigDecimal::from_str("2.50").unwrap(),
+            pole_pocket_allowance_cm: 10.0,
+        }
+    }
+}
+
+impl MediaType {
+    fn new(name: &str, cost_per_square_meter: &str, roll_widths_cm: &[f64]) -> Self {
+        MediaType {
+            name: name.to_string(),
+            cost_per_square_meter: BigDecimal::from_str(cost_per_square_meter).unwrap(),
+            roll_widths_cm: roll_widths_cm.to_vec(),
+        }
+    }
+}
+
+impl Default for StickerParameters {
+    fn default() -> Self {
+        StickerParameters {
+            die_cut_setup_cost: BigDecimal::from_str("40.00").unwrap(),
+            die_cut_cost_per_thousand: BigDecimal::from_str("12.00").unwrap(),
+            kiss_cut_setup_cost: BigDecimal::from_str("25.00").unwrap(),
+            kiss_cut_cost_per_thousand: BigDecimal::from_str("8.00").unwrap(),
+            label_stock_cost_per_sheet: BigDecimal::from_str("0.30").unwrap(),
+        }
+    }
+}
+
+impl Default for BusinessCardParameters {
+    fn default() -> Self {
+        BusinessCardParameters {
+            jobs_per_gang_sheet: 8,
+        }
+    }
+}
+
+impl Default for InkParameters {
+    fn default() -> Self {
+        InkParameters {
+            process_ink_cost_per_kg: BigDecimal::from_str("12.00").unwrap(),
+            spot_ink_cost_per_kg: BigDecimal::from_str("28.00").unwrap(),
+            grams_per_square_meter: 1.5,
+            default_coverage_percent: 25.0,
+        }
+    }
+}
+
+impl Default for PricingParameters {
+    fn default() -> Self {
+        PricingParameters {
+            mode: ProfitMode::Markup,
+            job_type_rates: HashMap::new(),
+            minimum_margin_percentage: BigDecimal::from_str("0.10").unwrap(),
+        }
+    }
+}
+
+impl Default for DigitalParameters {
+    fn default() -> Self {
+        DigitalParameters {
+            color_click_cost: BigDecimal::from_str("0.08").unwrap(),
+            mono_click_cost: BigDecimal::from_str("0.02").unwrap(),
+            max_gsm: 350,
+        }
+    }
+}
+
+impl Default for TurnaroundParameters {
+    fn default() -> Self {
+        TurnaroundParameters {
+            offset_lead_days: 2,
+            digital_lead_days: 1,
+            wide_format_lead_days: 1,
+            production_hours_per_day: 8.0,
+        }
+    }
+}
+
+impl Default for AdjustmentParameters {
+    fn default() -> Self {
+        AdjustmentParameters {
+            volume_discounts: vec![
+                VolumeDiscount { min_quantity: 5000, percentage: BigDecimal::from_str("0.03").unwrap() },
+                VolumeDiscount { min_quantity: 10000, percentage: BigDecimal::from_str("0.05").unwrap() },
+                VolumeDiscount { min_quantity: 25000, percentage: BigDecimal::from_str("0.08").unwrap() },
+            ],
+            promo_codes: Vec::new(),
+            minimum_order_value: BigDecimal::from_str("25.00").unwrap(),
+            rush_surcharge_per_day: BigDecimal::from_str("0.15").unwrap(),
+            max_rush_surcharge: BigDecimal::from_str("0.50").unwrap(),
+            small_quantity_threshold: 100,
+            small_quantity_surcharge: BigDecimal::from_str("10.00").unwrap(),
+        }
+    }
+}
+
+impl Default for LaminationParameters {
+    fn default() -> Self {
+        LaminationParameters {
+            gloss_cost_per_square_meter: BigDecimal::from_str("0.40").unwrap(),
+            matte_cost_per_square_meter: BigDecimal::from_str("0.45").unwrap(),
+            soft_touch_cost_per_square_meter: BigDecimal::from_str("1.10").unwrap(),
+            anti_scuff_cost_per_square_meter: BigDecimal::from_str("0.90").unwrap(),
+            setup_cost: BigDecimal::from_str("15.00").unwrap(),
+            min_uncoated_gsm: 170,
+        }
+    }
+}
+
+impl FinishingOperation {
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        id: &str,
+        name: &str,
+        setup_cost: &str,
+        rate: &str,
+        rate_basis: RateBasis,
+        minimum_charge: &str,
+        setup_minutes: f64,
+        units_per_hour: f64,
+    ) -> Self {
+        FinishingOperation {
+            id: id.to_string(),
+            name: name.to_string(),
+            setup_cost: BigDecimal::from_str(setup_cost).unwrap(),
+            rate: BigDecimal::from_str(rate).unwrap(),
+            rate_basis,
+            minimum_charge: BigDecimal::from_str(minimum_charge).unwrap(),
+            setup_minutes,
+            units_per_hour,
+        }
+    }
+}
+
+fn default_finishing_operations() -> Vec<FinishingOperation> {
+    use RateBasis::{PerThousand, PerUnit};
+
+    vec![
+        FinishingOperation::new("cutting", "Guillotine cutting", "5.00", "1.50", PerThousand, "8.00", 10.0, 5000.0),
+        FinishingOperation::new("folding_half", "Half fold", "8.00", "4.00", PerThousand, "12.00", 15.0, 8000.0),
+        FinishingOperation::new("folding_tri", "Tri-fold", "10.00", "6.00", PerThousand, "15.00", 20.0, 6000.0),
+        FinishingOperation::new("folding_z", "Z-fold", "10.00", "6.00", PerThousand, "15.00", 20.0, 6000.0),
+        FinishingOperation::new("folding_gate", "Gate fold", "12.00", "9.00", PerThousand, "20.00", 25.0, 4000.0),
+        FinishingOperation::new("folding_cross", "Cross fold", "12.00", "8.00", PerThousand, "18.00", 25.0, 5000.0),
+        FinishingOperation::new("creasing", "Creasing", "8.00", "5.00", PerThousand, "12.00", 15.0, 3000.0),
+        FinishingOperation::new("perforating", "Perforating", "8.00", "5.00", PerThousand, "12.00", 15.0, 3000.0),
+        FinishingOperation::new("numbering", "Numbering", "10.00", "7.00", PerThousand, "15.00", 15.0, 2500.0),
+        FinishingOperation::new("foil_stamping", "Foil stamping", "45.00", "0.08", PerUnit, "60.00", 45.0, 1500.0),
+        FinishingOperation::new("embossing", "Embossing", "40.00", "0.06", PerUnit, "55.00", 45.0, 1500.0),
+        FinishingOperation::new("spot_uv", "Spot UV", "35.00", "0.05", PerUnit, "50.00", 30.0, 2000.0),
+        FinishingOperation::new("round_corners", "Round corners", "5.00", "3.00", PerThousand, "8.00", 5.0, 4000.0),
+        FinishingOperation::new("drilling", "Drilling", "5.00", "4.00", PerThousand, "8.00", 10.0, 3000.0),
+    ]
+}
@@ -1,44 +1,157 @@
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::collections::HashMap;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub enum Currency {
-    USD,
-    FCFA,
-    EUR,
-    GBP,
-    CAD,
+/// Reference data for a single ISO 4217 currency.
+#[derive(Debug, Clone, Serialize)]
+pub struct CurrencyInfo {
+    pub code: &'static str,
+    pub symbol: &'static str,
+    pub name: &'static str,
+    #[serde(rename = "minorUnits")]
+    pub minor_units: u32,
+}
+
+const fn info(code: &'static str, symbol: &'static str, name: &'static str, minor_units: u32) -> CurrencyInfo {
+    CurrencyInfo { code, symbol, name, minor_units }
 }
 
+/// Every currency the application knows. Which of these a company actually
+/// offers is controlled by its currency settings, and only currencies with
+/// an exchange rate source can be enabled.
+pub static CURRENCY_REGISTRY: &[CurrencyInfo] = &[
+    info("AED", "د.إ", "UAE Dirham", 2),
+    info("AOA", "Kz", "Angolan Kwanza", 2),
+    info("ARS", "$", "Argentine Peso", 2),
+    info("AUD", "A$", "Australian Dollar", 2),
+    info("BDT", "৳", "Bangladeshi Taka", 2),
+    info("BHD", "BD", "Bahraini Dinar", 3),
+    info("BIF", "FBu", "Burundian Franc", 0),
+    info("BRL", "R$", "Brazilian Real", 2),
+    info("BWP", "P", "Botswana Pula", 2),
+    info("CAD", "C$", "Canadian Dollar", 2),
+    info("CDF", "FC", "Congolese Franc", 2),
+    info("CHF", "CHF", "Swiss Franc", 2),
+    info("CLP", "$", "Chilean Peso", 0),
+    info("CNY", "¥", "Chinese Yuan", 2),
+    info("COP", "$", "Colombian Peso", 2),
+    info("CVE", "Esc", "Cape Verdean Escudo", 2),
+    info("CZK", "Kč", "Czech Koruna", 2),
+    info("DJF", "Fdj", "Djiboutian Franc", 0),
+    info("DKK", "kr", "Danish Krone", 2),
+    info("DZD", "DA", "Algerian Dinar", 2),
+    info("EGP", "E£", "Egyptian Pound", 2),
+    info("ETB", "Br", "Ethiopian Birr", 2),
+    info("EUR", "€", "Euro", 2),
+    info("GBP", "£", "British Pound", 2),
+    info("GHS", "GH₵", "Ghanaian Cedi", 2),
+    info("GMD", "D", "Gambian Dalasi", 2),
+    info("GNF", "FG", "Guinean Franc", 0),
+    info("HKD", "HK$", "Hong Kong Dollar", 2),
+    info("HUF", "Ft", "Hungarian Forint", 2),
+    info("IDR", "Rp", "Indonesian Rupiah", 2),
+    info("ILS", "₪", "Israeli New Shekel", 2),
+    info("INR", "₹", "Indian Rupee", 2),
+    info("JOD", "JD", "Jordanian Dinar", 3),
+    info("JPY", "¥", "Japanese Yen", 0),
+    info("KES", "KSh", "Kenyan Shilling", 2),
+    info("KMF", "CF", "Comorian Franc", 0),
+    info("KRW", "₩", "South Korean Won", 0),
+    info("KWD", "KD", "Kuwaiti Dinar", 3),
+    info("LRD", "L$", "Liberian Dollar", 2),
+    info("LSL", "L", "Lesotho Loti", 2),
+    info("LYD", "LD", "Libyan Dinar", 3),
+    info("MAD", "DH", "Moroccan Dirham", 2),
+    info("MGA", "Ar", "Malagasy Ariary", 2),
+    info("MRU", "UM", "Mauritanian Ouguiya", 2),
+    info("MUR", "₨", "Mauritian Rupee", 2),
+    info("MWK", "MK", "Malawian Kwacha", 2),
+    info("MXN", "MX$", "Mexican Peso", 2),
+    info("MYR", "RM", "Malaysian Ringgit", 2),
+    info("MZN", "MT", "Mozambican Metical", 2),
+    info("NAD", "N$", "Namibian Dollar", 2),
+    info("NGN", "₦", "Nigerian Naira", 2),
+    info("NOK", "kr", "Norwegian Krone", 2),
+    info("NZD", "NZ$", "New Zealand Dollar", 2),
+    info("OMR", "RO", "Omani Rial", 3),
+    info("PHP", "₱", "Philippine Peso", 2),
+    info("PKR", "₨", "Pakistani Rupee", 2),
+    info("PLN", "zł", "Polish Zloty", 2),
+    info("QAR", "QR", "Qatari Riyal", 2),
+    info("RON", "lei", "Romanian Leu", 2),
+    info("RWF", "FRw", "Rwandan Franc", 0),
+    info("SAR", "SR", "Saudi Riyal", 2),
+    info("SCR", "SR", "Seychellois Rupee", 2),
+    info("SDG", "SDG", "Sudanese Pound", 2),
+    info("SEK", "kr", "Swedish Krona", 2),
+    info("SGD", "S$", "Singapore Dollar", 2),
+    info("SLE", "Le", "Sierra Leonean Leone", 2),
+    info("SOS", "Sh", "Somali Shilling", 2),
+    info("SSP", "SS£", "South Sudanese Pound", 2),
+    info("STN", "Db", "São Tomé and Príncipe Dobra", 2),
+    info("SZL", "E", "Swazi Lilangeni", 2),
+    info("THB", "฿", "Thai Baht", 2),
+    info("TND", "DT", "Tunisian Dinar", 3),
+    info("TRY", "₺", "Turkish Lira", 2),
+    info("TZS", "TSh", "Tanzanian Shilling", 2),
+    info("UGX", "USh", "Ugandan Shilling", 0),
+    info("USD", "$", "US Dollar", 2),
+    info("VND", "₫", "Vietnamese Dong", 0),
+    info("XAF", "FCFA", "Central African CFA Franc", 0),
+    info("XOF", "CFA", "West African CFA Franc", 0),
+    info("ZAR", "R", "South African Rand", 2),
+    info("ZMW", "ZK", "Zambian Kwacha", 2),
+];
+
+/// Legacy names accepted on input and mapped to their ISO code.
+const CURRENCY_ALIASES: &[(&str, &str)] = &[("FCFA", "XAF")];
+
+/// An ISO 4217 currency from [`CURRENCY_REGISTRY`].
+///
+/// Serialises as its ISO code. Deserialisation also accepts the legacy
+/// variant names used before the registry existed (e.g. `"FCFA"`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Currency(&'static str);
+
 impl Currency {
+    pub const USD: Currency = Currency("USD");
+    pub const EUR: Currency = Currency("EUR");
+    pub const XAF: Currency = Currency("XAF");
+
+    pub fn from_code(code: &str) -> Option<Currency> {
+        let code = code.trim().to_uppercase();
+        let code = CURRENCY_ALIASES
+            .iter()
+            .find(|(alias, _)| *alias == code)
+            .map(|(_, iso)| iso.to_string())
+            .unwrap_or(code);
+
+        CURRENCY_REGISTRY
+            .iter()
+            .find(|currency| currency.code == code)
+            .map(|currency| Currency(currency.code))
+    }
+
+    pub fn info(&self) -> &'static CurrencyInfo {
+        CURRENCY_REGISTRY
+            .iter()
+            .find(|currency| currency.code == self.0)
+            .expect("currency codes always come from the registry")
+    }
+
     pub fn code(&self) -> &'static str {
-        match self {
-            Currency::USD => "USD",
-            Currency::FCFA => "XAF", // Central African CFA franc
-            Currency::EUR => "EUR",
-            Currency::GBP => "GBP",
-            Currency::CAD => "CAD",
-        }
+        self.0
     }
 
     pub fn symbol(&self) -> &'static str {
-        match self {
-            Currency::USD => "$",
-            Currency::FCFA => "FCFA",
-            Currency::EUR => "€",
-            Currency::GBP => "£",
-            Currency::CAD => "C$",
-        }
+        self.info().symbol
     }
 
     pub fn name(&self) -> &'static str {
-        match self {
-            Currency::USD => "US Dollar",
-            Currency::FCFA => "Central African CFA Franc",
-            Currency::EUR => "Euro",
-            Currency::GBP => "British Pound",
-            Currency::CAD => "Canadian Dollar",
-        }
+        self.info().name
+    }
+
+    pub fn minor_units(&self) -> u32 {
+        self.info().minor_units
     }
 }
 
@@ -48,6 +161,33 @@ impl Default for Currency {
     }
 }
 
+impl std::fmt::Display for Currency {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::str::FromStr for Currency {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Currency::from_code(s).ok_or_else(|| format!("Unsupported currency: {}", s))
+    }
+}
+
+impl Serialize for Currency {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for Currency {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let code = String::deserialize(deserializer)?;
+        code.parse().map_err(serde::de::Error::custom)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ExchangeRates {
     pub base: Currency,
@@ -80,8 +220,28 @@ pub struct CurrencyConversionResponse {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CurrencySettings {
+    /// Currency the company's cost parameters are denominated in.
+    #[serde(rename = "baseCurrency")]
+    pub base_currency: Currency,
     #[serde(rename = "defaultCurrency")]
     pub default_currency: Currency,
+    /// Currencies an admin has enabled for quoting.
     #[serde(rename = "supportedCurrencies")]
     pub supported_currencies: Vec<Currency>,
 }
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateCurrencySettingsRequest {
+    #[serde(rename = "baseCurrency")]
+    pub base_currency: Option<Currency>,
+    #[serde(rename = "defaultCurrency")]
+    pub default_currency: Option<Currency>,
+    #[serde(rename = "supportedCurrencies")]
+    pub supported_currencies: Option<Vec<Currency>>,
+}
+
+/// A user's display currency; `None` clears the preference.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CurrencyPreference {
+    pub currency: Option<Currency>,
+}
@@ -0,0 +1,84 @@
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::models::{Currency, Discount};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Customer {
+    pub id: Uuid,
+    pub name: String,
+    pub email: Option<String>,
+    pub phone: Option<String>,
+    #[serde(rename = "preferredCurrency")]
+    pub preferred_currency: Option<Currency>,
+    /// Profit rate for this customer's quotes, overriding the job-type and
+    /// default rates.
+    #[serde(rename = "profitRate")]
+    pub profit_rate: Option<BigDecimal>,
+    /// Standing discount on this customer's quotes.
+    pub discount: Option<Discount>,
+    /// ISO 3166-1 alpha-2 country code, used to pick the tax treatment.
+    pub country: Option<String>,
+    #[serde(rename = "taxId")]
+    pub tax_id: Option<String>,
+    /// No tax is charged on this customer's quotes.
+    #[serde(rename = "taxExempt")]
+    pub tax_exempt: bool,
+    #[serde(rename = "createdAt")]
+    pub created_at: DateTime<Utc>,
+    #[serde(rename = "updatedAt")]
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct CreateCustomerRequest {
+    #[validate(length(min = 1))]
+    pub name: String,
+    #[validate(email)]
+    pub email: Option<String>,
+    pub phone: Option<String>,
+    #[serde(rename = "preferredCurrency")]
+    pub preferred_currency: Option<Currency>,
+    /// Profit rate for this customer's quotes, overriding the job-type and
+    /// default rates.
+    #[serde(rename = "profitRate")]
+    pub profit_rate: Option<BigDecimal>,
+    /// Standing discount on this customer's quotes.
+    pub discount: Option<Discount>,
+    /// ISO 3166-1 alpha-2 country code, used to pick the tax treatment.
+    #[validate(length(equal = 2))]
+    pub country: Option<String>,
+    #[serde(rename = "taxId")]
+    pub tax_id: Option<String>,
+    /// No tax is charged on this customer's quotes.
+    #[serde(rename = "taxExempt", default)]
+    pub tax_exempt: bool,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct UpdateCustomerRequest {
+    #[validate(length(min = 1))]
+    pub name: Option<String>,
+    #[validate(email)]
+    pub email: Option<String>,
+    pub phone: Option<String>,
+    #[serde(rename = "preferredCurrency")]
+    pub preferred_currency: Option<Currency>,
+    /// Profit rate for this customer's quotes, overriding the job-type and
+    /// default rates.
+    #[serde(rename = "profitRate")]
+    pub profit_rate: Option<BigDecimal>,
+    /// Standing discount on this customer's quotes.
+    pub discount: Option<Discount>,
+    /// ISO 3166-1 alpha-2 country code, used to pick the tax treatment.
+    #[validate(length(equal = 2))]
+    pub country: Option<String>,
+    #[serde(rename = "taxId")]
+    pub tax_id: Option<String>,
+    /// No tax is charged on this customer's quotes.
+    #[serde(rename = "taxExempt")]
+    pub tax_exempt: Option<bool>,
+}
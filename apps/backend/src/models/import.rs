@@ -0,0 +1,74 @@
+use bigdecimal::BigDecimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::models::JobType;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ImportFormat {
+    Csv,
+    Xlsx,
+}
+
+impl ImportFormat {
+    /// Format of an uploaded file, from its extension.
+    pub fn from_file_name(name: &str) -> Option<Self> {
+        let extension = name.rsplit_once('.')?.1.to_lowercase();
+        match extension.as_str() {
+            "csv" => Some(ImportFormat::Csv),
+            "xlsx" => Some(ImportFormat::Xlsx),
+            _ => None,
+        }
+    }
+
+    pub fn from_content_type(content_type: &str) -> Option<Self> {
+        match content_type {
+            "text/csv" => Some(ImportFormat::Csv),
+            "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet" => Some(ImportFormat::Xlsx),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct JobImportQuery {
+    /// Validate and cost every row without creating any jobs.
+    #[serde(rename = "dryRun", default)]
+    pub dry_run: bool,
+}
+
+/// A problem with one row. Rows are numbered as in the spreadsheet, so the
+/// first row after the header is row 2.
+#[derive(Debug, Clone, Serialize)]
+pub struct ImportRowError {
+    pub row: usize,
+    /// Column at fault; `None` when the row could not be costed.
+    pub column: Option<String>,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ImportedJob {
+    pub row: usize,
+    pub title: String,
+    #[serde(rename = "jobType")]
+    pub job_type: JobType,
+    pub quantity: i32,
+    #[serde(rename = "totalCost")]
+    pub total_cost: BigDecimal,
+    /// Id of the created job; `None` on a dry run.
+    #[serde(rename = "jobId")]
+    pub job_id: Option<Uuid>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct JobImportReport {
+    #[serde(rename = "dryRun")]
+    pub dry_run: bool,
+    /// Data rows read, blank rows excluded.
+    pub rows: usize,
+    /// Rows that passed validation and costing.
+    pub imported: Vec<ImportedJob>,
+    pub errors: Vec<ImportRowError>,
+}
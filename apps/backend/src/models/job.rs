@@ -1,11 +1,11 @@
 use bigdecimal::BigDecimal;
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use uuid::Uuid;
-use crate::models::Currency;
+use crate::models::{AdjustmentLine, Currency, MachineKind, ProfitMode, QuoteTerms, TaxSummary};
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum JobType {
     Book,
     Flyer,
@@ -84,10 +84,213 @@ pub struct JobSpecifications {
     pub colors: ColorSpecification,
     pub pages: Option<i32>,
     pub binding: Option<String>,
-    pub lamination: Option<String>,
+    pub lamination: Option<LaminationSpecification>,
     pub finishing: Vec<String>,
     #[serde(rename = "specialRequirements")]
     pub special_requirements: Option<String>,
+    /// Book-only details; `JobSpecifications` paper and colors describe the
+    /// interior when this is set.
+    pub book: Option<BookSpecifications>,
+    /// Banner and poster details; replaces `paper_size` and `paper_type`
+    /// when set.
+    #[serde(rename = "largeFormat")]
+    pub large_format: Option<LargeFormatSpecifications>,
+    /// Process pinned by the estimator; the cheapest eligible process is
+    /// chosen when absent.
+    pub process: Option<PrintProcess>,
+}
+
+impl JobSpecifications {
+    /// Interior paper weight in g/m², parsed from values like "80gsm" or "80 g/m²".
+    pub fn paper_weight_gsm(&self) -> Option<i32> {
+        self.paper_weight.as_deref().and_then(parse_gsm)
+    }
+}
+
+pub(crate) fn parse_gsm(weight: &str) -> Option<i32> {
+    let digits: String = weight
+        .trim()
+        .chars()
+        .take_while(|c| c.is_ascii_digit())
+        .collect();
+    digits.parse().ok().filter(|gsm| *gsm > 0)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PrintProcess {
+    Offset,
+    Digital,
+    WideFormat,
+}
+
+impl PrintProcess {
+    /// Press the process runs on; wide-format work is costed by area instead.
+    pub fn press(&self) -> Option<MachineKind> {
+        match self {
+            PrintProcess::Offset => Some(MachineKind::OffsetPress),
+            PrintProcess::Digital => Some(MachineKind::DigitalPress),
+            PrintProcess::WideFormat => None,
+        }
+    }
+}
+
+impl std::fmt::Display for PrintProcess {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PrintProcess::Offset => write!(f, "offset"),
+            PrintProcess::Digital => write!(f, "digital"),
+            PrintProcess::WideFormat => write!(f, "wide_format"),
+        }
+    }
+}
+
+impl std::str::FromStr for PrintProcess {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "offset" => Ok(PrintProcess::Offset),
+            "digital" => Ok(PrintProcess::Digital),
+            "wide_format" => Ok(PrintProcess::WideFormat),
+            _ => Err(format!("Invalid print process: {}", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BindingMethod {
+    PerfectBound,
+    SaddleStitch,
+    CaseBound,
+    WireO,
+}
+
+impl std::fmt::Display for BindingMethod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BindingMethod::PerfectBound => write!(f, "perfect_bound"),
+            BindingMethod::SaddleStitch => write!(f, "saddle_stitch"),
+            BindingMethod::CaseBound => write!(f, "case_bound"),
+            BindingMethod::WireO => write!(f, "wire_o"),
+        }
+    }
+}
+
+impl std::str::FromStr for BindingMethod {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().replace(['-', ' '], "_").as_str() {
+            "perfect" | "perfect_bound" | "perfectbound" => Ok(BindingMethod::PerfectBound),
+            "saddle_stitch" | "saddlestitch" | "saddle_stitched" => Ok(BindingMethod::SaddleStitch),
+            "case" | "case_bound" | "casebound" | "hardcover" => Ok(BindingMethod::CaseBound),
+            "wire_o" | "wireo" | "wire" => Ok(BindingMethod::WireO),
+            _ => Err(format!("Invalid binding method: {}", s)),
+        }
+    }
+}
+
+/// Paper and colors for one part of a book.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StockSpecification {
+    #[serde(rename = "paperType")]
+    pub paper_type: String,
+    #[serde(rename = "paperWeightGsm")]
+    pub paper_weight_gsm: i32,
+    pub colors: ColorSpecification,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BookSpecifications {
+    #[serde(rename = "bindingMethod")]
+    pub binding_method: BindingMethod,
+    /// Pages per signature (4, 8, 16 or 32). Chosen from the page count when omitted.
+    #[serde(rename = "signaturePages")]
+    pub signature_pages: Option<i32>,
+    pub cover: StockSpecification,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LaminationFilm {
+    Gloss,
+    Matte,
+    SoftTouch,
+    AntiScuff,
+}
+
+impl std::fmt::Display for LaminationFilm {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LaminationFilm::Gloss => write!(f, "gloss"),
+            LaminationFilm::Matte => write!(f, "matte"),
+            LaminationFilm::SoftTouch => write!(f, "soft_touch"),
+            LaminationFilm::AntiScuff => write!(f, "anti_scuff"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LaminationSides {
+    #[default]
+    Single,
+    Double,
+}
+
+impl LaminationSides {
+    pub fn count(&self) -> i32 {
+        match self {
+            LaminationSides::Single => 1,
+            LaminationSides::Double => 2,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LaminationSpecification {
+    pub film: LaminationFilm,
+    #[serde(default)]
+    pub sides: LaminationSides,
+}
+
+/// Finished size and media for roll-fed work.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LargeFormatSpecifications {
+    #[serde(rename = "widthCm")]
+    pub width_cm: f64,
+    #[serde(rename = "heightCm")]
+    pub height_cm: f64,
+    /// Name of a media type from the large-format cost parameters.
+    #[serde(rename = "mediaType")]
+    pub media_type: String,
+    /// Ink coverage in percent of the printed area; full coverage when omitted.
+    #[serde(rename = "inkCoverage")]
+    pub ink_coverage: Option<f64>,
+    /// Distance between grommets along the edges; no grommets when omitted.
+    #[serde(rename = "grommetSpacingCm")]
+    pub grommet_spacing_cm: Option<f64>,
+    #[serde(default)]
+    pub hemming: bool,
+    #[serde(rename = "polePockets")]
+    pub pole_pockets: Option<PolePockets>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PolePockets {
+    Top,
+    TopAndBottom,
+}
+
+impl PolePockets {
+    pub fn count(&self) -> i32 {
+        match self {
+            PolePockets::Top => 1,
+            PolePockets::TopAndBottom => 2,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -100,6 +303,138 @@ pub struct ColorSpecification {
     pub spot_colors: Vec<String>,
     #[serde(rename = "isFullColor")]
     pub is_full_color: bool,
+    /// Estimated coverage per process color, in percent.
+    #[serde(rename = "processCoverage")]
+    pub process_coverage: Option<f64>,
+    /// Estimated coverage per spot color, in percent.
+    #[serde(rename = "spotCoverage")]
+    pub spot_coverage: Option<f64>,
+}
+
+impl ColorSpecification {
+    /// Full-color work always runs at least CMYK on the front.
+    pub fn front_process_colors(&self) -> i32 {
+        let colors = self.front_colors.max(0);
+        if self.is_full_color {
+            colors.max(4)
+        } else {
+            colors
+        }
+    }
+
+    /// Full-color work runs CMYK on a printed back too.
+    pub fn back_process_colors(&self) -> i32 {
+        let colors = self.back_colors.max(0);
+        if self.is_full_color && colors > 0 {
+            colors.max(4)
+        } else {
+            colors
+        }
+    }
+
+    /// Spot colors are printed on the front only.
+    pub fn front_inks(&self) -> i32 {
+        self.front_process_colors() + self.spot_colors.len() as i32
+    }
+
+    pub fn back_inks(&self) -> i32 {
+        self.back_process_colors()
+    }
+}
+
+/// Which `CostBreakdown` total a line rolls up into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CostCategory {
+    Paper,
+    Plates,
+    Ink,
+    Labor,
+    Binding,
+    Finishing,
+}
+
+/// One itemised cost, optionally tied to a section of the product such as
+/// a book's text block or cover.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CostLine {
+    pub category: CostCategory,
+    pub section: Option<String>,
+    pub description: String,
+    pub amount: BigDecimal,
+    /// How `amount` was worked out; only returned when asked for.
+    #[serde(default)]
+    pub explanation: Option<CostExplanation>,
+}
+
+impl CostLine {
+    pub fn new(category: CostCategory, description: impl Into<String>, amount: BigDecimal) -> Self {
+        CostLine {
+            category,
+            section: None,
+            description: description.into(),
+            amount,
+            explanation: None,
+        }
+    }
+
+    pub fn in_section(mut self, section: &str) -> Self {
+        self.section = Some(section.to_string());
+        self
+    }
+
+    pub fn explained(mut self, explanation: CostExplanation) -> Self {
+        self.explanation = Some(explanation);
+        self
+    }
+}
+
+/// The formula behind a cost line, with the rates and quantities it used
+/// and the values derived on the way. Money is in the company base
+/// currency, whatever the display currency.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CostExplanation {
+    pub formula: String,
+    pub inputs: Vec<TraceValue>,
+    pub intermediates: Vec<TraceValue>,
+}
+
+impl CostExplanation {
+    pub fn new(formula: impl Into<String>) -> Self {
+        CostExplanation {
+            formula: formula.into(),
+            inputs: Vec::new(),
+            intermediates: Vec::new(),
+        }
+    }
+
+    pub fn input(mut self, name: &str, value: impl ToString, unit: &str) -> Self {
+        self.inputs.push(TraceValue::new(name, value, unit));
+        self
+    }
+
+    pub fn intermediate(mut self, name: &str, value: impl ToString, unit: &str) -> Self {
+        self.intermediates.push(TraceValue::new(name, value, unit));
+        self
+    }
+}
+
+/// A named number in an explanation, e.g. `pressSheets = 250 sheets`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TraceValue {
+    pub name: String,
+    pub value: BigDecimal,
+    pub unit: String,
+}
+
+impl TraceValue {
+    fn new(name: &str, value: impl ToString, unit: &str) -> Self {
+        TraceValue {
+            name: name.to_string(),
+            value: value.to_string().parse().unwrap_or_default(),
+            unit: unit.to_string(),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -108,6 +443,8 @@ pub struct CostBreakdown {
     pub paper_cost: BigDecimal,
     #[serde(rename = "plateCost")]
     pub plate_cost: BigDecimal,
+    #[serde(rename = "inkCost", default)]
+    pub ink_cost: BigDecimal,
     #[serde(rename = "laborCost")]
     pub labor_cost: BigDecimal,
     #[serde(rename = "bindingCost")]
@@ -115,6 +452,163 @@ pub struct CostBreakdown {
     #[serde(rename = "finishingCost")]
     pub finishing_cost: BigDecimal,
     pub overhead: BigDecimal,
+    /// Itemised lines the totals above are summed from.
+    #[serde(default)]
+    pub lines: Vec<CostLine>,
+    /// Machine hours on the job's route, make-ready included.
+    #[serde(rename = "productionHours", default)]
+    pub production_hours: f64,
+    /// `production_hours` split by machine, in route order. Empty for work
+    /// that is not routed through machines.
+    #[serde(rename = "machineHours", default)]
+    pub machine_hours: Vec<MachineHours>,
+}
+
+/// Time a job spends on one machine.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MachineHours {
+    #[serde(rename = "machineId")]
+    pub machine_id: Uuid,
+    #[serde(rename = "machineName")]
+    pub machine_name: String,
+    pub hours: f64,
+}
+
+impl CostBreakdown {
+    /// Builds the category totals from itemised lines. Overhead is left at
+    /// zero for the caller to fill in.
+    pub fn from_lines(lines: Vec<CostLine>) -> CostBreakdown {
+        let total = |category: CostCategory| -> BigDecimal {
+            lines
+                .iter()
+                .filter(|line| line.category == category)
+                .fold(BigDecimal::from(0), |sum, line| sum + &line.amount)
+        };
+
+        CostBreakdown {
+            paper_cost: total(CostCategory::Paper),
+            plate_cost: total(CostCategory::Plates),
+            ink_cost: total(CostCategory::Ink),
+            labor_cost: total(CostCategory::Labor),
+            binding_cost: total(CostCategory::Binding),
+            finishing_cost: total(CostCategory::Finishing),
+            overhead: BigDecimal::from(0),
+            lines,
+            production_hours: 0.0,
+            machine_hours: Vec::new(),
+        }
+    }
+
+    pub fn with_production_hours(mut self, hours: f64) -> CostBreakdown {
+        self.production_hours = hours;
+        self
+    }
+
+    pub fn with_machine_hours(mut self, machine_hours: Vec<MachineHours>) -> CostBreakdown {
+        self.machine_hours = machine_hours;
+        self
+    }
+
+    /// Drops the per-line explanations, for callers that did not ask for them.
+    pub fn without_explanations(mut self) -> CostBreakdown {
+        for line in &mut self.lines {
+            line.explanation = None;
+        }
+        self
+    }
+
+    /// Sum of the direct production lines, excluding overhead.
+    pub fn production_total(&self) -> BigDecimal {
+        &self.paper_cost
+            + &self.plate_cost
+            + &self.ink_cost
+            + &self.labor_cost
+            + &self.binding_cost
+            + &self.finishing_cost
+    }
+
+    /// Multiplies every amount by `factor`, e.g. an exchange rate.
+    pub fn scaled(&self, factor: &BigDecimal) -> CostBreakdown {
+        CostBreakdown {
+            paper_cost: &self.paper_cost * factor,
+            plate_cost: &self.plate_cost * factor,
+            ink_cost: &self.ink_cost * factor,
+            labor_cost: &self.labor_cost * factor,
+            binding_cost: &self.binding_cost * factor,
+            finishing_cost: &self.finishing_cost * factor,
+            overhead: &self.overhead * factor,
+            lines: self
+                .lines
+                .iter()
+                .map(|line| CostLine {
+                    amount: &line.amount * factor,
+                    ..line.clone()
+                })
+                .collect(),
+            production_hours: self.production_hours,
+            machine_hours: self.machine_hours.clone(),
+        }
+    }
+}
+
+/// Production cost, overhead, profit and selling price, kept apart so a
+/// quote shows what the job costs the shop and what it earns.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PriceSummary {
+    #[serde(rename = "productionCost")]
+    pub production_cost: BigDecimal,
+    pub overhead: BigDecimal,
+    /// Production cost plus overhead.
+    #[serde(rename = "fullCost")]
+    pub full_cost: BigDecimal,
+    pub profit: BigDecimal,
+    #[serde(rename = "sellingPrice")]
+    pub selling_price: BigDecimal,
+    #[serde(rename = "profitMode")]
+    pub profit_mode: ProfitMode,
+    /// Rate applied, after job-type and customer overrides.
+    #[serde(rename = "profitRate")]
+    pub profit_rate: BigDecimal,
+    /// Profit as a fraction of the selling price.
+    #[serde(rename = "marginPercentage")]
+    pub margin_percentage: BigDecimal,
+    /// Profit as a fraction of full cost.
+    #[serde(rename = "markupPercentage")]
+    pub markup_percentage: BigDecimal,
+    /// Discounts and surcharges applied to the selling price.
+    #[serde(default)]
+    pub adjustments: Vec<AdjustmentLine>,
+    /// Selling price after adjustments; the amount quoted before tax.
+    #[serde(rename = "quotedPrice")]
+    pub quoted_price: BigDecimal,
+    /// Set when the margin left after adjustments is below the configured
+    /// minimum.
+    #[serde(rename = "requiresApproval")]
+    pub requires_approval: bool,
+}
+
+impl PriceSummary {
+    /// Multiplies the amounts by `factor`, e.g. an exchange rate. Rates are
+    /// unchanged.
+    pub fn scaled(&self, factor: &BigDecimal) -> PriceSummary {
+        PriceSummary {
+            production_cost: &self.production_cost * factor,
+            overhead: &self.overhead * factor,
+            full_cost: &self.full_cost * factor,
+            profit: &self.profit * factor,
+            selling_price: &self.selling_price * factor,
+            adjustments: self
+                .adjustments
+                .iter()
+                .map(|line| AdjustmentLine {
+                    amount: &line.amount * factor,
+                    ..line.clone()
+                })
+                .collect(),
+            quoted_price: &self.quoted_price * factor,
+            ..self.clone()
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -129,10 +623,40 @@ pub struct Job {
     pub specifications: JobSpecifications,
     #[serde(rename = "costBreakdown")]
     pub cost_breakdown: CostBreakdown,
+    /// Quoted price before tax.
     #[serde(rename = "totalCost")]
     pub total_cost: BigDecimal,
     #[serde(rename = "unitCost")]
     pub unit_cost: BigDecimal,
+    #[serde(default)]
+    pub pricing: Option<PriceSummary>,
+    #[serde(default)]
+    pub tax: Option<TaxSummary>,
+    #[serde(rename = "customerId", default)]
+    pub customer_id: Option<Uuid>,
+    /// Promo code and requested delivery date the job is quoted under.
+    #[serde(default)]
+    pub terms: QuoteTerms,
+    /// Number of the latest quote revision.
+    #[serde(default)]
+    pub revision: i32,
+    /// Job this one repeats, when it was duplicated as a reorder.
+    #[serde(rename = "reorderOf", default)]
+    pub reorder_of: Option<Uuid>,
+    /// Until when the price copied from the original job stands; cleared
+    /// once the job is priced with current parameters.
+    #[serde(rename = "priceHeldUntil", default)]
+    pub price_held_until: Option<DateTime<Utc>>,
+    /// Date the shop has committed to; the customer's requested date is in
+    /// `terms`.
+    #[serde(rename = "promisedDate", default)]
+    pub promised_date: Option<NaiveDate>,
+    /// Manager who approved a below-minimum margin.
+    #[serde(rename = "marginApprovedBy", default)]
+    pub margin_approved_by: Option<Uuid>,
+    /// Process the job was costed on.
+    #[serde(rename = "printProcess", default)]
+    pub print_process: Option<PrintProcess>,
     pub status: JobStatus,
     #[serde(rename = "createdAt")]
     pub created_at: DateTime<Utc>,
@@ -147,6 +671,11 @@ pub struct CreateJobRequest {
     pub job_type: JobType,
     pub quantity: i32,
     pub specifications: JobSpecifications,
+    /// Customer the job is quoted for; drives profit rate, discounts and tax.
+    #[serde(rename = "customerId")]
+    pub customer_id: Option<Uuid>,
+    #[serde(flatten)]
+    pub terms: QuoteTerms,
 }
 
 #[derive(Debug, Deserialize)]
@@ -155,6 +684,30 @@ pub struct UpdateJobRequest {
     pub quantity: Option<i32>,
     pub specifications: Option<JobSpecifications>,
     pub status: Option<JobStatus>,
+    /// Replaces the promo code and requested delivery date.
+    pub terms: Option<QuoteTerms>,
+    #[serde(rename = "promisedDate")]
+    pub promised_date: Option<NaiveDate>,
+}
+
+/// Copies a job's specifications and customer into a new draft reorder.
+#[derive(Debug, Deserialize)]
+pub struct DuplicateJobRequest {
+    /// Defaults to the original title.
+    pub title: Option<String>,
+    /// Price with the current parameters instead of keeping the original
+    /// price.
+    #[serde(default)]
+    pub recost: bool,
+    /// Days the original price is held for; 30 when omitted.
+    #[serde(rename = "holdDays")]
+    pub hold_days: Option<i64>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RevisionDiffQuery {
+    pub from: i32,
+    pub to: i32,
 }
 
 #[derive(Debug, Deserialize)]
@@ -183,20 +736,71 @@ pub struct CostCalculationRequest {
     pub job_type: JobType,
     pub quantity: i32,
     pub specifications: JobSpecifications,
+    /// Display currency; falls back to the customer, user and company
+    /// defaults when omitted.
     pub currency: Option<Currency>,
+    #[serde(rename = "customerId")]
+    pub customer_id: Option<Uuid>,
+    #[serde(flatten)]
+    pub terms: QuoteTerms,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CostCalculationQuery {
+    /// Include each line's formula, inputs and intermediate values.
+    pub explain: Option<bool>,
 }
 
 #[derive(Debug, Serialize)]
 pub struct CostCalculationResponse {
     #[serde(rename = "costBreakdown")]
     pub cost_breakdown: CostBreakdown,
+    /// Quoted price before tax, equal to `pricing.quotedPrice`.
     #[serde(rename = "totalCost")]
     pub total_cost: BigDecimal,
     #[serde(rename = "unitCost")]
     pub unit_cost: BigDecimal,
     #[serde(rename = "estimatedDeliveryDays")]
     pub estimated_delivery_days: i32,
+    pub pricing: Option<PriceSummary>,
+    /// Net, tax and gross totals; the net is the quoted price above.
+    pub tax: Option<TaxSummary>,
+    /// Process the totals above are for.
+    pub process: Option<PrintProcess>,
+    /// Every eligible process, costed for comparison.
+    pub alternatives: Vec<ProcessAlternative>,
+    /// Quantity from which offset becomes cheaper than digital, when both
+    /// are eligible and the lines cross.
+    #[serde(rename = "crossoverQuantity")]
+    pub crossover_quantity: Option<i32>,
+    /// Version of the cost parameters used.
+    #[serde(rename = "parametersVersion")]
+    pub parameters_version: Option<i32>,
     pub currency: Option<Currency>,
     #[serde(rename = "exchangeRate")]
     pub exchange_rate: Option<f64>,
 }
+
+/// One process's cost and delivery for the requested quantity.
+#[derive(Debug, Clone, Serialize)]
+pub struct ProcessAlternative {
+    pub process: PrintProcess,
+    #[serde(rename = "totalCost")]
+    pub total_cost: BigDecimal,
+    #[serde(rename = "unitCost")]
+    pub unit_cost: BigDecimal,
+    #[serde(rename = "estimatedDeliveryDays")]
+    pub estimated_delivery_days: i32,
+    pub selected: bool,
+}
+
+impl ProcessAlternative {
+    /// Multiplies the costs by `factor`, e.g. an exchange rate.
+    pub fn scaled(&self, factor: &BigDecimal) -> ProcessAlternative {
+        ProcessAlternative {
+            total_cost: &self.total_cost * factor,
+            unit_cost: &self.unit_cost * factor,
+            ..self.clone()
+        }
+    }
+}
@@ -0,0 +1,215 @@
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MachineKind {
+    OffsetPress,
+    DigitalPress,
+    Cutter,
+    Folder,
+    Binder,
+}
+
+impl std::fmt::Display for MachineKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MachineKind::OffsetPress => write!(f, "offset_press"),
+            MachineKind::DigitalPress => write!(f, "digital_press"),
+            MachineKind::Cutter => write!(f, "cutter"),
+            MachineKind::Folder => write!(f, "folder"),
+            MachineKind::Binder => write!(f, "binder"),
+        }
+    }
+}
+
+impl std::str::FromStr for MachineKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "offset_press" => Ok(MachineKind::OffsetPress),
+            "digital_press" => Ok(MachineKind::DigitalPress),
+            "cutter" => Ok(MachineKind::Cutter),
+            "folder" => Ok(MachineKind::Folder),
+            "binder" => Ok(MachineKind::Binder),
+            _ => Err(format!("Invalid machine kind: {}", s)),
+        }
+    }
+}
+
+/// Run speed for stock up to `max_gsm`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpeedBand {
+    #[serde(rename = "maxGsm")]
+    pub max_gsm: i32,
+    #[serde(rename = "sheetsPerHour")]
+    pub sheets_per_hour: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Machine {
+    pub id: Uuid,
+    pub name: String,
+    pub kind: MachineKind,
+    #[serde(rename = "makeReadyMinutes")]
+    pub make_ready_minutes: f64,
+    /// Ordered from lightest to heaviest stock.
+    #[serde(rename = "speedBands")]
+    pub speed_bands: Vec<SpeedBand>,
+    #[serde(rename = "machineRatePerHour")]
+    pub machine_rate_per_hour: BigDecimal,
+    #[serde(rename = "operatorRatePerHour")]
+    pub operator_rate_per_hour: BigDecimal,
+    /// Hours the machine runs on a working day.
+    #[serde(rename = "hoursPerDay", default = "default_hours_per_day")]
+    pub hours_per_day: f64,
+    /// ISO weekdays the machine runs on, Monday being 1.
+    #[serde(rename = "workingDays", default = "default_working_days")]
+    pub working_days: Vec<u32>,
+    #[serde(rename = "isActive")]
+    pub is_active: bool,
+    #[serde(rename = "createdAt")]
+    pub created_at: DateTime<Utc>,
+    #[serde(rename = "updatedAt")]
+    pub updated_at: DateTime<Utc>,
+}
+
+pub fn default_hours_per_day() -> f64 {
+    8.0
+}
+
+/// Monday to Friday.
+pub fn default_working_days() -> Vec<u32> {
+    vec![1, 2, 3, 4, 5]
+}
+
+impl Machine {
+    /// Speed of the first band that takes `gsm`; the heaviest band for
+    /// anything beyond it.
+    pub fn sheets_per_hour(&self, gsm: i32) -> f64 {
+        self.speed_bands
+            .iter()
+            .find(|band| gsm <= band.max_gsm)
+            .or_else(|| self.speed_bands.last())
+            .map(|band| band.sheets_per_hour)
+            .unwrap_or(1.0)
+            .max(1.0)
+    }
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct CreateMachineRequest {
+    #[validate(length(min = 1))]
+    pub name: String,
+    pub kind: MachineKind,
+    #[serde(rename = "makeReadyMinutes")]
+    #[validate(range(min = 0.0))]
+    pub make_ready_minutes: f64,
+    #[serde(rename = "speedBands")]
+    #[validate(length(min = 1))]
+    pub speed_bands: Vec<SpeedBand>,
+    #[serde(rename = "machineRatePerHour")]
+    pub machine_rate_per_hour: BigDecimal,
+    #[serde(rename = "operatorRatePerHour")]
+    pub operator_rate_per_hour: BigDecimal,
+    #[serde(rename = "hoursPerDay")]
+    #[validate(range(min = 0.5, max = 24.0))]
+    pub hours_per_day: Option<f64>,
+    #[serde(rename = "workingDays")]
+    pub working_days: Option<Vec<u32>>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct UpdateMachineRequest {
+    #[validate(length(min = 1))]
+    pub name: Option<String>,
+    #[serde(rename = "makeReadyMinutes")]
+    #[validate(range(min = 0.0))]
+    pub make_ready_minutes: Option<f64>,
+    #[serde(rename = "speedBands")]
+    #[validate(length(min = 1))]
+    pub speed_bands: Option<Vec<SpeedBand>>,
+    #[serde(rename = "machineRatePerHour")]
+    pub machine_rate_per_hour: Option<BigDecimal>,
+    #[serde(rename = "operatorRatePerHour")]
+    pub operator_rate_per_hour: Option<BigDecimal>,
+    #[serde(rename = "hoursPerDay")]
+    #[validate(range(min = 0.5, max = 24.0))]
+    pub hours_per_day: Option<f64>,
+    #[serde(rename = "workingDays")]
+    pub working_days: Option<Vec<u32>>,
+    #[serde(rename = "isActive")]
+    pub is_active: Option<bool>,
+}
+
+fn default_machine(
+    id: u128,
+    name: &str,
+    kind: MachineKind,
+    make_ready_minutes: f64,
+    speed_bands: &[(i32, f64)],
+    machine_rate_per_hour: &str,
+    operator_rate_per_hour: &str,
+) -> Machine {
+    Machine {
+        id: Uuid::from_u128(id),
+        name: name.to_string(),
+        kind,
+        make_ready_minutes,
+        speed_bands: speed_bands
+            .iter()
+            .map(|(max_gsm, sheets_per_hour)| SpeedBand {
+                max_gsm: *max_gsm,
+                sheets_per_hour: *sheets_per_hour,
+            })
+            .collect(),
+        machine_rate_per_hour: BigDecimal::from_str(machine_rate_per_hour).unwrap(),
+        operator_rate_per_hour: BigDecimal::from_str(operator_rate_per_hour).unwrap(),
+        hours_per_day: default_hours_per_day(),
+        working_days: default_working_days(),
+        is_active: true,
+        created_at: Utc::now(),
+        updated_at: Utc::now(),
+    }
+}
+
+/// Equipment seeded into a new shop. The ids are fixed so machine hours on
+/// stored quotes keep pointing at the same machine.
+pub fn default_machines() -> Vec<Machine> {
+    vec![
+        default_machine(
+            1,
+            "SRA3 offset press",
+            MachineKind::OffsetPress,
+            30.0,
+            &[(115, 8000.0), (170, 7000.0), (250, 5500.0), (400, 4000.0)],
+            "40.00",
+            "15.00",
+        ),
+        default_machine(
+            2,
+            "Digital press",
+            MachineKind::DigitalPress,
+            5.0,
+            &[(170, 3000.0), (300, 2400.0), (400, 1800.0)],
+            "25.00",
+            "15.00",
+        ),
+        default_machine(3, "Guillotine", MachineKind::Cutter, 10.0, &[(400, 4000.0)], "12.00", "15.00"),
+        default_machine(
+            4,
+            "Buckle folder",
+            MachineKind::Folder,
+            15.0,
+            &[(115, 6000.0), (170, 4500.0), (250, 3000.0)],
+            "18.00",
+            "15.00",
+        ),
+        default_machine(5, "Binding line", MachineKind::Binder, 20.0, &[(400, 600.0)], "30.00", "15.00"),
+    ]
+}
@@ -1,11 +1,35 @@
 pub mod user;
+pub mod adjustment;
+pub mod attachment;
+pub mod batch;
+pub mod budget;
+pub mod comment;
+pub mod import;
 pub mod job;
 pub mod cost_parameters;
 pub mod branding_settings;
 pub mod currency;
+pub mod customer;
+pub mod machine;
+pub mod tax;
+pub mod revision;
+pub mod schedule;
+pub mod template;
 
 pub use user::*;
+pub use adjustment::*;
+pub use attachment::*;
+pub use batch::*;
+pub use budget::*;
+pub use comment::*;
+pub use import::*;
 pub use job::*;
 pub use cost_parameters::*;
 pub use branding_settings::*;
 pub use currency::*;
+pub use customer::*;
+pub use machine::*;
+pub use tax::*;
+pub use revision::*;
+pub use schedule::*;
+pub use template::*;
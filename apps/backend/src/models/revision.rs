@@ -0,0 +1,217 @@
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::models::{
+    CostBreakdown, CostCategory, CostLine, Currency, Job, JobSpecifications, JobType, PriceSummary, PrintProcess, TaxSummary,
+};
+
+/// Immutable snapshot of a job's quote, taken whenever its specification or
+/// price changes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JobRevision {
+    pub id: Uuid,
+    #[serde(rename = "jobId")]
+    pub job_id: Uuid,
+    /// 1 for the quote the job was created with, then counting up.
+    pub revision: i32,
+    #[serde(rename = "jobType")]
+    pub job_type: JobType,
+    pub quantity: i32,
+    pub specifications: JobSpecifications,
+    #[serde(rename = "costBreakdown")]
+    pub cost_breakdown: CostBreakdown,
+    #[serde(rename = "totalCost")]
+    pub total_cost: BigDecimal,
+    #[serde(rename = "unitCost")]
+    pub unit_cost: BigDecimal,
+    pub pricing: Option<PriceSummary>,
+    pub tax: Option<TaxSummary>,
+    #[serde(rename = "printProcess")]
+    pub print_process: Option<PrintProcess>,
+    /// Version of the cost parameters the quote was priced with.
+    #[serde(rename = "parametersVersion")]
+    pub parameters_version: i32,
+    pub currency: Currency,
+    /// Rate from the base currency to `currency` at the time.
+    #[serde(rename = "exchangeRate")]
+    pub exchange_rate: f64,
+    #[serde(rename = "createdAt")]
+    pub created_at: DateTime<Utc>,
+}
+
+impl JobRevision {
+    /// Snapshots the quote `job` currently carries.
+    pub fn of(job: &Job, revision: i32, parameters_version: i32, currency: Currency, exchange_rate: f64) -> Self {
+        JobRevision {
+            id: Uuid::new_v4(),
+            job_id: job.id,
+            revision,
+            job_type: job.job_type,
+            quantity: job.quantity,
+            specifications: job.specifications.clone(),
+            cost_breakdown: job.cost_breakdown.clone(),
+            total_cost: job.total_cost.clone(),
+            unit_cost: job.unit_cost.clone(),
+            pricing: job.pricing.clone(),
+            tax: job.tax.clone(),
+            print_process: job.print_process,
+            parameters_version,
+            currency,
+            exchange_rate,
+            created_at: Utc::now(),
+        }
+    }
+
+    /// What changed from this revision to `to`.
+    pub fn diff(&self, to: &JobRevision) -> RevisionDiff {
+        let mut specification_changes = Vec::new();
+        if self.quantity != to.quantity {
+            specification_changes.push(FieldChange {
+                field: "quantity".to_string(),
+                from: Value::from(self.quantity),
+                to: Value::from(to.quantity),
+            });
+        }
+        json_changes(
+            "specifications",
+            &serde_json::to_value(&self.specifications).unwrap_or(Value::Null),
+            &serde_json::to_value(&to.specifications).unwrap_or(Value::Null),
+            &mut specification_changes,
+        );
+
+        RevisionDiff {
+            job_id: self.job_id,
+            from: self.revision,
+            to: to.revision,
+            specification_changes,
+            line_changes: line_changes(&self.cost_breakdown, &to.cost_breakdown),
+            overhead_change: &to.cost_breakdown.overhead - &self.cost_breakdown.overhead,
+            total_cost_change: &to.total_cost - &self.total_cost,
+            parameters_changed: self.parameters_version != to.parameters_version,
+            exchange_rate_changed: self.currency != to.currency || self.exchange_rate != to.exchange_rate,
+        }
+    }
+}
+
+/// A specification field that differs between two revisions, addressed by
+/// its dotted JSON path.
+#[derive(Debug, Clone, Serialize)]
+pub struct FieldChange {
+    pub field: String,
+    pub from: Value,
+    pub to: Value,
+}
+
+/// A cost line in either revision. Lines are matched by category, section
+/// and position, so a line whose description changed with the quantity
+/// still lines up with its counterpart.
+#[derive(Debug, Clone, Serialize)]
+pub struct LineChange {
+    pub category: CostCategory,
+    pub section: Option<String>,
+    /// Description in the later revision, or the earlier one if removed.
+    pub description: String,
+    pub from: Option<BigDecimal>,
+    pub to: Option<BigDecimal>,
+    pub change: BigDecimal,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RevisionDiff {
+    #[serde(rename = "jobId")]
+    pub job_id: Uuid,
+    pub from: i32,
+    pub to: i32,
+    #[serde(rename = "specificationChanges")]
+    pub specification_changes: Vec<FieldChange>,
+    /// Lines whose amount changed, or that were added or removed.
+    #[serde(rename = "lineChanges")]
+    pub line_changes: Vec<LineChange>,
+    #[serde(rename = "overheadChange")]
+    pub overhead_change: BigDecimal,
+    #[serde(rename = "totalCostChange")]
+    pub total_cost_change: BigDecimal,
+    /// The cost parameters were updated between the two revisions.
+    #[serde(rename = "parametersChanged")]
+    pub parameters_changed: bool,
+    #[serde(rename = "exchangeRateChanged")]
+    pub exchange_rate_changed: bool,
+}
+
+fn json_changes(path: &str, from: &Value, to: &Value, changes: &mut Vec<FieldChange>) {
+    match (from, to) {
+        (Value::Object(from_fields), Value::Object(to_fields)) => {
+            let mut keys: Vec<&String> = from_fields.keys().chain(to_fields.keys()).collect();
+            keys.sort();
+            keys.dedup();
+            for key in keys {
+                json_changes(
+                    &format!("{}.{}", path, key),
+                    from_fields.get(key).unwrap_or(&Value::Null),
+                    to_fields.get(key).unwrap_or(&Value::Null),
+                    changes,
+                );
+            }
+        }
+        _ if from != to => changes.push(FieldChange {
+            field: path.to_string(),
+            from: from.clone(),
+            to: to.clone(),
+        }),
+        _ => {}
+    }
+}
+
+fn line_changes(from: &CostBreakdown, to: &CostBreakdown) -> Vec<LineChange> {
+    let (from_lines, to_lines) = (keyed_lines(from), keyed_lines(to));
+    let zero = BigDecimal::from(0);
+
+    let mut changes = Vec::new();
+    for (key, line) in &to_lines {
+        let previous = from_lines.iter().find(|(other, _)| other == key).map(|(_, line)| *line);
+        let change = &line.amount - previous.map_or(&zero, |previous| &previous.amount);
+        if previous.is_none() || change != zero {
+            changes.push(LineChange {
+                category: line.category,
+                section: line.section.clone(),
+                description: line.description.clone(),
+                from: previous.map(|previous| previous.amount.clone()),
+                to: Some(line.amount.clone()),
+                change,
+            });
+        }
+    }
+    for (key, line) in &from_lines {
+        if !to_lines.iter().any(|(other, _)| other == key) {
+            changes.push(LineChange {
+                category: line.category,
+                section: line.section.clone(),
+                description: line.description.clone(),
+                from: Some(line.amount.clone()),
+                to: None,
+                change: -line.amount.clone(),
+            });
+        }
+    }
+    changes
+}
+
+type LineKey = ((CostCategory, Option<String>), usize);
+
+/// Keys each line by category, section and how many such lines came before it.
+fn keyed_lines(breakdown: &CostBreakdown) -> Vec<(LineKey, &CostLine)> {
+    let mut seen: Vec<(CostCategory, Option<String>)> = Vec::new();
+    breakdown
+        .lines
+        .iter()
+        .map(|line| {
+            let key = (line.category, line.section.clone());
+            let position = seen.iter().filter(|other| **other == key).count();
+            seen.push(key.clone());
+            ((key, position), line)
+        })
+        .collect()
+}
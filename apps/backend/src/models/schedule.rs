@@ -0,0 +1,93 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::models::JobStatus;
+
+/// A day the whole shop is closed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Holiday {
+    pub date: NaiveDate,
+    pub name: String,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct CreateHolidayRequest {
+    pub date: NaiveDate,
+    #[validate(length(min = 1, max = 100))]
+    pub name: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ScheduleQuery {
+    /// First day of the calendar; today when omitted.
+    pub from: Option<NaiveDate>,
+    /// Days the calendar covers; 14 when omitted.
+    pub days: Option<i64>,
+}
+
+/// A job's share of one machine on one day.
+#[derive(Debug, Clone, Serialize)]
+pub struct ScheduledRun {
+    #[serde(rename = "jobId")]
+    pub job_id: Uuid,
+    pub title: String,
+    pub hours: f64,
+}
+
+/// One machine's bookings on one day. Work not routed through a configured
+/// machine is booked on a general production line with no `machine_id`.
+#[derive(Debug, Clone, Serialize)]
+pub struct MachineDay {
+    #[serde(rename = "machineId")]
+    pub machine_id: Option<Uuid>,
+    #[serde(rename = "machineName")]
+    pub machine_name: String,
+    /// Zero when the machine does not run that day.
+    #[serde(rename = "capacityHours")]
+    pub capacity_hours: f64,
+    #[serde(rename = "bookedHours")]
+    pub booked_hours: f64,
+    pub runs: Vec<ScheduledRun>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ScheduleDay {
+    pub date: NaiveDate,
+    /// Set when the shop is closed for a holiday.
+    pub holiday: Option<String>,
+    pub machines: Vec<MachineDay>,
+}
+
+/// When a job is planned to run and finish, against the date it is due.
+#[derive(Debug, Clone, Serialize)]
+pub struct JobSchedule {
+    #[serde(rename = "jobId")]
+    pub job_id: Uuid,
+    pub title: String,
+    pub status: JobStatus,
+    #[serde(rename = "requestedDate")]
+    pub requested_date: Option<NaiveDate>,
+    #[serde(rename = "promisedDate")]
+    pub promised_date: Option<NaiveDate>,
+    #[serde(rename = "startDate")]
+    pub start_date: NaiveDate,
+    /// None when the work does not fit within the planning horizon.
+    #[serde(rename = "completionDate")]
+    pub completion_date: Option<NaiveDate>,
+    /// The completion date falls after the promised date, or the requested
+    /// date when nothing has been promised.
+    pub late: bool,
+    #[serde(rename = "daysLate")]
+    pub days_late: i64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ProductionSchedule {
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+    pub days: Vec<ScheduleDay>,
+    /// Every approved and in-production job, in the order they were placed.
+    pub jobs: Vec<JobSchedule>,
+}
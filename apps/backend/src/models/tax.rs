@@ -0,0 +1,200 @@
+use bigdecimal::BigDecimal;
+use serde::{Deserialize, Serialize};
+
+use crate::models::Customer;
+
+/// EU member states, whose VAT-registered customers account for VAT
+/// themselves under the reverse-charge rule.
+const EU_COUNTRIES: &[&str] = &[
+    "AT", "BE", "BG", "CY", "CZ", "DE", "DK", "EE", "ES", "FI", "FR", "GR", "HR", "HU", "IE", "IT", "LT", "LU",
+    "LV", "MT", "NL", "PL", "PT", "RO", "SE", "SI", "SK",
+];
+
+/// One tax charged on the selling price, e.g. Cameroon's 19.25% VAT.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaxRate {
+    pub name: String,
+    /// Fraction of the net amount, e.g. `0.1925`.
+    pub rate: BigDecimal,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaxSettings {
+    /// ISO 3166-1 alpha-2 code of the country the company is registered in.
+    #[serde(rename = "companyCountry")]
+    pub company_country: String,
+    /// The company's own tax id, printed on quotes.
+    #[serde(rename = "companyTaxId")]
+    pub company_tax_id: Option<String>,
+    /// Taxes charged on every taxable sale, each on the net amount.
+    pub rates: Vec<TaxRate>,
+    /// Countries whose customers with a tax id are invoiced without tax.
+    #[serde(rename = "reverseChargeCountries")]
+    pub reverse_charge_countries: Vec<String>,
+    /// Legal mention printed on reverse-charge quotes.
+    #[serde(rename = "reverseChargeNote")]
+    pub reverse_charge_note: String,
+    /// Legal mention printed on quotes for exempt customers.
+    #[serde(rename = "exemptionNote")]
+    pub exemption_note: String,
+}
+
+impl Default for TaxSettings {
+    fn default() -> Self {
+        Self {
+            company_country: "CM".to_string(),
+            company_tax_id: None,
+            rates: vec![TaxRate {
+                name: "VAT".to_string(),
+                rate: "0.1925".parse().unwrap(),
+            }],
+            reverse_charge_countries: EU_COUNTRIES.iter().map(|code| code.to_string()).collect(),
+            reverse_charge_note: "Reverse charge: VAT to be accounted for by the recipient (Art. 196 Directive 2006/112/EC)"
+                .to_string(),
+            exemption_note: "Exempt from VAT".to_string(),
+        }
+    }
+}
+
+impl TaxSettings {
+    /// How a sale to `customer` is taxed. Quotes without a customer are
+    /// taxed at the standard rates.
+    pub fn treatment(&self, customer: Option<&Customer>) -> TaxTreatment {
+        let Some(customer) = customer else {
+            return TaxTreatment::Standard;
+        };
+        if customer.tax_exempt {
+            return TaxTreatment::Exempt;
+        }
+
+        let foreign_reverse_charge = customer.country.as_deref().is_some_and(|country| {
+            !country.eq_ignore_ascii_case(&self.company_country)
+                && self
+                    .reverse_charge_countries
+                    .iter()
+                    .any(|code| code.eq_ignore_ascii_case(country))
+        });
+        let has_tax_id = customer.tax_id.as_deref().is_some_and(|id| !id.trim().is_empty());
+        if foreign_reverse_charge && has_tax_id {
+            TaxTreatment::ReverseCharge
+        } else {
+            TaxTreatment::Standard
+        }
+    }
+
+    /// Tax lines and totals on top of the selling price `net`.
+    pub fn summary(&self, net: &BigDecimal, customer: Option<&Customer>) -> TaxSummary {
+        let treatment = self.treatment(customer);
+        let lines: Vec<TaxLine> = match treatment {
+            TaxTreatment::Standard => self
+                .rates
+                .iter()
+                .map(|rate| TaxLine {
+                    name: rate.name.clone(),
+                    rate: rate.rate.clone(),
+                    taxable_amount: net.clone(),
+                    amount: net * &rate.rate,
+                })
+                .collect(),
+            TaxTreatment::Exempt | TaxTreatment::ReverseCharge => Vec::new(),
+        };
+        let tax = lines.iter().fold(BigDecimal::from(0), |total, line| total + &line.amount);
+
+        TaxSummary {
+            gross: net + &tax,
+            net: net.clone(),
+            lines,
+            tax,
+            treatment,
+            note: match treatment {
+                TaxTreatment::Standard => None,
+                TaxTreatment::Exempt => Some(self.exemption_note.clone()),
+                TaxTreatment::ReverseCharge => Some(self.reverse_charge_note.clone()),
+            },
+            company_tax_id: self.company_tax_id.clone(),
+            customer_tax_id: customer.and_then(|customer| customer.tax_id.clone()),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateTaxSettingsRequest {
+    #[serde(rename = "companyCountry")]
+    pub company_country: Option<String>,
+    #[serde(rename = "companyTaxId")]
+    pub company_tax_id: Option<String>,
+    pub rates: Option<Vec<TaxRate>>,
+    #[serde(rename = "reverseChargeCountries")]
+    pub reverse_charge_countries: Option<Vec<String>>,
+    #[serde(rename = "reverseChargeNote")]
+    pub reverse_charge_note: Option<String>,
+    #[serde(rename = "exemptionNote")]
+    pub exemption_note: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TaxTreatment {
+    Standard,
+    /// The customer is exempt; no tax is charged.
+    Exempt,
+    /// The customer accounts for the tax; none is charged.
+    ReverseCharge,
+}
+
+impl std::fmt::Display for TaxTreatment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TaxTreatment::Standard => write!(f, "standard"),
+            TaxTreatment::Exempt => write!(f, "exempt"),
+            TaxTreatment::ReverseCharge => write!(f, "reverse_charge"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaxLine {
+    pub name: String,
+    pub rate: BigDecimal,
+    #[serde(rename = "taxableAmount")]
+    pub taxable_amount: BigDecimal,
+    pub amount: BigDecimal,
+}
+
+/// Net, tax and gross totals of a quote, computed after the selling price.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaxSummary {
+    /// Selling price before tax.
+    pub net: BigDecimal,
+    pub lines: Vec<TaxLine>,
+    pub tax: BigDecimal,
+    pub gross: BigDecimal,
+    pub treatment: TaxTreatment,
+    /// Legal mention explaining why no tax is charged.
+    pub note: Option<String>,
+    #[serde(rename = "companyTaxId")]
+    pub company_tax_id: Option<String>,
+    #[serde(rename = "customerTaxId")]
+    pub customer_tax_id: Option<String>,
+}
+
+impl TaxSummary {
+    /// Multiplies the amounts by `factor`, e.g. an exchange rate.
+    pub fn scaled(&self, factor: &BigDecimal) -> TaxSummary {
+        TaxSummary {
+            net: &self.net * factor,
+            lines: self
+                .lines
+                .iter()
+                .map(|line| TaxLine {
+                    taxable_amount: &line.taxable_amount * factor,
+                    amount: &line.amount * factor,
+                    ..line.clone()
+                })
+                .collect(),
+            tax: &self.tax * factor,
+            gross: &self.gross * factor,
+            ..self.clone()
+        }
+    }
+}
@@ -0,0 +1,100 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::models::{JobSpecifications, JobType, QuoteTerms};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TemplateScope {
+    /// Visible to the user who saved it.
+    User,
+    /// Visible to everyone; only managers may change it.
+    Shared,
+}
+
+impl std::fmt::Display for TemplateScope {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TemplateScope::User => write!(f, "user"),
+            TemplateScope::Shared => write!(f, "shared"),
+        }
+    }
+}
+
+impl std::str::FromStr for TemplateScope {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "user" => Ok(TemplateScope::User),
+            "shared" => Ok(TemplateScope::Shared),
+            _ => Err(format!("Invalid template scope: {}", s)),
+        }
+    }
+}
+
+/// Named preset for a recurring job, e.g. "Business cards, 350gsm matt".
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JobTemplate {
+    pub id: Uuid,
+    pub name: String,
+    pub description: Option<String>,
+    #[serde(rename = "jobType")]
+    pub job_type: JobType,
+    /// Quantity a job from the template starts with.
+    pub quantity: i32,
+    pub specifications: JobSpecifications,
+    pub scope: TemplateScope,
+    /// User who saved the template.
+    #[serde(rename = "ownerId")]
+    pub owner_id: Uuid,
+    #[serde(rename = "createdAt")]
+    pub created_at: DateTime<Utc>,
+    #[serde(rename = "updatedAt")]
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct CreateJobTemplateRequest {
+    #[validate(length(min = 1, max = 255))]
+    pub name: String,
+    pub description: Option<String>,
+    #[serde(rename = "jobType")]
+    pub job_type: JobType,
+    pub quantity: i32,
+    pub specifications: JobSpecifications,
+    pub scope: TemplateScope,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct UpdateJobTemplateRequest {
+    #[validate(length(min = 1, max = 255))]
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub quantity: Option<i32>,
+    pub specifications: Option<JobSpecifications>,
+    pub scope: Option<TemplateScope>,
+}
+
+/// Saves an existing job's type, quantity and specifications as a template.
+#[derive(Debug, Deserialize, Validate)]
+pub struct SaveJobAsTemplateRequest {
+    #[validate(length(min = 1, max = 255))]
+    pub name: String,
+    pub description: Option<String>,
+    pub scope: TemplateScope,
+}
+
+/// A job started from a template; anything omitted comes from the template.
+#[derive(Debug, Deserialize)]
+pub struct CreateJobFromTemplateRequest {
+    pub title: String,
+    pub quantity: Option<i32>,
+    pub specifications: Option<JobSpecifications>,
+    #[serde(rename = "customerId")]
+    pub customer_id: Option<Uuid>,
+    #[serde(flatten)]
+    pub terms: QuoteTerms,
+}
@@ -3,6 +3,8 @@ use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 use validator::Validate;
 
+use crate::models::Currency;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum UserRole {
     Admin,
@@ -42,6 +44,7 @@ pub struct User {
     pub first_name: Option<String>,
     pub last_name: Option<String>,
     pub is_active: bool,
+    pub preferred_currency: Option<Currency>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -0,0 +1,233 @@
+use lopdf::{Dictionary, Document, Object};
+
+use crate::{
+    models::{ArtworkAnalysis, ArtworkWarning, ArtworkWarningKind, Job, JobAttachment, JobType, PageBox},
+    services::costing_strategies::imposition::{self, Dimensions},
+    utils::errors::AppError,
+};
+
+const MM_PER_POINT: f64 = 25.4 / 72.0;
+
+/// Bleed the press needs beyond the trim on every side.
+const MIN_BLEED_MM: f64 = 3.0;
+
+/// Differences smaller than this are rounding in the file, not a real size
+/// difference.
+const SIZE_TOLERANCE_MM: f64 = 1.0;
+
+/// Smallest signature the bindery folds; see `BookSpecifications`.
+const MIN_SIGNATURE_PAGES: i32 = 4;
+
+/// A page box in PDF user space, in points.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Rect {
+    left: f64,
+    bottom: f64,
+    right: f64,
+    top: f64,
+}
+
+impl Rect {
+    fn size(&self, rotated: bool) -> PageBox {
+        let width = round_mm((self.right - self.left) * MM_PER_POINT);
+        let height = round_mm((self.top - self.bottom) * MM_PER_POINT);
+        if rotated {
+            PageBox { width_mm: height, height_mm: width }
+        } else {
+            PageBox { width_mm: width, height_mm: height }
+        }
+    }
+
+    /// Narrowest margin between this box and the `inner` one, in mm.
+    fn margin_mm(&self, inner: &Rect) -> f64 {
+        let margin = (inner.left - self.left)
+            .min(inner.bottom - self.bottom)
+            .min(self.right - inner.right)
+            .min(self.top - inner.top);
+        round_mm(margin.max(0.0) * MM_PER_POINT)
+    }
+}
+
+struct PdfPage {
+    media: Rect,
+    trim: Rect,
+    bleed: Option<Rect>,
+    /// Turned a quarter, so width and height swap on screen and on paper.
+    rotated: bool,
+}
+
+/// Reads the page boxes of a PDF and checks them against the job.
+pub fn analyze(attachment: &JobAttachment, bytes: &[u8], job: &Job) -> Result<ArtworkAnalysis, AppError> {
+    let pages = read_pages(bytes)?;
+    let first = pages
+        .first()
+        .ok_or(AppError::ValidationError("The PDF has no pages".to_string()))?;
+
+    let page_count = pages.len() as i32;
+    let trim_box = first.trim.size(first.rotated);
+    // Without a BleedBox, anything the MediaBox holds beyond the trim is bleed
+    let bleed_area = first.bleed.unwrap_or(first.media);
+    let bleed_mm = bleed_area.margin_mm(&first.trim);
+    let has_bleed = bleed_mm >= 0.5;
+
+    let mut warnings = Vec::new();
+
+    if job.job_type == JobType::Book {
+        let signature_pages = job
+            .specifications
+            .book
+            .as_ref()
+            .and_then(|book| book.signature_pages)
+            .unwrap_or(MIN_SIGNATURE_PAGES);
+        if page_count % signature_pages != 0 {
+            warnings.push(warning(
+                ArtworkWarningKind::SignatureMultiple,
+                format!(
+                    "{} pages is not a multiple of the {}-page signature; {} blank pages are needed to make {}",
+                    page_count,
+                    signature_pages,
+                    signature_pages - page_count % signature_pages,
+                    (page_count / signature_pages + 1) * signature_pages
+                ),
+            ));
+        }
+    }
+
+    if !has_bleed {
+        warnings.push(warning(
+            ArtworkWarningKind::MissingBleed,
+            format!("The artwork has no bleed; {} mm beyond the trim is needed on every side", MIN_BLEED_MM),
+        ));
+    } else if bleed_mm < MIN_BLEED_MM {
+        warnings.push(warning(
+            ArtworkWarningKind::InsufficientBleed,
+            format!("The bleed is {} mm; at least {} mm is needed on every side", bleed_mm, MIN_BLEED_MM),
+        ));
+    }
+
+    let odd_pages = pages
+        .iter()
+        .filter(|page| !same_size(page.trim.size(page.rotated), trim_box))
+        .count();
+    if odd_pages > 0 {
+        warnings.push(warning(
+            ArtworkWarningKind::MixedPageSizes,
+            format!(
+                "{} of {} pages differ from the first page's {} x {} mm trim",
+                odd_pages, page_count, trim_box.width_mm, trim_box.height_mm
+            ),
+        ));
+    }
+
+    let trim = Dimensions::new(trim_box.width_mm, trim_box.height_mm);
+    if let Some(paper) = imposition::parse_paper_size(&job.specifications.paper_size) {
+        let matches = same_size(trim_box, PageBox { width_mm: paper.width_mm, height_mm: paper.height_mm })
+            || same_size(trim_box, PageBox { width_mm: paper.height_mm, height_mm: paper.width_mm });
+        if !matches {
+            warnings.push(warning(
+                ArtworkWarningKind::SizeMismatch,
+                format!(
+                    "The artwork trims to {} x {} mm but the job is specified as {}",
+                    trim_box.width_mm, trim_box.height_mm, job.specifications.paper_size
+                ),
+            ));
+        }
+    }
+
+    let suggested_paper_size = imposition::named_size(trim, SIZE_TOLERANCE_MM)
+        .map(str::to_string)
+        .unwrap_or_else(|| format!("{}x{}mm", trim_box.width_mm, trim_box.height_mm));
+
+    Ok(ArtworkAnalysis {
+        attachment_id: attachment.id,
+        page_count,
+        media_box: first.media.size(first.rotated),
+        trim_box,
+        bleed_box: first.bleed.map(|bleed| bleed.size(first.rotated)),
+        bleed_mm,
+        has_bleed,
+        suggested_pages: page_count,
+        suggested_paper_size,
+        warnings,
+    })
+}
+
+fn read_pages(bytes: &[u8]) -> Result<Vec<PdfPage>, AppError> {
+    let document = Document::load_mem(bytes)
+        .map_err(|e| AppError::ValidationError(format!("Could not read the PDF: {}", e)))?;
+
+    document
+        .get_pages()
+        .into_iter()
+        .map(|(number, id)| {
+            let page = document
+                .get_dictionary(id)
+                .map_err(|e| AppError::ValidationError(format!("Could not read page {}: {}", number, e)))?;
+            let media = inherited(&document, page, b"MediaBox")
+                .and_then(|object| rect(&document, object))
+                .ok_or(AppError::ValidationError(format!("Page {} has no MediaBox", number)))?;
+            let crop = inherited(&document, page, b"CropBox").and_then(|object| rect(&document, object));
+            // TrimBox and BleedBox are not inherited from the page tree
+            let trim = page.get(b"TrimBox").ok().and_then(|object| rect(&document, object));
+            let bleed = page.get(b"BleedBox").ok().and_then(|object| rect(&document, object));
+            let rotation = inherited(&document, page, b"Rotate")
+                .and_then(|object| object.as_i64().ok())
+                .unwrap_or(0);
+
+            Ok(PdfPage {
+                media,
+                trim: trim.or(crop).unwrap_or(media),
+                bleed,
+                rotated: rotation.rem_euclid(180) == 90,
+            })
+        })
+        .collect()
+}
+
+/// A page attribute, looked up through the page's ancestors when the page
+/// does not set it.
+fn inherited<'a>(document: &'a Document, page: &'a Dictionary, key: &[u8]) -> Option<&'a Object> {
+    let mut node = page;
+    // Bounded so a malformed file with a cycle in the tree cannot hang us
+    for _ in 0..32 {
+        if let Ok(object) = node.get(key) {
+            return document.dereference(object).ok().map(|(_, object)| object);
+        }
+        let parent = node.get(b"Parent").ok()?.as_reference().ok()?;
+        node = document.get_dictionary(parent).ok()?;
+    }
+    None
+}
+
+fn rect(document: &Document, object: &Object) -> Option<Rect> {
+    let (_, object) = document.dereference(object).ok()?;
+    let values: Vec<f64> = object
+        .as_array()
+        .ok()?
+        .iter()
+        .map(|value| document.dereference(value).ok()?.1.as_float().ok().map(f64::from))
+        .collect::<Option<_>>()?;
+    let [x1, y1, x2, y2] = values[..] else {
+        return None;
+    };
+
+    let rect = Rect {
+        left: x1.min(x2),
+        bottom: y1.min(y2),
+        right: x1.max(x2),
+        top: y1.max(y2),
+    };
+    (rect.right > rect.left && rect.top > rect.bottom).then_some(rect)
+}
+
+fn same_size(a: PageBox, b: PageBox) -> bool {
+    (a.width_mm - b.width_mm).abs() <= SIZE_TOLERANCE_MM && (a.height_mm - b.height_mm).abs() <= SIZE_TOLERANCE_MM
+}
+
+fn round_mm(value: f64) -> f64 {
+    (value * 10.0).round() / 10.0
+}
+
+fn warning(kind: ArtworkWarningKind, message: String) -> ArtworkWarning {
+    ArtworkWarning { kind, message }
+}
@@ -0,0 +1,218 @@
+use axum::body::Bytes;
+use chrono::Utc;
+use sea_orm::{ActiveModelTrait, ColumnTrait, EntityTrait, ModelTrait, QueryFilter, QueryOrder, Set};
+use sha2::{Digest, Sha256};
+use std::str::FromStr;
+use uuid::Uuid;
+
+use crate::{
+    entities::job_attachments,
+    models::{AttachmentKind, JobAttachment},
+    services::file_store::FileStore,
+    utils::errors::AppError,
+};
+
+/// Extensions accepted for upload and the MIME type each is served as.
+const ALLOWED_TYPES: &[(&str, &str)] = &[
+    ("pdf", "application/pdf"),
+    ("png", "image/png"),
+    ("jpg", "image/jpeg"),
+    ("jpeg", "image/jpeg"),
+    ("tif", "image/tiff"),
+    ("tiff", "image/tiff"),
+    ("svg", "image/svg+xml"),
+    ("ai", "application/postscript"),
+    ("eps", "application/postscript"),
+    ("psd", "image/vnd.adobe.photoshop"),
+    ("zip", "application/zip"),
+];
+
+const MAX_FILENAME_LENGTH: usize = 200;
+
+pub struct AttachmentService<'a> {
+    db: &'a sea_orm::DatabaseConnection,
+    files: &'a dyn FileStore,
+    max_bytes: usize,
+}
+
+impl<'a> AttachmentService<'a> {
+    pub fn new(db: &'a sea_orm::DatabaseConnection, files: &'a dyn FileStore, max_bytes: usize) -> Self {
+        Self { db, files, max_bytes }
+    }
+
+    pub async fn upload(
+        &self,
+        job_id: Uuid,
+        kind: AttachmentKind,
+        filename: &str,
+        bytes: Bytes,
+        uploaded_by: Option<Uuid>,
+    ) -> Result<JobAttachment, AppError> {
+        if bytes.is_empty() {
+            return Err(AppError::ValidationError("The file is empty".to_string()));
+        }
+        if bytes.len() > self.max_bytes {
+            return Err(AppError::ValidationError(format!(
+                "Files may be at most {} MB",
+                self.max_bytes / (1024 * 1024)
+            )));
+        }
+
+        let filename = sanitize_filename(filename)?;
+        let mime_type = mime_type_for(&filename, &bytes)?;
+        let checksum = hex::encode(Sha256::digest(&bytes));
+        let id = Uuid::new_v4();
+        let storage_key = format!("jobs/{}/{}", job_id, id);
+        let size_bytes = bytes.len() as i64;
+
+        self.files.put(&storage_key, bytes).await?;
+
+        let inserted = job_attachments::ActiveModel {
+            id: Set(id),
+            job_id: Set(job_id),
+            kind: Set(kind.to_string()),
+            filename: Set(filename),
+            mime_type: Set(mime_type.to_string()),
+            size_bytes: Set(size_bytes),
+            checksum: Set(checksum),
+            uploaded_by: Set(uploaded_by),
+            storage_key: Set(storage_key.clone()),
+            created_at: Set(Utc::now().into()),
+        }
+        .insert(self.db)
+        .await;
+
+        match inserted {
+            Ok(model) => Ok(to_attachment(model)),
+            Err(e) => {
+                // Don't leave a file behind that nothing points to
+                let _ = self.files.delete(&storage_key).await;
+                Err(e.into())
+            }
+        }
+    }
+
+    pub async fn list_for_job(&self, job_id: Uuid) -> Result<Vec<JobAttachment>, AppError> {
+        let models = job_attachments::Entity::find()
+            .filter(job_attachments::Column::JobId.eq(job_id))
+            .order_by_asc(job_attachments::Column::CreatedAt)
+            .all(self.db)
+            .await?;
+        Ok(models.into_iter().map(to_attachment).collect())
+    }
+
+    pub async fn find_by_id(&self, job_id: Uuid, attachment_id: Uuid) -> Result<Option<JobAttachment>, AppError> {
+        let model = job_attachments::Entity::find_by_id(attachment_id)
+            .filter(job_attachments::Column::JobId.eq(job_id))
+            .one(self.db)
+            .await?;
+        Ok(model.map(to_attachment))
+    }
+
+    /// The attachment's metadata and content.
+    pub async fn download(&self, job_id: Uuid, attachment_id: Uuid) -> Result<(JobAttachment, Bytes), AppError> {
+        let attachment = self
+            .find_by_id(job_id, attachment_id)
+            .await?
+            .ok_or(AppError::NotFound("Attachment not found".to_string()))?;
+        let bytes = self.files.get(&attachment.storage_key).await?;
+        Ok((attachment, bytes))
+    }
+
+    pub async fn delete(&self, job_id: Uuid, attachment_id: Uuid) -> Result<(), AppError> {
+        let model = job_attachments::Entity::find_by_id(attachment_id)
+            .filter(job_attachments::Column::JobId.eq(job_id))
+            .one(self.db)
+            .await?
+            .ok_or(AppError::NotFound("Attachment not found".to_string()))?;
+
+        self.files.delete(&model.storage_key).await?;
+        model.delete(self.db).await?;
+        Ok(())
+    }
+
+    /// Removes every attachment of a deleted job.
+    pub async fn delete_for_job(&self, job_id: Uuid) -> Result<(), AppError> {
+        let models = job_attachments::Entity::find()
+            .filter(job_attachments::Column::JobId.eq(job_id))
+            .all(self.db)
+            .await?;
+
+        for model in models {
+            self.files.delete(&model.storage_key).await?;
+            model.delete(self.db).await?;
+        }
+        Ok(())
+    }
+}
+
+/// Keeps the last path component and drops characters that would upset a
+/// `Content-Disposition` header or a file system.
+fn sanitize_filename(filename: &str) -> Result<String, AppError> {
+    let name = filename.rsplit(['/', '\\']).next().unwrap_or_default();
+    let cleaned: String = name
+        .chars()
+        .filter(|c| !c.is_control() && !matches!(c, '"' | '<' | '>' | ':' | '|' | '?' | '*'))
+        .collect::<String>()
+        .trim()
+        .trim_start_matches('.')
+        .chars()
+        .take(MAX_FILENAME_LENGTH)
+        .collect();
+
+    if cleaned.is_empty() {
+        return Err(AppError::ValidationError("A file name is required".to_string()));
+    }
+    Ok(cleaned)
+}
+
+/// The MIME type for an allowed extension, checked against the file's
+/// leading bytes for formats that have a signature.
+fn mime_type_for(filename: &str, bytes: &[u8]) -> Result<&'static str, AppError> {
+    let extension = filename
+        .rsplit_once('.')
+        .map(|(_, extension)| extension.to_lowercase())
+        .unwrap_or_default();
+    let mime_type = ALLOWED_TYPES
+        .iter()
+        .find(|(allowed, _)| *allowed == extension)
+        .map(|(_, mime_type)| *mime_type)
+        .ok_or_else(|| {
+            let allowed: Vec<&str> = ALLOWED_TYPES.iter().map(|(extension, _)| *extension).collect();
+            AppError::ValidationError(format!("File type not allowed. Upload one of: {}", allowed.join(", ")))
+        })?;
+
+    let signatures: &[&[u8]] = match mime_type {
+        "application/pdf" => &[b"%PDF"],
+        "image/png" => &[b"\x89PNG"],
+        "image/jpeg" => &[b"\xFF\xD8\xFF"],
+        "image/tiff" => &[b"II*\0", b"MM\0*"],
+        "image/vnd.adobe.photoshop" => &[b"8BPS"],
+        "application/zip" => &[b"PK\x03\x04"],
+        // Text formats and Illustrator files, which may be PDF or PostScript
+        _ => &[],
+    };
+    if !signatures.is_empty() && !signatures.iter().any(|signature| bytes.starts_with(signature)) {
+        return Err(AppError::ValidationError(format!(
+            "The file's content does not match its .{} extension",
+            extension
+        )));
+    }
+
+    Ok(mime_type)
+}
+
+fn to_attachment(model: job_attachments::Model) -> JobAttachment {
+    JobAttachment {
+        id: model.id,
+        job_id: model.job_id,
+        kind: AttachmentKind::from_str(&model.kind).unwrap_or(AttachmentKind::Other),
+        filename: model.filename,
+        mime_type: model.mime_type,
+        size_bytes: model.size_bytes,
+        checksum: model.checksum,
+        uploaded_by: model.uploaded_by,
+        storage_key: model.storage_key,
+        created_at: model.created_at.into(),
+    }
+}
@@ -0,0 +1,317 @@
+use chrono::Utc;
+use sea_orm::{ActiveModelTrait, ColumnTrait, EntityTrait, ModelTrait, QueryFilter, QueryOrder, Set, TransactionTrait};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::{
+    entities::{job_comment_edits, job_comments, users},
+    models::{CommentEdit, CreateCommentRequest, JobComment, UpdateCommentRequest},
+    utils::errors::AppError,
+};
+
+pub struct CommentService<'a> {
+    db: &'a sea_orm::DatabaseConnection,
+}
+
+impl<'a> CommentService<'a> {
+    pub fn new(db: &'a sea_orm::DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// The job's comments, oldest first. With `customer_visible_only`,
+    /// internal notes are left out.
+    pub async fn list_for_job(&self, job_id: Uuid, customer_visible_only: bool) -> Result<Vec<JobComment>, AppError> {
+        let mut query = job_comments::Entity::find().filter(job_comments::Column::JobId.eq(job_id));
+        if customer_visible_only {
+            query = query.filter(job_comments::Column::CustomerVisible.eq(true));
+        }
+        let models = query
+            .order_by_asc(job_comments::Column::CreatedAt)
+            .all(self.db)
+            .await?;
+        self.to_comments(models).await
+    }
+
+    /// Comments that mention the user, newest first.
+    pub async fn list_mentions(&self, user_id: Uuid) -> Result<Vec<JobComment>, AppError> {
+        // Mentions are stored as a JSON array of ids, and an id cannot match
+        // part of another
+        let models = job_comments::Entity::find()
+            .filter(job_comments::Column::Mentions.contains(user_id.to_string()))
+            .order_by_desc(job_comments::Column::CreatedAt)
+            .all(self.db)
+            .await?;
+        self.to_comments(models).await
+    }
+
+    pub async fn create_comment(
+        &self,
+        job_id: Uuid,
+        author_id: Uuid,
+        request: CreateCommentRequest,
+    ) -> Result<JobComment, AppError> {
+        let mentions = self.resolve_mentions(&request.body).await?;
+
+        let model = job_comments::ActiveModel {
+            id: Set(Uuid::new_v4()),
+            job_id: Set(job_id),
+            author_id: Set(author_id),
+            body: Set(request.body),
+            customer_visible: Set(request.customer_visible),
+            mentions: Set(mentions_to_json(&mentions)?),
+            edited: Set(false),
+            created_at: Set(Utc::now().into()),
+            updated_at: Set(Utc::now().into()),
+        }
+        .insert(self.db)
+        .await?;
+
+        self.to_comment(model).await
+    }
+
+    /// Changes a comment, keeping what it said before in its history. Only
+    /// the author may edit.
+    pub async fn update_comment(
+        &self,
+        job_id: Uuid,
+        comment_id: Uuid,
+        user_id: Uuid,
+        request: UpdateCommentRequest,
+    ) -> Result<JobComment, AppError> {
+        let model = self.find_model(job_id, comment_id).await?;
+        if model.author_id != user_id {
+            return Err(AppError::Forbidden("Only the author can edit a comment".to_string()));
+        }
+
+        let body = request.body.unwrap_or_else(|| model.body.clone());
+        let customer_visible = request.customer_visible.unwrap_or(model.customer_visible);
+        if body == model.body && customer_visible == model.customer_visible {
+            return self.to_comment(model).await;
+        }
+        let mentions = self.resolve_mentions(&body).await?;
+
+        let txn = self.db.begin().await?;
+        job_comment_edits::ActiveModel {
+            id: Set(Uuid::new_v4()),
+            comment_id: Set(model.id),
+            body: Set(model.body.clone()),
+            customer_visible: Set(model.customer_visible),
+            edited_by: Set(user_id),
+            edited_at: Set(Utc::now().into()),
+        }
+        .insert(&txn)
+        .await?;
+
+        let mut active: job_comments::ActiveModel = model.into();
+        active.body = Set(body);
+        active.customer_visible = Set(customer_visible);
+        active.mentions = Set(mentions_to_json(&mentions)?);
+        active.edited = Set(true);
+        active.updated_at = Set(Utc::now().into());
+        let updated = active.update(&txn).await?;
+        txn.commit().await?;
+
+        self.to_comment(updated).await
+    }
+
+    /// Earlier versions of a comment, oldest first.
+    pub async fn history(&self, job_id: Uuid, comment_id: Uuid) -> Result<Vec<CommentEdit>, AppError> {
+        let comment = self.find_model(job_id, comment_id).await?;
+        let edits = job_comment_edits::Entity::find()
+            .filter(job_comment_edits::Column::CommentId.eq(comment.id))
+            .order_by_asc(job_comment_edits::Column::EditedAt)
+            .all(self.db)
+            .await?;
+        Ok(edits.into_iter().map(to_edit).collect())
+    }
+
+    /// Removes a comment and its history. Authors may delete their own
+    /// comments, managers anyone's.
+    pub async fn delete_comment(&self, job_id: Uuid, comment_id: Uuid, user_id: Uuid, manager: bool) -> Result<(), AppError> {
+        let model = self.find_model(job_id, comment_id).await?;
+        if model.author_id != user_id && !manager {
+            return Err(AppError::Forbidden("Only the author or a manager can delete a comment".to_string()));
+        }
+
+        let txn = self.db.begin().await?;
+        job_comment_edits::Entity::delete_many()
+            .filter(job_comment_edits::Column::CommentId.eq(model.id))
+            .exec(&txn)
+            .await?;
+        model.delete(&txn).await?;
+        txn.commit().await?;
+        Ok(())
+    }
+
+    /// Removes every comment of a deleted job.
+    pub async fn delete_for_job(&self, job_id: Uuid) -> Result<(), AppError> {
+        let ids: Vec<Uuid> = job_comments::Entity::find()
+            .filter(job_comments::Column::JobId.eq(job_id))
+            .all(self.db)
+            .await?
+            .into_iter()
+            .map(|comment| comment.id)
+            .collect();
+        if ids.is_empty() {
+            return Ok(());
+        }
+
+        let txn = self.db.begin().await?;
+        job_comment_edits::Entity::delete_many()
+            .filter(job_comment_edits::Column::CommentId.is_in(ids))
+            .exec(&txn)
+            .await?;
+        job_comments::Entity::delete_many()
+            .filter(job_comments::Column::JobId.eq(job_id))
+            .exec(&txn)
+            .await?;
+        txn.commit().await?;
+        Ok(())
+    }
+
+    async fn find_model(&self, job_id: Uuid, comment_id: Uuid) -> Result<job_comments::Model, AppError> {
+        job_comments::Entity::find_by_id(comment_id)
+            .filter(job_comments::Column::JobId.eq(job_id))
+            .one(self.db)
+            .await?
+            .ok_or(AppError::NotFound("Comment not found".to_string()))
+    }
+
+    /// Active users named in the body. Names that match no one, or more
+    /// than one user, are left as plain text.
+    async fn resolve_mentions(&self, body: &str) -> Result<Vec<Uuid>, AppError> {
+        let handles = mention_handles(body);
+        if handles.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let users = users::Entity::find()
+            .filter(users::Column::IsActive.eq(true))
+            .all(self.db)
+            .await?;
+
+        let mut mentions = Vec::new();
+        for handle in handles {
+            let matching: Vec<Uuid> = users
+                .iter()
+                .filter(|user| {
+                    let email = user.email.to_lowercase();
+                    if handle.contains('@') {
+                        email == handle
+                    } else {
+                        email.split('@').next() == Some(handle.as_str())
+                    }
+                })
+                .map(|user| user.id)
+                .collect();
+            if let [user_id] = matching[..] {
+                if !mentions.contains(&user_id) {
+                    mentions.push(user_id);
+                }
+            }
+        }
+        Ok(mentions)
+    }
+
+    async fn to_comment(&self, model: job_comments::Model) -> Result<JobComment, AppError> {
+        let mut comments = self.to_comments(vec![model]).await?;
+        Ok(comments.remove(0))
+    }
+
+    async fn to_comments(&self, models: Vec<job_comments::Model>) -> Result<Vec<JobComment>, AppError> {
+        let mut author_ids: Vec<Uuid> = models.iter().map(|model| model.author_id).collect();
+        author_ids.sort();
+        author_ids.dedup();
+
+        let authors: HashMap<Uuid, String> = if author_ids.is_empty() {
+            HashMap::new()
+        } else {
+            users::Entity::find()
+                .filter(users::Column::Id.is_in(author_ids))
+                .all(self.db)
+                .await?
+                .into_iter()
+                .map(|user| (user.id, display_name(&user)))
+                .collect()
+        };
+
+        models
+            .into_iter()
+            .map(|model| {
+                let mentions: Vec<Uuid> = serde_json::from_str(&model.mentions)
+                    .map_err(|e| AppError::InternalServerError(format!("Invalid stored mentions: {}", e)))?;
+                Ok(JobComment {
+                    id: model.id,
+                    job_id: model.job_id,
+                    author_id: model.author_id,
+                    author_name: authors.get(&model.author_id).cloned(),
+                    body: model.body,
+                    customer_visible: model.customer_visible,
+                    mentions,
+                    edited: model.edited,
+                    created_at: model.created_at.into(),
+                    updated_at: model.updated_at.into(),
+                })
+            })
+            .collect()
+    }
+}
+
+/// Lowercased `@` handles in a comment, e.g. `jane` and `sam@example.com` in
+/// "@jane please check with @sam@example.com.". An `@` inside a word, as in
+/// an email address written out, does not start one.
+fn mention_handles(body: &str) -> Vec<String> {
+    let mut handles = Vec::new();
+    let mut previous = ' ';
+    let mut chars = body.char_indices().peekable();
+
+    while let Some((index, c)) = chars.next() {
+        if c == '@' && !previous.is_alphanumeric() {
+            let rest = &body[index + 1..];
+            let length = rest
+                .find(|c: char| !(c.is_alphanumeric() || matches!(c, '.' | '_' | '-' | '+' | '@')))
+                .unwrap_or(rest.len());
+            // Sentence punctuation after a handle is not part of it
+            let handle = rest[..length].trim_end_matches(['.', '-', '@']);
+            if !handle.is_empty() {
+                handles.push(handle.to_lowercase());
+            }
+            while chars.peek().is_some_and(|(next, _)| *next <= index + length) {
+                chars.next();
+            }
+            previous = 'x';
+            continue;
+        }
+        previous = c;
+    }
+
+    handles
+}
+
+fn display_name(user: &users::Model) -> String {
+    let name = [user.first_name.as_deref(), user.last_name.as_deref()]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>()
+        .join(" ");
+    if name.trim().is_empty() {
+        user.email.clone()
+    } else {
+        name
+    }
+}
+
+fn mentions_to_json(mentions: &[Uuid]) -> Result<String, AppError> {
+    serde_json::to_string(mentions).map_err(|e| AppError::InternalServerError(format!("Failed to serialize mentions: {}", e)))
+}
+
+fn to_edit(model: job_comment_edits::Model) -> CommentEdit {
+    CommentEdit {
+        id: model.id,
+        comment_id: model.comment_id,
+        body: model.body,
+        customer_visible: model.customer_visible,
+        edited_by: model.edited_by,
+        edited_at: model.edited_at.into(),
+    }
+}
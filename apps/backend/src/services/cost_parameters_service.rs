@@ -1,5 +1,8 @@
+use chrono::Utc;
+use sea_orm::{sea_query::OnConflict, ActiveModelTrait, EntityTrait, QueryOrder, Set};
+
 use crate::{
-    db::Database,
+    entities::cost_parameter_versions,
     models::{CostParameters, UpdateCostParametersRequest},
     utils::errors::AppError,
 };
@@ -13,10 +16,30 @@ impl<'a> CostParametersService<'a> {
         Self { db }
     }
 
+    /// The latest saved parameters. The defaults are saved as version 1 the
+    /// first time they are needed, so every quote points at a stored version.
     pub async fn get_current_parameters(&self) -> Result<CostParameters, AppError> {
-        // For now, return default parameters
-        // In a real implementation, this would query the database
-        Ok(CostParameters::default())
+        let latest = cost_parameter_versions::Entity::find()
+            .order_by_desc(cost_parameter_versions::Column::Version)
+            .one(self.db)
+            .await?;
+
+        match latest {
+            Some(model) => to_parameters(&model),
+            None => {
+                let defaults = CostParameters::default();
+                // A concurrent request may save the defaults first; either copy will do
+                cost_parameter_versions::Entity::insert(to_active_model(&defaults)?)
+                    .on_conflict(
+                        OnConflict::column(cost_parameter_versions::Column::Version)
+                            .do_nothing()
+                            .to_owned(),
+                    )
+                    .exec_without_returning(self.db)
+                    .await?;
+                Ok(defaults)
+            }
+        }
     }
 
     pub async fn update_parameters(
@@ -29,8 +52,8 @@ impl<'a> CostParametersService<'a> {
             current_params.paper_cost_per_sheet = paper_cost_per_sheet;
         }
 
-        if let Some(plate_cost_per_job) = request.plate_cost_per_job {
-            current_params.plate_cost_per_job = plate_cost_per_job;
+        if let Some(plate_cost) = request.plate_cost {
+            current_params.plate_cost = plate_cost;
         }
 
         if let Some(labor_cost_per_hour) = request.labor_cost_per_hour {
@@ -49,9 +72,84 @@ impl<'a> CostParametersService<'a> {
             current_params.profit_margin_percentage = profit_margin_percentage;
         }
 
-        current_params.updated_at = chrono::Utc::now();
+        if let Some(pricing) = request.pricing {
+            current_params.pricing = pricing;
+        }
+
+        if let Some(press_sheet_width_mm) = request.press_sheet_width_mm {
+            current_params.press_sheet_width_mm = press_sheet_width_mm;
+        }
+
+        if let Some(press_sheet_height_mm) = request.press_sheet_height_mm {
+            current_params.press_sheet_height_mm = press_sheet_height_mm;
+        }
+
+        if let Some(gutter_mm) = request.gutter_mm {
+            current_params.gutter_mm = gutter_mm;
+        }
+
+        if let Some(book) = request.book {
+            current_params.book = book;
+        }
+
+        if let Some(large_format) = request.large_format {
+            current_params.large_format = large_format;
+        }
+
+        if let Some(sticker) = request.sticker {
+            current_params.sticker = sticker;
+        }
+
+        if let Some(business_card) = request.business_card {
+            current_params.business_card = business_card;
+        }
+
+        if let Some(lamination) = request.lamination {
+            current_params.lamination = lamination;
+        }
+
+        if let Some(ink) = request.ink {
+            current_params.ink = ink;
+        }
+
+        if let Some(digital) = request.digital {
+            current_params.digital = digital;
+        }
+
+        if let Some(turnaround) = request.turnaround {
+            current_params.turnaround = turnaround;
+        }
+
+        if let Some(adjustments) = request.adjustments {
+            current_params.adjustments = adjustments;
+        }
+
+        if let Some(finishing_operations) = request.finishing_operations {
+            current_params.finishing_operations = finishing_operations;
+        }
+
+        // Earlier versions stay stored for the quotes priced with them
+        current_params.version += 1;
+        current_params.updated_at = Utc::now();
+        to_active_model(&current_params)?.insert(self.db).await?;
 
-        // TODO: Save to database
         Ok(current_params)
     }
 }
+
+fn to_parameters(model: &cost_parameter_versions::Model) -> Result<CostParameters, AppError> {
+    let mut parameters: CostParameters = serde_json::from_str(&model.parameters)
+        .map_err(|e| AppError::InternalServerError(format!("Invalid stored cost parameters: {}", e)))?;
+    parameters.version = model.version;
+    Ok(parameters)
+}
+
+fn to_active_model(parameters: &CostParameters) -> Result<cost_parameter_versions::ActiveModel, AppError> {
+    let json = serde_json::to_string(parameters).map_err(|e| AppError::InternalServerError(e.to_string()))?;
+
+    Ok(cost_parameter_versions::ActiveModel {
+        version: Set(parameters.version),
+        parameters: Set(json),
+        created_at: Set(parameters.updated_at.into()),
+    })
+}
@@ -1,14 +1,40 @@
-use std::collections::HashMap;
 use bigdecimal::BigDecimal;
+use chrono::{NaiveDate, Utc};
+use std::collections::{hash_map::Entry, HashMap};
+use std::sync::Arc;
+use uuid::Uuid;
 
 use crate::{
     models::{
-        CostParameters, JobSpecifications, JobType, CostBreakdown, Currency,
+        AdjustmentKind, AdjustmentLine, AdjustmentParameters, BudgetOption, BudgetRequest, CostCalculationRequest,
+        CostParameters,
+        JobSpecifications, JobType, StockOption,
+        CostBreakdown, Currency, Customer, Discount, DiscountKind, Machine, PriceSummary, PrintProcess,
+        ProcessAlternative, ProfitMode, QuoteTerms, TaxSettings, TaxSummary,
+    },
+    services::{
+        cost_parameters_service::CostParametersService,
+        costing_strategies::{strategy_for, CostingStrategy},
+        currency_service::CurrencyService,
+        customer_service::CustomerService,
+        machine_service::MachineService,
+        tax_settings_service::TaxSettingsService,
     },
-    services::{cost_parameters_service::CostParametersService, currency_service::CurrencyService},
     utils::errors::AppError,
 };
 
+/// Largest quantity considered when looking for the offset/digital crossover.
+const MAX_CROSSOVER_QUANTITY: i32 = 1_000_000;
+
+/// Largest quantity considered when fitting a job to a budget.
+const MAX_BUDGET_QUANTITY: i32 = 1_000_000;
+
+/// Budget options returned when the request does not say.
+const DEFAULT_BUDGET_OPTIONS: usize = 5;
+
+/// Most quotes priced in one batch.
+pub const MAX_BATCH_ITEMS: usize = 5_000;
+
 pub struct CostingService<'a> {
     db: &'a sea_orm::DatabaseConnection,
 }
@@ -23,78 +49,606 @@ impl<'a> CostingService<'a> {
         job_type: &JobType,
         quantity: i32,
         specifications: &JobSpecifications,
+        customer_id: Option<Uuid>,
+        terms: &QuoteTerms,
     ) -> Result<CostCalculationResult, AppError> {
+        let context = self.load_context(customer_id).await?;
+
+        Self::calculate_with_parameters(job_type, quantity, specifications, &context, terms)
+    }
+
+    /// Checks that a job can be priced as specified, exactly as when a job
+    /// is created, without a customer or commercial terms.
+    pub async fn validate_job(
+        &self,
+        job_type: &JobType,
+        quantity: i32,
+        specifications: &JobSpecifications,
+    ) -> Result<(), AppError> {
+        let context = self.load_context(None).await?;
+
+        Self::calculate_with_parameters(job_type, quantity, specifications, &context, &QuoteTerms::default()).map(|_| ())
+    }
+
+    /// Prices many quotes against one snapshot of parameters, machines, tax
+    /// rules and customers, spread over the available cores. Results keep
+    /// the order of `requests`; one that cannot be priced carries its error
+    /// instead of failing the rest.
+    pub async fn calculate_batch(
+        &self,
+        requests: Vec<CostCalculationRequest>,
+    ) -> Result<Vec<Result<CostCalculationResult, AppError>>, AppError> {
+        if requests.len() > MAX_BATCH_ITEMS {
+            return Err(AppError::ValidationError(format!(
+                "A batch can price at most {} items",
+                MAX_BATCH_ITEMS
+            )));
+        }
+
+        // Each customer is loaded once, whatever the number of their items
+        let snapshot = self.load_context(None).await?;
+        let mut contexts: HashMap<Option<Uuid>, PricingContext> = HashMap::new();
+        for customer_id in requests.iter().filter_map(|request| request.customer_id) {
+            if let Entry::Vacant(entry) = contexts.entry(Some(customer_id)) {
+                let customer = CustomerService::new(self.db).find_by_id(customer_id).await?;
+                entry.insert(PricingContext { customer, ..snapshot.clone() });
+            }
+        }
+        contexts.insert(None, snapshot);
+        let contexts = Arc::new(contexts);
+
+        let workers = std::thread::available_parallelism().map_or(1, |workers| workers.get());
+        let chunk_size = requests.len().div_ceil(workers).max(1);
+        let mut requests = requests.into_iter();
+        let mut tasks = Vec::new();
+        loop {
+            let chunk: Vec<CostCalculationRequest> = requests.by_ref().take(chunk_size).collect();
+            if chunk.is_empty() {
+                break;
+            }
+            let contexts = Arc::clone(&contexts);
+            tasks.push(tokio::task::spawn_blocking(move || {
+                chunk
+                    .iter()
+                    .map(|request| {
+                        CostingService::calculate_with_parameters(
+                            &request.job_type,
+                            request.quantity,
+                            &request.specifications,
+                            &contexts[&request.customer_id],
+                            &request.terms,
+                        )
+                    })
+                    .collect::<Vec<_>>()
+            }));
+        }
+
+        let mut results = Vec::new();
+        for task in tasks {
+            results.extend(task.await.map_err(|e| AppError::InternalServerError(e.to_string()))?);
+        }
+        Ok(results)
+    }
+
+    /// Options for a job within `budget`, given in the base currency: the
+    /// requested specification and each requested variant at the largest
+    /// quantity that fits, best first. Variants that cannot be printed are
+    /// left out.
+    pub async fn fit_budget(&self, request: &BudgetRequest, budget: &BigDecimal) -> Result<Vec<BudgetOption>, AppError> {
+        let context = self.load_context(request.customer_id).await?;
+        let variants = budget_variants(&request.specifications, &request.stocks, request.vary_finishing);
+
+        let mut options = Vec::new();
+        for (index, (description, specifications)) in variants.into_iter().enumerate() {
+            let fit = Self::fit_quantity(&request.job_type, &specifications, budget, request.includes_tax, &context, &request.terms);
+            let (quantity, priced) = match fit {
+                Ok(Some(fit)) => fit,
+                Ok(None) => continue,
+                // Only the specification as requested has to be printable
+                Err(error) if index == 0 => return Err(error),
+                Err(_) => continue,
+            };
+            let PricedJob { processes, selected, tax, .. } = priced;
+            let price = if request.includes_tax { &tax.gross } else { &selected.total_cost };
+            let option = BudgetOption {
+                description,
+                quantity,
+                specifications,
+                process: selected.process,
+                remaining: budget - price,
+                cost_breakdown: selected.cost_breakdown,
+                total_cost: selected.total_cost,
+                unit_cost: selected.unit_cost,
+                pricing: selected.pricing,
+                tax,
+                crossover_quantity: None,
+            };
+            options.push((option, processes));
+        }
+
+        options.sort_by(|(a, _), (b, _)| b.quantity.cmp(&a.quantity).then_with(|| a.total_cost.cmp(&b.total_cost)));
+        options.truncate(request.limit.unwrap_or(DEFAULT_BUDGET_OPTIONS).max(1));
+
+        // The crossover search is the costly part, so only options returned get one
+        Ok(options
+            .into_iter()
+            .map(|(option, processes)| BudgetOption {
+                crossover_quantity: Self::crossover_for(&request.job_type, &option.specifications, &processes, &context),
+                ..option
+            })
+            .collect())
+    }
+
+    /// Parameters, machines, tax rules and customer a quote is priced with.
+    async fn load_context(&self, customer_id: Option<Uuid>) -> Result<PricingContext, AppError> {
         let cost_params_service = CostParametersService::new(self.db);
         let cost_params = cost_params_service.get_current_parameters().await?;
+        let machines = MachineService::new(self.db).active_machines().await?;
+        let tax_settings = TaxSettingsService::new(self.db).get_settings().await?;
+        let customer = match customer_id {
+            Some(customer_id) => CustomerService::new(self.db).find_by_id(customer_id).await?,
+            None => None,
+        };
+
+        Ok(PricingContext {
+            cost_params,
+            machines,
+            tax_settings,
+            customer,
+        })
+    }
+
+    /// Largest quantity whose price is within `budget`, or `None` when not
+    /// even one copy fits. The price grows with quantity except where a
+    /// volume discount starts or the small-quantity surcharge stops, where it
+    /// drops, so the stretch between those points is searched on its own:
+    /// the highest stretch whose first quantity fits, by doubling and then
+    /// bisection.
+    fn fit_quantity(
+        job_type: &JobType,
+        specifications: &JobSpecifications,
+        budget: &BigDecimal,
+        includes_tax: bool,
+        context: &PricingContext,
+        terms: &QuoteTerms,
+    ) -> Result<Option<(i32, PricedJob)>, AppError> {
+        let evaluate = |quantity: i32| -> Result<(bool, PricedJob), AppError> {
+            let priced = Self::price_job(job_type, quantity, specifications, context, terms)?;
+            let price = if includes_tax { &priced.tax.gross } else { &priced.selected.total_cost };
+            Ok((price <= budget, priced))
+        };
 
-        // Calculate paper cost
-        let paper_cost = cost_params.paper_cost_per_sheet.clone() * BigDecimal::from(quantity);
-        let plate_cost = cost_params.plate_cost_per_job.clone();
-        let labor_cost = cost_params.labor_cost_per_hour.clone() * BigDecimal::from(2); // Assume 2 hours
-        
-        let binding_cost = cost_params.binding_cost_per_unit.clone() * BigDecimal::from(quantity);
+        let adjustments = &context.cost_params.adjustments;
+        let mut starts: Vec<i32> = adjustments
+            .volume_discounts
+            .iter()
+            .map(|tier| tier.min_quantity)
+            .chain(std::iter::once(adjustments.small_quantity_threshold))
+            .filter(|quantity| (2..=MAX_BUDGET_QUANTITY).contains(quantity))
+            .collect();
+        starts.push(1);
+        starts.sort_unstable();
+        starts.dedup();
 
-        // Calculate finishing cost
-        let finishing_cost = self.calculate_finishing_cost(quantity, specifications)?;
+        // The highest stretch whose first quantity fits holds the answer
+        let mut found = None;
+        for (index, start) in starts.iter().enumerate() {
+            let (fits, priced) = evaluate(*start)?;
+            if fits {
+                let end = starts.get(index + 1).map_or(MAX_BUDGET_QUANTITY, |next| next - 1);
+                found = Some((*start, end, priced));
+            }
+        }
+        let Some((mut fitting, end, mut best)) = found else {
+            return Ok(None);
+        };
 
-        // Calculate subtotal
-        let subtotal = paper_cost.clone() + plate_cost.clone() + labor_cost.clone() + binding_cost.clone() + finishing_cost.clone();
+        let mut over = end + 1;
+        let mut step = 1;
+        while fitting + step <= end {
+            let (fits, priced) = evaluate(fitting + step)?;
+            if !fits {
+                over = fitting + step;
+                break;
+            }
+            fitting += step;
+            best = priced;
+            step = step.saturating_mul(2);
+        }
 
-        // Calculate overhead
-        let overhead = subtotal.clone() * cost_params.overhead_percentage.clone();
+        while over - fitting > 1 {
+            let middle = fitting + (over - fitting) / 2;
+            let (fits, priced) = evaluate(middle)?;
+            if fits {
+                fitting = middle;
+                best = priced;
+            } else {
+                over = middle;
+            }
+        }
+        Ok(Some((fitting, best)))
+    }
 
-        // Calculate total before margin
-        let total_before_margin = subtotal + overhead.clone();
+    /// Prices a job against an explicit parameter set, machine list, tax
+    /// rules and customer, without touching the database. Every eligible
+    /// process is costed; the pinned one is selected if given, otherwise the
+    /// cheapest after discounts and surcharges. A customer's profit rate
+    /// overrides the configured ones, and their tax status decides the tax
+    /// added on top of the quoted price.
+    pub fn calculate_with_parameters(
+        job_type: &JobType,
+        quantity: i32,
+        specifications: &JobSpecifications,
+        context: &PricingContext,
+        terms: &QuoteTerms,
+    ) -> Result<CostCalculationResult, AppError> {
+        let PricedJob { processes, costed, selected, tax } =
+            Self::price_job(job_type, quantity, specifications, context, terms)?;
+        let crossover_quantity = Self::crossover_for(job_type, specifications, &processes, context);
 
-        // Calculate profit margin
-        let profit_margin_amount = total_before_margin.clone() * cost_params.profit_margin_percentage.clone();
+        let alternatives = costed
+            .into_iter()
+            .map(|cost| ProcessAlternative {
+                process: cost.process,
+                selected: cost.process == selected.process,
+                total_cost: cost.total_cost,
+                unit_cost: cost.unit_cost,
+                estimated_delivery_days: cost.estimated_delivery_days,
+            })
+            .collect();
 
-        // Calculate final cost
-        let final_cost = total_before_margin + profit_margin_amount;
+        Ok(CostCalculationResult {
+            parameters_version: context.cost_params.version,
+            tax,
+            cost_breakdown: selected.cost_breakdown,
+            total_cost: selected.total_cost,
+            unit_cost: selected.unit_cost,
+            pricing: selected.pricing,
+            process: selected.process,
+            estimated_delivery_days: selected.estimated_delivery_days,
+            alternatives,
+            crossover_quantity,
+        })
+    }
 
-        // Calculate unit cost
-        let unit_cost = final_cost.clone() / BigDecimal::from(quantity);
+    /// Costs every eligible process and selects the one quoted, with its
+    /// tax. Leaves out the crossover search, so it is cheap enough to call
+    /// repeatedly.
+    fn price_job(
+        job_type: &JobType,
+        quantity: i32,
+        specifications: &JobSpecifications,
+        context: &PricingContext,
+        terms: &QuoteTerms,
+    ) -> Result<PricedJob, AppError> {
+        let PricingContext { cost_params, machines, tax_settings, customer } = context;
+        let customer = customer.as_ref();
 
-        // Create breakdown details (simplified for now)
-        let _breakdown_details: HashMap<String, BigDecimal> = HashMap::new();
+        if quantity <= 0 {
+            return Err(AppError::ValidationError("Quantity must be greater than zero".to_string()));
+        }
 
-        let cost_breakdown = CostBreakdown {
-            paper_cost,
-            plate_cost,
-            labor_cost,
-            binding_cost,
-            finishing_cost,
-            overhead,
-        };
+        // Direct production costs depend on the kind of job
+        let strategy = strategy_for(job_type);
+        strategy.validate(quantity, specifications, cost_params)?;
+        let profit_rate = Self::profit_rate(job_type, context);
+
+        // Only processes the shop has a working press for
+        let processes: Vec<PrintProcess> = strategy
+            .processes(specifications, cost_params)
+            .into_iter()
+            .filter(|process| {
+                process
+                    .press()
+                    .is_none_or(|kind| machines.iter().any(|machine| machine.kind == kind && machine.is_active))
+            })
+            .collect();
+        if let Some(pinned) = specifications.process {
+            if !processes.contains(&pinned) {
+                return Err(AppError::ValidationError(format!(
+                    "This job cannot be printed on {}",
+                    pinned
+                )));
+            }
+        }
+
+        let today = Utc::now().date_naive();
+        let customer_discount = customer.and_then(|customer| customer.discount.as_ref());
+        let costed = processes
+            .iter()
+            .map(|process| {
+                let cost =
+                    Self::price(strategy.as_ref(), quantity, specifications, cost_params, machines, &profit_rate, *process)?;
+                Self::adjusted(cost, quantity, cost_params, customer_discount, terms, today)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let selected = match specifications.process {
+            Some(pinned) => costed.iter().find(|cost| cost.process == pinned),
+            None => costed.iter().min_by(|a, b| a.total_cost.cmp(&b.total_cost)),
+        }
+        .ok_or_else(|| AppError::ValidationError("No print process is available for this job".to_string()))?
+        .clone();
+
+        // Tax is charged on the quoted price, never folded into it
+        let tax = tax_settings.summary(&selected.total_cost, customer);
+
+        Ok(PricedJob {
+            processes,
+            costed,
+            selected,
+            tax,
+        })
+    }
+
+    /// The customer's profit rate, else the job type's, else the default.
+    fn profit_rate(job_type: &JobType, context: &PricingContext) -> BigDecimal {
+        let customer_rate = context.customer.as_ref().and_then(|customer| customer.profit_rate.as_ref());
+        context.cost_params.profit_rate(job_type, customer_rate)
+    }
+
+    /// Offset/digital crossover for a job that can go on both.
+    fn crossover_for(
+        job_type: &JobType,
+        specifications: &JobSpecifications,
+        processes: &[PrintProcess],
+        context: &PricingContext,
+    ) -> Option<i32> {
+        if !(processes.contains(&PrintProcess::Offset) && processes.contains(&PrintProcess::Digital)) {
+            return None;
+        }
+        let strategy = strategy_for(job_type);
+        let profit_rate = Self::profit_rate(job_type, context);
+        Self::crossover_quantity(strategy.as_ref(), specifications, &context.cost_params, &context.machines, &profit_rate)
+    }
+
+    /// Production costs on one process plus overhead and profit.
+    fn price(
+        strategy: &dyn CostingStrategy,
+        quantity: i32,
+        specifications: &JobSpecifications,
+        cost_params: &CostParameters,
+        machines: &[Machine],
+        profit_rate: &BigDecimal,
+        process: PrintProcess,
+    ) -> Result<ProcessCost, AppError> {
+        let mut cost_breakdown = strategy.calculate(quantity, specifications, cost_params, machines, process)?;
 
-        let cost_calculation_result = CostCalculationResult {
+        let pricing = Self::price_summary(cost_breakdown.production_total(), cost_params, profit_rate)?;
+        let final_cost = pricing.selling_price.clone();
+
+        // Calculate unit cost
+        let unit_cost = &final_cost / BigDecimal::from(quantity);
+
+        cost_breakdown.overhead = pricing.overhead.clone();
+        let estimated_delivery_days = cost_params
+            .turnaround
+            .delivery_days(process, cost_breakdown.production_hours);
+
+        Ok(ProcessCost {
+            process,
             cost_breakdown,
             total_cost: final_cost,
             unit_cost,
+            pricing,
+            estimated_delivery_days,
+        })
+    }
+
+    /// Adds overhead to production cost, then profit by markup on that
+    /// full cost or as a margin on the selling price.
+    fn price_summary(
+        production_cost: BigDecimal,
+        cost_params: &CostParameters,
+        profit_rate: &BigDecimal,
+    ) -> Result<PriceSummary, AppError> {
+        let zero = BigDecimal::from(0);
+        let overhead = &production_cost * &cost_params.overhead_percentage;
+        let full_cost = &production_cost + &overhead;
+
+        let mode = cost_params.pricing.mode;
+        let selling_price = match mode {
+            ProfitMode::Markup => &full_cost + &full_cost * profit_rate,
+            ProfitMode::Margin => {
+                let cost_share = BigDecimal::from(1) - profit_rate;
+                if cost_share <= zero {
+                    return Err(AppError::ValidationError(format!(
+                        "A margin of {} leaves nothing to cover cost; it must be below 1",
+                        profit_rate
+                    )));
+                }
+                &full_cost / cost_share
+            }
         };
+        let profit = &selling_price - &full_cost;
 
-        Ok(cost_calculation_result)
+        let margin_percentage = if selling_price > zero { &profit / &selling_price } else { zero.clone() };
+        let markup_percentage = if full_cost > zero { &profit / &full_cost } else { zero.clone() };
+
+        Ok(PriceSummary {
+            requires_approval: margin_percentage < cost_params.pricing.minimum_margin_percentage,
+            production_cost,
+            overhead,
+            full_cost,
+            profit,
+            quoted_price: selling_price.clone(),
+            selling_price,
+            profit_mode: mode,
+            profit_rate: profit_rate.clone(),
+            margin_percentage,
+            markup_percentage,
+            adjustments: Vec::new(),
+        })
     }
 
-    fn calculate_finishing_cost(
-        &self,
+    /// Applies the commercial rules to a priced process. The quoted price
+    /// replaces the selling price as its total, and the approval check is
+    /// redone on the margin that is left.
+    fn adjusted(
+        mut cost: ProcessCost,
         quantity: i32,
-        specifications: &JobSpecifications,
-    ) -> Result<BigDecimal, AppError> {
-        use std::str::FromStr;
-        let mut finishing_cost = BigDecimal::from_str("0.00").unwrap();
+        cost_params: &CostParameters,
+        customer_discount: Option<&Discount>,
+        terms: &QuoteTerms,
+        today: NaiveDate,
+    ) -> Result<ProcessCost, AppError> {
+        let zero = BigDecimal::from(0);
+        let pricing = &mut cost.pricing;
+        pricing.adjustments = Self::adjustments(
+            &cost_params.adjustments,
+            &pricing.selling_price,
+            quantity,
+            cost.estimated_delivery_days,
+            customer_discount,
+            terms,
+            today,
+        )?;
+        pricing.quoted_price = pricing
+            .adjustments
+            .iter()
+            .fold(pricing.selling_price.clone(), |total, line| total + &line.amount);
+
+        let quoted_margin = if pricing.quoted_price > zero {
+            (&pricing.quoted_price - &pricing.full_cost) / &pricing.quoted_price
+        } else {
+            zero
+        };
+        pricing.requires_approval = quoted_margin < cost_params.pricing.minimum_margin_percentage;
+
+        cost.total_cost = pricing.quoted_price.clone();
+        cost.unit_cost = &cost.total_cost / BigDecimal::from(quantity);
+        Ok(cost)
+    }
+
+    /// Itemised discounts and surcharges on `selling_price`, in the order
+    /// they apply: discounts, then surcharges, then the minimum order top-up.
+    fn adjustments(
+        params: &AdjustmentParameters,
+        selling_price: &BigDecimal,
+        quantity: i32,
+        estimated_delivery_days: i32,
+        customer_discount: Option<&Discount>,
+        terms: &QuoteTerms,
+        today: NaiveDate,
+    ) -> Result<Vec<AdjustmentLine>, AppError> {
+        let mut lines = Vec::new();
+
+        let volume = params
+            .volume_discounts
+            .iter()
+            .filter(|tier| quantity >= tier.min_quantity)
+            .max_by_key(|tier| tier.min_quantity);
+        if let Some(tier) = volume {
+            lines.push(
+                AdjustmentLine::new(
+                    AdjustmentKind::VolumeDiscount,
+                    format!("Volume discount ({}+ units)", tier.min_quantity),
+                    -(selling_price * &tier.percentage),
+                )
+                .with_rate(tier.percentage.clone()),
+            );
+        }
+
+        if let Some(discount) = customer_discount {
+            lines.push(discount_line(AdjustmentKind::CustomerDiscount, "Customer discount", discount, selling_price));
+        }
 
-        // Lamination cost
-        if let Some(_lamination) = &specifications.lamination {
-            finishing_cost += BigDecimal::from(quantity) * BigDecimal::from_str("0.005").unwrap(); // $0.005 per unit
+        if let Some(code) = terms.promo_code.as_deref().map(str::trim).filter(|code| !code.is_empty()) {
+            let promo = params
+                .promo_codes
+                .iter()
+                .find(|promo| promo.code.eq_ignore_ascii_case(code))
+                .ok_or_else(|| AppError::ValidationError(format!("Unknown promo code {}", code)))?;
+            if let Some(valid_until) = promo.valid_until.filter(|valid_until| *valid_until < today) {
+                return Err(AppError::ValidationError(format!(
+                    "Promo code {} expired on {}",
+                    promo.code, valid_until
+                )));
+            }
+            lines.push(discount_line(
+                AdjustmentKind::PromoDiscount,
+                &format!("Promo code {}", promo.code),
+                &promo.discount,
+                selling_price,
+            ));
         }
 
-        // Other finishing costs
-        for _finishing in &specifications.finishing {
-            finishing_cost += BigDecimal::from(quantity) * BigDecimal::from_str("0.002").unwrap(); // $0.002 per unit per finishing
+        if let Some(requested) = terms.requested_delivery_date {
+            let available_days = (requested - today).num_days();
+            if available_days < 0 {
+                return Err(AppError::ValidationError("Requested delivery date is in the past".to_string()));
+            }
+            let days_short = estimated_delivery_days as i64 - available_days;
+            if days_short > 0 {
+                let rate = (&params.rush_surcharge_per_day * BigDecimal::from(days_short)).min(params.max_rush_surcharge.clone());
+                lines.push(
+                    AdjustmentLine::new(
+                        AdjustmentKind::RushSurcharge,
+                        format!("Rush: {} days instead of {}", available_days, estimated_delivery_days),
+                        selling_price * &rate,
+                    )
+                    .with_rate(rate),
+                );
+            }
         }
 
-        Ok(finishing_cost)
+        if quantity < params.small_quantity_threshold {
+            lines.push(AdjustmentLine::new(
+                AdjustmentKind::SmallQuantitySurcharge,
+                format!("Small quantity (under {} units)", params.small_quantity_threshold),
+                params.small_quantity_surcharge.clone(),
+            ));
+        }
+
+        // Discounts never take the price below zero
+        let adjusted = lines
+            .iter()
+            .fold(selling_price.clone(), |total, line| total + &line.amount)
+            .max(BigDecimal::from(0));
+        if adjusted < params.minimum_order_value {
+            lines.push(AdjustmentLine::new(
+                AdjustmentKind::MinimumOrderCharge,
+                format!("Minimum order value {}", params.minimum_order_value),
+                &params.minimum_order_value - adjusted,
+            ));
+        }
+
+        Ok(lines)
+    }
+
+    /// Smallest quantity at which offset costs no more than digital, found
+    /// by bisection. `None` when offset is already cheaper for a single copy
+    /// or digital stays cheaper up to `MAX_CROSSOVER_QUANTITY`.
+    fn crossover_quantity(
+        strategy: &dyn CostingStrategy,
+        specifications: &JobSpecifications,
+        cost_params: &CostParameters,
+        machines: &[Machine],
+        profit_rate: &BigDecimal,
+    ) -> Option<i32> {
+        let offset_cheaper = |quantity: i32| -> Option<bool> {
+            let total = |process| {
+                Self::price(strategy, quantity, specifications, cost_params, machines, profit_rate, process)
+                    .ok()
+                    .map(|cost| cost.total_cost)
+            };
+            Some(total(PrintProcess::Offset)? <= total(PrintProcess::Digital)?)
+        };
+
+        if offset_cheaper(1)? || !offset_cheaper(MAX_CROSSOVER_QUANTITY)? {
+            return None;
+        }
+
+        let (mut digital, mut offset) = (1, MAX_CROSSOVER_QUANTITY);
+        while offset - digital > 1 {
+            let middle = digital + (offset - digital) / 2;
+            if offset_cheaper(middle)? {
+                offset = middle;
+            } else {
+                digital = middle;
+            }
+        }
+        Some(offset)
     }
 
     pub async fn calculate_cost_with_currency(
@@ -102,70 +656,121 @@ impl<'a> CostingService<'a> {
         job_type: &JobType,
         quantity: i32,
         specifications: &JobSpecifications,
+        customer_id: Option<Uuid>,
+        base_currency: Currency,
         target_currency: Currency,
     ) -> Result<CostCalculationResultWithCurrency, AppError> {
-        // First calculate in USD (base currency)
-        let usd_result = self.calculate_cost(job_type, quantity, specifications).await?;
-        
-        if target_currency.code() == "USD" {
-            return Ok(CostCalculationResultWithCurrency {
-                cost_breakdown: usd_result.cost_breakdown,
-                total_cost: usd_result.total_cost,
-                unit_cost: usd_result.unit_cost,
-                currency: Currency::USD,
-                exchange_rate: 1.0,
-            });
-        }
-
-        // Convert to target currency
+        // First calculate in the company base currency
+        let base_result = self
+            .calculate_cost(job_type, quantity, specifications, customer_id, &QuoteTerms::default())
+            .await?;
+
+        // Convert every amount with a single rate so the breakdown still adds up
         let currency_service = CurrencyService::new();
-        
-        // Convert each cost component
-        let paper_cost_f64: f64 = usd_result.cost_breakdown.paper_cost.to_string().parse().unwrap_or(0.0);
-        let plate_cost_f64: f64 = usd_result.cost_breakdown.plate_cost.to_string().parse().unwrap_or(0.0);
-        let labor_cost_f64: f64 = usd_result.cost_breakdown.labor_cost.to_string().parse().unwrap_or(0.0);
-        let binding_cost_f64: f64 = usd_result.cost_breakdown.binding_cost.to_string().parse().unwrap_or(0.0);
-        let finishing_cost_f64: f64 = usd_result.cost_breakdown.finishing_cost.to_string().parse().unwrap_or(0.0);
-        let overhead_f64: f64 = usd_result.cost_breakdown.overhead.to_string().parse().unwrap_or(0.0);
-        let total_cost_f64: f64 = usd_result.total_cost.to_string().parse().unwrap_or(0.0);
-        let unit_cost_f64: f64 = usd_result.unit_cost.to_string().parse().unwrap_or(0.0);
-
-        let converted_paper = currency_service.convert_amount(paper_cost_f64, Currency::USD, target_currency.clone()).await?;
-        let converted_plate = currency_service.convert_amount(plate_cost_f64, Currency::USD, target_currency.clone()).await?;
-        let converted_labor = currency_service.convert_amount(labor_cost_f64, Currency::USD, target_currency.clone()).await?;
-        let converted_binding = currency_service.convert_amount(binding_cost_f64, Currency::USD, target_currency.clone()).await?;
-        let converted_finishing = currency_service.convert_amount(finishing_cost_f64, Currency::USD, target_currency.clone()).await?;
-        let converted_overhead = currency_service.convert_amount(overhead_f64, Currency::USD, target_currency.clone()).await?;
-        let converted_total = currency_service.convert_amount(total_cost_f64, Currency::USD, target_currency.clone()).await?;
-        let converted_unit = currency_service.convert_amount(unit_cost_f64, Currency::USD, target_currency.clone()).await?;
-
-        // Get exchange rate for display
-        let rates = currency_service.get_exchange_rates().await?;
-        let exchange_rate = rates.rates.get(target_currency.code()).copied().unwrap_or(1.0);
+        let exchange_rate = currency_service.get_rate(base_currency, target_currency).await?;
 
         use std::str::FromStr;
+        let factor = BigDecimal::from_str(&exchange_rate.to_string()).unwrap();
+
         Ok(CostCalculationResultWithCurrency {
-            cost_breakdown: CostBreakdown {
-                paper_cost: BigDecimal::from_str(&converted_paper.to_string()).unwrap(),
-                plate_cost: BigDecimal::from_str(&converted_plate.to_string()).unwrap(),
-                labor_cost: BigDecimal::from_str(&converted_labor.to_string()).unwrap(),
-                binding_cost: BigDecimal::from_str(&converted_binding.to_string()).unwrap(),
-                finishing_cost: BigDecimal::from_str(&converted_finishing.to_string()).unwrap(),
-                overhead: BigDecimal::from_str(&converted_overhead.to_string()).unwrap(),
-            },
-            total_cost: BigDecimal::from_str(&converted_total.to_string()).unwrap(),
-            unit_cost: BigDecimal::from_str(&converted_unit.to_string()).unwrap(),
+            cost_breakdown: base_result.cost_breakdown.scaled(&factor),
+            total_cost: base_result.total_cost * &factor,
+            unit_cost: base_result.unit_cost * &factor,
             currency: target_currency,
             exchange_rate,
         })
     }
 }
 
+fn discount_line(kind: AdjustmentKind, description: &str, discount: &Discount, price: &BigDecimal) -> AdjustmentLine {
+    let line = AdjustmentLine::new(kind, description, -discount.amount(price));
+    match discount.kind {
+        DiscountKind::Percentage => line.with_rate(discount.value.clone()),
+        DiscountKind::Fixed => line,
+    }
+}
+
+/// The requested specification followed by the stock and finishing
+/// variants to try, each with a description of what changed.
+fn budget_variants(
+    specifications: &JobSpecifications,
+    stocks: &[StockOption],
+    vary_finishing: bool,
+) -> Vec<(String, JobSpecifications)> {
+    let mut variants = vec![("As specified".to_string(), specifications.clone())];
+
+    for stock in stocks {
+        let mut variant = specifications.clone();
+        variant.paper_type = stock.paper_type.clone();
+        variant.paper_weight = stock.paper_weight.clone();
+        let description = match &stock.paper_weight {
+            Some(weight) => format!("On {} {}", weight, stock.paper_type),
+            None => format!("On {}", stock.paper_type),
+        };
+        variants.push((description, variant));
+    }
+
+    if vary_finishing {
+        if specifications.lamination.is_some() {
+            let mut variant = specifications.clone();
+            variant.lamination = None;
+            variants.push(("Without lamination".to_string(), variant));
+        }
+        for operation in &specifications.finishing {
+            let mut variant = specifications.clone();
+            variant.finishing.retain(|other| other != operation);
+            variants.push((format!("Without {}", operation), variant));
+        }
+        if specifications.finishing.len() > 1 {
+            let mut variant = specifications.clone();
+            variant.finishing.clear();
+            variants.push(("Without finishing".to_string(), variant));
+        }
+    }
+
+    variants
+}
+
 #[derive(Debug)]
 pub struct CostCalculationResult {
     pub cost_breakdown: CostBreakdown,
     pub total_cost: BigDecimal,
     pub unit_cost: BigDecimal,
+    pub pricing: PriceSummary,
+    pub tax: TaxSummary,
+    pub process: PrintProcess,
+    pub estimated_delivery_days: i32,
+    pub alternatives: Vec<ProcessAlternative>,
+    pub crossover_quantity: Option<i32>,
+    pub parameters_version: i32,
+}
+
+/// Everything loaded from the database to price a quote.
+#[derive(Clone)]
+pub struct PricingContext {
+    pub cost_params: CostParameters,
+    pub machines: Vec<Machine>,
+    pub tax_settings: TaxSettings,
+    pub customer: Option<Customer>,
+}
+
+/// A job costed on every eligible process, before the comparison extras.
+struct PricedJob {
+    processes: Vec<PrintProcess>,
+    costed: Vec<ProcessCost>,
+    selected: ProcessCost,
+    tax: TaxSummary,
+}
+
+/// A job priced on one process.
+#[derive(Debug, Clone)]
+struct ProcessCost {
+    process: PrintProcess,
+    cost_breakdown: CostBreakdown,
+    total_cost: BigDecimal,
+    unit_cost: BigDecimal,
+    pricing: PriceSummary,
+    estimated_delivery_days: i32,
 }
 
 #[derive(Debug)]
@@ -176,3 +781,84 @@ pub struct CostCalculationResultWithCurrency {
     pub currency: Currency,
     pub exchange_rate: f64,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{models::default_machines, services::costing_strategies::test_support::specifications};
+
+    fn context() -> PricingContext {
+        PricingContext {
+            cost_params: CostParameters::default(),
+            machines: default_machines(),
+            tax_settings: TaxSettings::default(),
+            customer: None,
+        }
+    }
+
+    fn price(quantity: i32, specifications: &JobSpecifications, context: &PricingContext) -> BigDecimal {
+        CostingService::price_job(&JobType::Flyer, quantity, specifications, context, &QuoteTerms::default())
+            .unwrap()
+            .selected
+            .total_cost
+    }
+
+    #[test]
+    fn budget_fit_reaches_past_a_volume_discount_tier() {
+        let context = context();
+        let specifications = specifications("A4");
+        let tier_price = price(5000, &specifications, &context);
+        assert!(price(4999, &specifications, &context) > tier_price);
+
+        let (quantity, priced) = CostingService::fit_quantity(
+            &JobType::Flyer,
+            &specifications,
+            &tier_price,
+            false,
+            &context,
+            &QuoteTerms::default(),
+        )
+        .unwrap()
+        .unwrap();
+
+        assert!(quantity >= 5000);
+        assert!(priced.selected.total_cost <= tier_price);
+        assert!(price(quantity + 1, &specifications, &context) > tier_price);
+    }
+
+    #[test]
+    fn budget_fit_finds_the_largest_quantity_below_the_first_tier() {
+        let context = context();
+        let specifications = specifications("A4");
+        let budget = price(1200, &specifications, &context);
+
+        let (quantity, _) = CostingService::fit_quantity(
+            &JobType::Flyer,
+            &specifications,
+            &budget,
+            false,
+            &context,
+            &QuoteTerms::default(),
+        )
+        .unwrap()
+        .unwrap();
+
+        assert!((1200..5000).contains(&quantity));
+        assert!(price(quantity + 1, &specifications, &context) > budget);
+    }
+
+    #[test]
+    fn budget_fit_is_none_when_one_copy_is_over_budget() {
+        let fit = CostingService::fit_quantity(
+            &JobType::Flyer,
+            &specifications("A4"),
+            &BigDecimal::from(0),
+            false,
+            &context(),
+            &QuoteTerms::default(),
+        )
+        .unwrap();
+
+        assert!(fit.is_none());
+    }
+}
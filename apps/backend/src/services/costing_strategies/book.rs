@@ -0,0 +1,403 @@
+use bigdecimal::BigDecimal;
+
+use super::{
+    ceil_div, finishing_lines, imposition, is_coated_stock, lamination_line, press_form,
+    press_sheet_area_square_meters, print_lines,
+    routing::{press_step, route_hours, route_lines, route_machine_hours, RouteStep},
+    sheet_fed_processes, CostingStrategy, Dimensions,
+};
+use crate::{
+    models::{
+        BindingMethod, ColorSpecification, CostBreakdown, CostCategory, CostExplanation, CostLine, CostParameters, JobSpecifications,
+        Machine, MachineKind, PrintProcess,
+    },
+    utils::errors::AppError,
+};
+
+/// Signature sizes the bindery can fold, largest first.
+const SIGNATURE_SIZES: [i32; 4] = [32, 16, 8, 4];
+
+const TEXT_BLOCK: &str = "textBlock";
+const COVER: &str = "cover";
+const BINDING: &str = "binding";
+
+/// Books are costed as a text block printed in signatures plus a separately
+/// printed cover wrapped around a spine sized from the page count.
+pub struct BookStrategy;
+
+/// How a book's pages are split into signatures and how thick the result is.
+struct BookLayout {
+    pages: i32,
+    signature_pages: i32,
+    signatures: i64,
+    binding_method: BindingMethod,
+    spine_width_mm: f64,
+}
+
+impl BookLayout {
+    fn new(specifications: &JobSpecifications, parameters: &CostParameters) -> Result<Self, AppError> {
+        let book = &parameters.book;
+        let pages = specifications.pages.unwrap_or(book.signature_pages);
+        if pages <= 0 {
+            return Err(AppError::ValidationError("Books must have at least one page".to_string()));
+        }
+
+        let requested_signature = specifications.book.as_ref().and_then(|spec| spec.signature_pages);
+        let signature_pages = signature_size(pages, requested_signature, book.signature_pages)?;
+
+        let binding_method = specifications
+            .book
+            .as_ref()
+            .map(|spec| spec.binding_method)
+            .or_else(|| specifications.binding.as_deref().and_then(|binding| binding.parse().ok()))
+            .unwrap_or(BindingMethod::PerfectBound);
+
+        // Caliper in mm is grammage × bulk / 1000; each leaf carries two pages
+        let gsm = specifications.paper_weight_gsm().unwrap_or(book.default_interior_gsm);
+        let bulk = if is_coated_stock(&specifications.paper_type) {
+            book.coated_paper_bulk
+        } else {
+            book.uncoated_paper_bulk
+        };
+        let mut spine_width_mm = pages as f64 / 2.0 * gsm as f64 * bulk / 1000.0;
+        if binding_method == BindingMethod::CaseBound {
+            spine_width_mm += 2.0 * book.case_board_thickness_mm;
+        }
+
+        let layout = BookLayout {
+            pages,
+            signature_pages,
+            signatures: (pages / signature_pages) as i64,
+            binding_method,
+            spine_width_mm,
+        };
+        layout.check_binding_limits(parameters)?;
+        Ok(layout)
+    }
+
+    fn check_binding_limits(&self, parameters: &CostParameters) -> Result<(), AppError> {
+        let book = &parameters.book;
+        match self.binding_method {
+            BindingMethod::SaddleStitch if self.pages > book.saddle_stitch_max_pages => {
+                Err(AppError::ValidationError(format!(
+                    "Saddle-stitched books cannot exceed {} pages ({} requested)",
+                    book.saddle_stitch_max_pages, self.pages
+                )))
+            }
+            BindingMethod::PerfectBound if self.spine_width_mm < book.perfect_bound_min_spine_mm => {
+                Err(AppError::ValidationError(format!(
+                    "Spine of {:.1} mm is too thin for perfect binding (minimum {} mm); consider saddle-stitch",
+                    self.spine_width_mm, book.perfect_bound_min_spine_mm
+                )))
+            }
+            BindingMethod::CaseBound if self.pages < book.case_bound_min_pages => {
+                Err(AppError::ValidationError(format!(
+                    "Case-bound books need at least {} pages ({} requested)",
+                    book.case_bound_min_pages, self.pages
+                )))
+            }
+            BindingMethod::WireO if self.spine_width_mm > book.wire_o_max_spine_mm => {
+                Err(AppError::ValidationError(format!(
+                    "Spine of {:.1} mm is too thick for wire-o binding (maximum {} mm)",
+                    self.spine_width_mm, book.wire_o_max_spine_mm
+                )))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Validates a requested signature size, or picks the largest one up to
+/// `max_signature_pages` that divides the page count.
+fn signature_size(pages: i32, requested: Option<i32>, max_signature_pages: i32) -> Result<i32, AppError> {
+    if let Some(signature_pages) = requested {
+        if !SIGNATURE_SIZES.contains(&signature_pages) {
+            return Err(AppError::ValidationError(format!(
+                "Signatures must be 4, 8, 16 or 32 pages, not {}",
+                signature_pages
+            )));
+        }
+        if pages % signature_pages != 0 {
+            return Err(AppError::ValidationError(format!(
+                "Page count {} is not a multiple of the {}-page signature",
+                pages, signature_pages
+            )));
+        }
+        return Ok(signature_pages);
+    }
+
+    SIGNATURE_SIZES
+        .iter()
+        .copied()
+        .filter(|size| *size <= max_signature_pages.max(4))
+        .find(|size| pages % size == 0)
+        .ok_or_else(|| {
+            AppError::ValidationError(format!(
+                "Page count {} must be a multiple of 4; the nearest valid count is {}",
+                pages,
+                (pages + 3) / 4 * 4
+            ))
+        })
+}
+
+impl CostingStrategy for BookStrategy {
+    fn validate(
+        &self,
+        _quantity: i32,
+        specifications: &JobSpecifications,
+        parameters: &CostParameters,
+    ) -> Result<(), AppError> {
+        BookLayout::new(specifications, parameters).map(|_| ())
+    }
+
+    /// Text block and cover go on the same process, so digital needs both
+    /// within its limits.
+    fn processes(&self, specifications: &JobSpecifications, parameters: &CostParameters) -> Vec<PrintProcess> {
+        let text = (&specifications.colors, specifications.paper_weight_gsm());
+        match specifications.book.as_ref().map(|spec| &spec.cover) {
+            Some(cover) => sheet_fed_processes(&[text, (&cover.colors, Some(cover.paper_weight_gsm))], parameters),
+            None => sheet_fed_processes(&[text], parameters),
+        }
+    }
+
+    fn calculate(
+        &self,
+        quantity: i32,
+        specifications: &JobSpecifications,
+        parameters: &CostParameters,
+        machines: &[Machine],
+        process: PrintProcess,
+    ) -> Result<CostBreakdown, AppError> {
+        let book = &parameters.book;
+        let layout = BookLayout::new(specifications, parameters)?;
+        let quantity = quantity as i64;
+        let trim = imposition::parse_paper_size(&specifications.paper_size);
+
+        // Text block: both sides of a press sheet carry 2 × ups pages, and
+        // every sheet of every signature is its own form
+        let text_form = press_form(&specifications.colors, trim, parameters);
+        let sheets_per_signature = ceil_div(layout.signature_pages as i64, 2 * text_form.ups);
+        let text_sheets = quantity * layout.signatures * sheets_per_signature;
+
+        // Cover: spread flat, back + spine + front, on its own stock and colors
+        let cover_spec = specifications.book.as_ref().map(|spec| &spec.cover);
+        let cover_colors = cover_spec
+            .map(|cover| cover.colors.clone())
+            .unwrap_or_else(|| ColorSpecification {
+                front_colors: book.cover_colors,
+                back_colors: 0,
+                spot_colors: Vec::new(),
+                is_full_color: false,
+                process_coverage: None,
+                spot_coverage: None,
+            });
+        let cover_flat = trim.map(|trim| Dimensions::new(2.0 * trim.width_mm + layout.spine_width_mm, trim.height_mm));
+        let cover_form = press_form(&cover_colors, cover_flat, parameters);
+        let cover_ups = if cover_flat.is_some() {
+            cover_form.ups
+        } else {
+            book.covers_per_sheet.max(1) as i64
+        };
+        let cover_sheets = ceil_div(quantity, cover_ups);
+        let cover_stock = cover_spec
+            .map(|cover| format!("{} {}gsm", cover.paper_type, cover.paper_weight_gsm))
+            .unwrap_or_else(|| "cover stock".to_string());
+
+        let interior_gsm = Some(specifications.paper_weight_gsm().unwrap_or(book.default_interior_gsm));
+        let cover_gsm = cover_spec.map(|cover| cover.paper_weight_gsm);
+        let text_route = [press_step(machines, process, text_sheets, interior_gsm)?];
+        let cover_route = [press_step(machines, process, cover_sheets, cover_gsm)?];
+        let binding_route = [
+            RouteStep::new(machines, MachineKind::Folder, text_sheets, interior_gsm)?,
+            RouteStep::new(machines, MachineKind::Binder, quantity, interior_gsm)?,
+        ];
+
+        let binding_rate = match layout.binding_method {
+            BindingMethod::PerfectBound => &book.binding_rates.perfect_bound,
+            BindingMethod::SaddleStitch => &book.binding_rates.saddle_stitch,
+            BindingMethod::CaseBound => &book.binding_rates.case_bound,
+            BindingMethod::WireO => &book.binding_rates.wire_o,
+        };
+
+        let mut lines = vec![
+            CostLine::new(
+                CostCategory::Paper,
+                format!(
+                    "{} press sheets ({} × {}-page signatures)",
+                    text_sheets, layout.signatures, layout.signature_pages
+                ),
+                &parameters.paper_cost_per_sheet * BigDecimal::from(text_sheets),
+            )
+            .in_section(TEXT_BLOCK)
+            .explained(
+                CostExplanation::new("quantity × signatures × ⌈signature pages ÷ (2 × ups)⌉ × paper cost per sheet")
+                    .input("quantity", quantity, "copies")
+                    .input("signatures", layout.signatures, "signatures")
+                    .input("signaturePages", layout.signature_pages, "pages")
+                    .input("ups", text_form.ups, "per side")
+                    .input("paperCostPerSheet", &parameters.paper_cost_per_sheet, "per sheet")
+                    .intermediate("sheetsPerSignature", sheets_per_signature, "sheets")
+                    .intermediate("pressSheets", text_sheets, "sheets"),
+            ),
+            CostLine::new(
+                CostCategory::Paper,
+                format!(
+                    "{} sheets of {}, {}-up, spine {:.1} mm",
+                    cover_sheets, cover_stock, cover_ups, layout.spine_width_mm
+                ),
+                &book.cover_cost_per_sheet * BigDecimal::from(cover_sheets),
+            )
+            .in_section(COVER)
+            .explained(
+                CostExplanation::new("⌈quantity ÷ covers per sheet⌉ × cover cost per sheet")
+                    .input("quantity", quantity, "copies")
+                    .input("coversPerSheet", cover_ups, "per sheet")
+                    .input("coverCostPerSheet", &book.cover_cost_per_sheet, "per sheet")
+                    .intermediate("spineWidth", layout.spine_width_mm, "mm")
+                    .intermediate("coverSheets", cover_sheets, "sheets"),
+            ),
+            CostLine::new(
+                CostCategory::Binding,
+                format!("Binding materials ({})", layout.binding_method),
+                &binding_rate.setup_cost + &binding_rate.cost_per_unit * BigDecimal::from(quantity),
+            )
+            .in_section(BINDING)
+            .explained(
+                CostExplanation::new("setup + quantity × cost per unit")
+                    .input("quantity", quantity, "copies")
+                    .input("setupCost", &binding_rate.setup_cost, "per job")
+                    .input("costPerUnit", &binding_rate.cost_per_unit, "per unit"),
+            ),
+        ];
+        lines.extend(route_lines(&text_route, Some(TEXT_BLOCK)));
+        lines.extend(route_lines(&cover_route, Some(COVER)));
+        lines.extend(route_lines(&binding_route, Some(BINDING)));
+        lines.extend(
+            print_lines(
+                process,
+                &text_form,
+                layout.signatures * sheets_per_signature,
+                &specifications.colors,
+                text_sheets,
+                parameters,
+            )
+            .into_iter()
+            .map(|line| line.in_section(TEXT_BLOCK)),
+        );
+        lines.extend(
+            print_lines(process, &cover_form, 1, &cover_colors, cover_sheets, parameters)
+                .into_iter()
+                .map(|line| line.in_section(COVER)),
+        );
+        // Books laminate the cover only
+        if let Some(lamination) = &specifications.lamination {
+            let (paper_type, paper_weight_gsm) = match cover_spec {
+                Some(cover) => (cover.paper_type.as_str(), Some(cover.paper_weight_gsm)),
+                None => (specifications.paper_type.as_str(), specifications.paper_weight_gsm()),
+            };
+            lines.push(
+                lamination_line(
+                    lamination,
+                    paper_type,
+                    paper_weight_gsm,
+                    cover_sheets as f64 * press_sheet_area_square_meters(parameters),
+                    parameters,
+                )?
+                .in_section(COVER),
+            );
+        }
+        lines.extend(finishing_lines(quantity as i32, specifications.finishing.iter(), parameters)?);
+
+        let route = || text_route.iter().chain(&cover_route).chain(&binding_route);
+        Ok(CostBreakdown::from_lines(lines)
+            .with_production_hours(route_hours(route()))
+            .with_machine_hours(route_machine_hours(route())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{models::default_machines, services::costing_strategies::test_support::specifications};
+
+    fn book(pages: i32, paper_type: &str, binding: &str) -> JobSpecifications {
+        JobSpecifications {
+            paper_type: paper_type.to_string(),
+            paper_weight: Some("80gsm".to_string()),
+            pages: Some(pages),
+            binding: Some(binding.to_string()),
+            ..specifications("A4")
+        }
+    }
+
+    #[test]
+    fn picks_the_largest_signature_that_divides_the_pages() {
+        assert_eq!(signature_size(64, None, 16).unwrap(), 16);
+        assert_eq!(signature_size(64, None, 32).unwrap(), 32);
+        assert_eq!(signature_size(40, None, 16).unwrap(), 8);
+        assert_eq!(signature_size(36, None, 16).unwrap(), 4);
+    }
+
+    #[test]
+    fn rejects_signatures_that_do_not_fold() {
+        assert!(matches!(
+            signature_size(42, None, 16),
+            Err(AppError::ValidationError(message)) if message.contains("nearest valid count is 44")
+        ));
+        assert!(signature_size(48, Some(32), 16).is_err());
+        assert!(signature_size(48, Some(12), 16).is_err());
+        assert_eq!(signature_size(48, Some(8), 16).unwrap(), 8);
+    }
+
+    #[test]
+    fn sizes_the_spine_from_caliper() {
+        let parameters = CostParameters::default();
+        let spine = |specifications: JobSpecifications| {
+            BookLayout::new(&specifications, &parameters).unwrap().spine_width_mm
+        };
+
+        // 100 leaves of 80gsm at 1.3 bulk uncoated, 0.9 coated
+        assert!((spine(book(200, "uncoated offset", "perfect_bound")) - 10.4).abs() < 1e-9);
+        assert!((spine(book(200, "silk", "perfect_bound")) - 7.2).abs() < 1e-9);
+        // Case binding adds a board on each side
+        assert!((spine(book(200, "uncoated offset", "case_bound")) - 15.4).abs() < 1e-9);
+    }
+
+    #[test]
+    fn enforces_binding_limits() {
+        let parameters = CostParameters::default();
+        let layout = |specifications: JobSpecifications| BookLayout::new(&specifications, &parameters);
+
+        assert!(layout(book(64, "uncoated", "saddle_stitch")).is_ok());
+        assert!(layout(book(80, "uncoated", "saddle_stitch")).is_err());
+        // 8 leaves make a 0.8 mm spine, too thin to glue
+        assert!(layout(book(16, "uncoated", "perfect_bound")).is_err());
+        assert!(layout(book(32, "uncoated", "case_bound")).is_err());
+        // 400 leaves make a 41.6 mm spine
+        assert!(layout(book(800, "uncoated", "wire_o")).is_err());
+    }
+
+    #[test]
+    fn prints_the_text_block_in_signatures() {
+        let parameters = CostParameters::default();
+        let breakdown = BookStrategy
+            .calculate(
+                1000,
+                &book(64, "uncoated", "perfect_bound"),
+                &parameters,
+                &default_machines(),
+                PrintProcess::Offset,
+            )
+            .unwrap();
+
+        // A4 is 2-up on SRA3, so each side carries 2 pages and a 16-page
+        // signature takes 4 sheets
+        let paper = breakdown
+            .lines
+            .iter()
+            .find(|line| line.section.as_deref() == Some(TEXT_BLOCK) && line.category == CostCategory::Paper)
+            .unwrap();
+        assert_eq!(paper.description, "16000 press sheets (4 × 16-page signatures)");
+        assert_eq!(paper.amount, BigDecimal::from(1600));
+    }
+}
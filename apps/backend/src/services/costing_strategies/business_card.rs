@@ -0,0 +1,151 @@
+use bigdecimal::BigDecimal;
+
+use super::{
+    ceil_div, finishing_lines, imposition, ink_lines, lamination_line, plate_line, press_form,
+    press_sheet_area_square_meters, print_lines,
+    routing::{press_step, route_hours, route_lines, route_machine_hours, RouteStep},
+    CostingStrategy, Dimensions,
+};
+use crate::{
+    models::{
+        CostBreakdown, CostCategory, CostExplanation, CostLine, CostParameters, JobSpecifications, Machine, MachineKind, PrintProcess,
+    },
+    utils::errors::AppError,
+};
+
+/// Standard 85 × 55 mm card, used when the size is not recognised.
+const DEFAULT_CARD_SIZE: Dimensions = Dimensions { width_mm: 85.0, height_mm: 55.0 };
+
+/// Business cards are gang-run on offset: several customers' cards share
+/// one press sheet, so plates and make-ready are split across the gang.
+/// Digital runs are printed on their own.
+pub struct BusinessCardStrategy;
+
+impl CostingStrategy for BusinessCardStrategy {
+    fn calculate(
+        &self,
+        quantity: i32,
+        specifications: &JobSpecifications,
+        parameters: &CostParameters,
+        machines: &[Machine],
+        process: PrintProcess,
+    ) -> Result<CostBreakdown, AppError> {
+        let jobs_per_gang = match process {
+            PrintProcess::Offset => parameters.business_card.jobs_per_gang_sheet.max(1),
+            _ => 1,
+        };
+        let card_size = imposition::parse_paper_size(&specifications.paper_size).unwrap_or(DEFAULT_CARD_SIZE);
+        let form = press_form(&specifications.colors, Some(card_size), parameters);
+        let press_sheets = ceil_div(quantity as i64, form.ups);
+
+        // Own run time plus a share of the gang's make-ready on press and cutter
+        let gsm = specifications.paper_weight_gsm();
+        let route = [
+            press_step(machines, process, press_sheets, gsm)?.with_make_ready_share(jobs_per_gang),
+            RouteStep::new(machines, MachineKind::Cutter, press_sheets, gsm)?.with_make_ready_share(jobs_per_gang),
+        ];
+
+        let mut lines = vec![CostLine::new(
+            CostCategory::Paper,
+            format!("{} press sheets", press_sheets),
+            &parameters.paper_cost_per_sheet * BigDecimal::from(press_sheets),
+        )
+        .explained(
+            CostExplanation::new("⌈quantity ÷ cards per sheet⌉ × paper cost per sheet")
+                .input("quantity", quantity, "cards")
+                .input("ups", form.ups, "per sheet")
+                .input("paperCostPerSheet", &parameters.paper_cost_per_sheet, "per sheet")
+                .intermediate("pressSheets", press_sheets, "sheets"),
+        )];
+        if process == PrintProcess::Offset {
+            // This job's share of the gang's plates
+            let gang_plates = plate_line(&form, 1, parameters);
+            let explanation = CostExplanation::new("plates × plate cost ÷ jobs per gang sheet")
+                .input("plates", form.plates, "plates")
+                .input("plateCost", &parameters.plate_cost, "per plate")
+                .input("jobsPerGangSheet", jobs_per_gang, "jobs")
+                .intermediate("gangPlateCost", &gang_plates.amount, "total");
+            lines.push(
+                CostLine::new(
+                    CostCategory::Plates,
+                    format!("Share of {}", gang_plates.description),
+                    gang_plates.amount / BigDecimal::from(jobs_per_gang),
+                )
+                .explained(explanation),
+            );
+            lines.extend(ink_lines(&specifications.colors, press_sheets, parameters));
+        } else {
+            lines.extend(print_lines(process, &form, 1, &specifications.colors, press_sheets, parameters));
+        }
+        lines.extend(route_lines(&route, None));
+        if let Some(lamination) = &specifications.lamination {
+            lines.push(lamination_line(
+                lamination,
+                &specifications.paper_type,
+                specifications.paper_weight_gsm(),
+                press_sheets as f64 * press_sheet_area_square_meters(parameters),
+                parameters,
+            )?);
+        }
+        lines.extend(finishing_lines(quantity, specifications.finishing.iter(), parameters)?);
+
+        Ok(CostBreakdown::from_lines(lines)
+            .with_production_hours(route_hours(&route))
+            .with_machine_hours(route_machine_hours(&route)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{models::default_machines, services::costing_strategies::test_support::specifications};
+
+    fn cards(parameters: &CostParameters, process: PrintProcess) -> CostBreakdown {
+        BusinessCardStrategy
+            .calculate(1000, &specifications("85x55"), parameters, &default_machines(), process)
+            .unwrap()
+    }
+
+    #[test]
+    fn gangs_cards_on_the_press_sheet() {
+        let parameters = CostParameters::default();
+        let breakdown = cards(&parameters, PrintProcess::Offset);
+
+        // 5 × 5 cards per SRA3 sheet
+        let paper = breakdown.lines.iter().find(|line| line.category == CostCategory::Paper).unwrap();
+        assert_eq!(paper.description, "40 press sheets");
+    }
+
+    #[test]
+    fn splits_plates_across_the_gang() {
+        let parameters = CostParameters::default();
+        let breakdown = cards(&parameters, PrintProcess::Offset);
+
+        // 4/4 on an odd grid runs sheetwise: 8 plates at 25.00, shared by 8 jobs
+        let plates = breakdown.lines.iter().find(|line| line.category == CostCategory::Plates).unwrap();
+        assert_eq!(plates.description, "Share of 8 plates, sheetwise");
+        assert_eq!(plates.amount, BigDecimal::from(25));
+    }
+
+    #[test]
+    fn splits_make_ready_across_the_gang() {
+        let ganged = cards(&CostParameters::default(), PrintProcess::Offset);
+        let mut parameters = CostParameters::default();
+        parameters.business_card.jobs_per_gang_sheet = 1;
+        let alone = cards(&parameters, PrintProcess::Offset);
+
+        // 30 min press and 10 min cutter make-ready, 7/8 of it carried by
+        // the rest of the gang
+        let saved = alone.production_hours - ganged.production_hours;
+        assert!((saved - 40.0 / 60.0 * 7.0 / 8.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn prints_digital_runs_alone() {
+        let parameters = CostParameters::default();
+        let breakdown = cards(&parameters, PrintProcess::Digital);
+
+        assert!(breakdown.lines.iter().all(|line| line.category != CostCategory::Plates));
+        assert!(breakdown.lines.iter().any(|line| line.description.starts_with("Digital clicks")));
+    }
+}
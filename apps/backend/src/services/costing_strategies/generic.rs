@@ -0,0 +1,89 @@
+use bigdecimal::BigDecimal;
+
+use super::{
+    ceil_div, finishing_lines, has_binding, imposition, lamination_line, press_form, press_sheet_area_square_meters,
+    print_lines,
+    routing::{press_step, route_hours, route_lines, route_machine_hours, RouteStep},
+    CostingStrategy,
+};
+use crate::{
+    models::{
+        CostBreakdown, CostCategory, CostExplanation, CostLine, CostParameters, JobSpecifications, Machine, MachineKind, PrintProcess,
+    },
+    utils::errors::AppError,
+};
+
+/// Sheet-fed costing for flat and folded work (flyers, brochures) and the
+/// fallback for `JobType::Custom`.
+pub struct GenericStrategy;
+
+impl CostingStrategy for GenericStrategy {
+    fn calculate(
+        &self,
+        quantity: i32,
+        specifications: &JobSpecifications,
+        parameters: &CostParameters,
+        machines: &[Machine],
+        process: PrintProcess,
+    ) -> Result<CostBreakdown, AppError> {
+        // Each leaf carries two pages
+        let leaves = ceil_div(specifications.pages.unwrap_or(1).max(1) as i64, 2);
+        let form = press_form(&specifications.colors, imposition::parse_paper_size(&specifications.paper_size), parameters);
+        let press_sheets = ceil_div(quantity as i64 * leaves, form.ups);
+        let gsm = specifications.paper_weight_gsm();
+
+        // Print, cut multi-up sheets apart, then fold anything with more than one leaf
+        let mut route = vec![press_step(machines, process, press_sheets, gsm)?];
+        if form.ups > 1 {
+            route.push(RouteStep::new(machines, MachineKind::Cutter, press_sheets, gsm)?);
+        }
+        if specifications.pages.unwrap_or(1) > 2 {
+            route.push(RouteStep::new(machines, MachineKind::Folder, quantity as i64, gsm)?);
+        }
+
+        let mut lines = vec![CostLine::new(
+            CostCategory::Paper,
+            format!("{} press sheets", press_sheets),
+            &parameters.paper_cost_per_sheet * BigDecimal::from(press_sheets),
+        )
+        .explained(
+            CostExplanation::new("⌈quantity × leaves ÷ ups⌉ × paper cost per sheet")
+                .input("quantity", quantity, "copies")
+                .input("leaves", leaves, "leaves")
+                .input("ups", form.ups, "per sheet")
+                .input("paperCostPerSheet", &parameters.paper_cost_per_sheet, "per sheet")
+                .intermediate("pressSheets", press_sheets, "sheets"),
+        )];
+        // Every leaf is its own form
+        lines.extend(print_lines(process, &form, leaves, &specifications.colors, press_sheets, parameters));
+        lines.extend(route_lines(&route, None));
+
+        if has_binding(specifications) {
+            lines.push(CostLine::new(
+                CostCategory::Binding,
+                specifications.binding.clone().unwrap_or_default(),
+                &parameters.binding_cost_per_unit * BigDecimal::from(quantity),
+            )
+            .explained(
+                CostExplanation::new("quantity × binding cost per unit")
+                    .input("quantity", quantity, "copies")
+                    .input("bindingCostPerUnit", &parameters.binding_cost_per_unit, "per unit"),
+            ));
+        }
+
+        if let Some(lamination) = &specifications.lamination {
+            lines.push(lamination_line(
+                lamination,
+                &specifications.paper_type,
+                specifications.paper_weight_gsm(),
+                press_sheets as f64 * press_sheet_area_square_meters(parameters),
+                parameters,
+            )?);
+        }
+        lines.extend(finishing_lines(quantity, specifications.finishing.iter(), parameters)?);
+
+        Ok(CostBreakdown::from_lines(lines)
+            .with_production_hours(route_hours(&route))
+            .with_machine_hours(route_machine_hours(&route)))
+    }
+}
@@ -0,0 +1,257 @@
+//! Trim sizes and how many of them fit on a press sheet.
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Dimensions {
+    pub width_mm: f64,
+    pub height_mm: f64,
+}
+
+impl Dimensions {
+    pub fn new(width_mm: f64, height_mm: f64) -> Self {
+        Self { width_mm, height_mm }
+    }
+
+    pub fn area_square_meters(&self) -> f64 {
+        self.width_mm * self.height_mm / 1_000_000.0
+    }
+}
+
+/// Named sizes accepted in `JobSpecifications::paper_size`, portrait, in mm.
+const NAMED_SIZES: &[(&str, f64, f64)] = &[
+    ("A0", 841.0, 1189.0),
+    ("A1", 594.0, 841.0),
+    ("A2", 420.0, 594.0),
+    ("A3", 297.0, 420.0),
+    ("A4", 210.0, 297.0),
+    ("A5", 148.0, 210.0),
+    ("A6", 105.0, 148.0),
+    ("A7", 74.0, 105.0),
+    ("B1", 707.0, 1000.0),
+    ("B2", 500.0, 707.0),
+    ("B3", 353.0, 500.0),
+    ("B4", 250.0, 353.0),
+    ("B5", 176.0, 250.0),
+    ("SRA3", 320.0, 450.0),
+    ("SRA2", 450.0, 640.0),
+    ("DL", 99.0, 210.0),
+    ("LETTER", 215.9, 279.4),
+    ("LEGAL", 215.9, 355.6),
+    ("TABLOID", 279.4, 431.8),
+    ("BUSINESS_CARD", 85.0, 55.0),
+];
+
+/// Parses a named size ("A4", "Letter") or explicit dimensions such as
+/// "210x297", "210 x 297 mm" or "60x160cm".
+pub fn parse_paper_size(paper_size: &str) -> Option<Dimensions> {
+    let normalized = paper_size.trim().to_uppercase().replace(' ', "");
+
+    if let Some((_, width, height)) = NAMED_SIZES
+        .iter()
+        .find(|(name, _, _)| name.replace('_', "") == normalized.replace('_', ""))
+    {
+        return Some(Dimensions::new(*width, *height));
+    }
+
+    let (dimensions, factor) = if let Some(value) = normalized.strip_suffix("MM") {
+        (value, 1.0)
+    } else if let Some(value) = normalized.strip_suffix("CM") {
+        (value, 10.0)
+    } else {
+        (normalized.as_str(), 1.0)
+    };
+
+    let (width, height) = dimensions.split_once(['X', '×', '*'])?;
+    let width: f64 = width.replace(',', ".").parse().ok()?;
+    let height: f64 = height.replace(',', ".").parse().ok()?;
+
+    if width <= 0.0 || height <= 0.0 {
+        return None;
+    }
+
+    Some(Dimensions::new(width * factor, height * factor))
+}
+
+/// The named size `dimensions` matches in either orientation, allowing
+/// `tolerance_mm` on each side.
+pub fn named_size(dimensions: Dimensions, tolerance_mm: f64) -> Option<&'static str> {
+    let close = |a: f64, b: f64| (a - b).abs() <= tolerance_mm;
+    NAMED_SIZES
+        .iter()
+        .find(|(_, width, height)| {
+            (close(dimensions.width_mm, *width) && close(dimensions.height_mm, *height))
+                || (close(dimensions.width_mm, *height) && close(dimensions.height_mm, *width))
+        })
+        .map(|(name, _, _)| *name)
+}
+
+/// Grid of items on a sheet; `across` runs along the sheet's width.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SheetLayout {
+    pub across: i32,
+    pub down: i32,
+}
+
+impl SheetLayout {
+    pub fn count(&self) -> i32 {
+        self.across * self.down
+    }
+}
+
+/// Best grid of `item`s on `sheet`, trying both orientations. Empty when
+/// the item is larger than the sheet.
+pub fn sheet_layout(item: Dimensions, sheet: Dimensions, gutter_mm: f64) -> SheetLayout {
+    let fit = |length: f64, available: f64| -> i32 {
+        if length <= 0.0 || length > available {
+            return 0;
+        }
+        ((available + gutter_mm) / (length + gutter_mm)).floor() as i32
+    };
+
+    let upright = SheetLayout {
+        across: fit(item.width_mm, sheet.width_mm),
+        down: fit(item.height_mm, sheet.height_mm),
+    };
+    let rotated = SheetLayout {
+        across: fit(item.height_mm, sheet.width_mm),
+        down: fit(item.width_mm, sheet.height_mm),
+    };
+
+    if rotated.count() > upright.count() {
+        rotated
+    } else {
+        upright
+    }
+}
+
+/// How both sides of a sheet are printed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PrintMethod {
+    SingleSided,
+    /// Separate plates for each side.
+    Sheetwise,
+    /// Front and back share one plate set; the sheet is turned side to side.
+    WorkAndTurn,
+    /// Front and back share one plate set; the sheet is tumbled head to tail.
+    WorkAndTumble,
+}
+
+impl std::fmt::Display for PrintMethod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PrintMethod::SingleSided => write!(f, "single-sided"),
+            PrintMethod::Sheetwise => write!(f, "sheetwise"),
+            PrintMethod::WorkAndTurn => write!(f, "work-and-turn"),
+            PrintMethod::WorkAndTumble => write!(f, "work-and-tumble"),
+        }
+    }
+}
+
+/// Work-and-turn or work-and-tumble needs both sides in the same inks and an
+/// even number of items along the axis the sheet is flipped on; otherwise
+/// double-sided work runs sheetwise.
+pub fn print_method(layout: SheetLayout, double_sided: bool, same_inks_both_sides: bool) -> PrintMethod {
+    if !double_sided {
+        PrintMethod::SingleSided
+    } else if !same_inks_both_sides {
+        PrintMethod::Sheetwise
+    } else if layout.across >= 2 && layout.across % 2 == 0 {
+        PrintMethod::WorkAndTurn
+    } else if layout.down >= 2 && layout.down % 2 == 0 {
+        PrintMethod::WorkAndTumble
+    } else {
+        PrintMethod::Sheetwise
+    }
+}
+
+/// Items nested across a roll, in rows along its length.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RollLayout {
+    pub roll_width_mm: f64,
+    pub across: i32,
+    pub rows: i32,
+    pub length_mm: f64,
+    /// Whether items run with their width along the roll.
+    pub rotated: bool,
+}
+
+impl RollLayout {
+    pub fn media_square_meters(&self) -> f64 {
+        self.roll_width_mm * self.length_mm / 1_000_000.0
+    }
+}
+
+/// Lays `quantity` items across a roll of `roll_width_mm`, trying both
+/// orientations and keeping the one that uses the least roll length.
+/// Returns `None` when the item is wider than the printable width either way.
+pub fn nest_on_roll(
+    item: Dimensions,
+    quantity: i32,
+    roll_width_mm: f64,
+    margin_mm: f64,
+    gap_mm: f64,
+) -> Option<RollLayout> {
+    let printable = roll_width_mm - 2.0 * margin_mm;
+    let layout = |across_mm: f64, along_mm: f64, rotated: bool| -> Option<RollLayout> {
+        if across_mm <= 0.0 || across_mm > printable {
+            return None;
+        }
+        let across = (((printable + gap_mm) / (across_mm + gap_mm)).floor() as i32).max(1);
+        let rows = (quantity.max(1) + across - 1) / across;
+        Some(RollLayout {
+            roll_width_mm,
+            across,
+            rows,
+            length_mm: rows as f64 * (along_mm + gap_mm),
+            rotated,
+        })
+    };
+
+    let upright = layout(item.width_mm, item.height_mm, false);
+    let rotated = layout(item.height_mm, item.width_mm, true);
+
+    match (upright, rotated) {
+        (Some(a), Some(b)) => Some(if b.length_mm < a.length_mm { b } else { a }),
+        (a, b) => a.or(b),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rotates_items_to_fit_more_on_a_sheet() {
+        // A4 on SRA3 with a 3 mm gutter: 1 upright, 2 rotated
+        let layout = sheet_layout(Dimensions::new(210.0, 297.0), Dimensions::new(320.0, 450.0), 3.0);
+        assert_eq!(layout, SheetLayout { across: 1, down: 2 });
+    }
+
+    #[test]
+    fn nests_items_across_the_roll() {
+        // 1350 mm printable fits two 600 mm items; rotated, 1600 mm does not fit
+        let layout = nest_on_roll(Dimensions::new(600.0, 1600.0), 5, 1370.0, 10.0, 10.0).unwrap();
+        assert_eq!(layout.across, 2);
+        assert_eq!(layout.rows, 3);
+        assert!(!layout.rotated);
+        assert!((layout.length_mm - 3.0 * 1610.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn keeps_the_orientation_using_less_roll() {
+        // Upright: 1 across × 3 rows of 510 mm; rotated: 2 across × 2 rows of 1010 mm
+        let layout = nest_on_roll(Dimensions::new(1000.0, 500.0), 3, 1370.0, 10.0, 10.0).unwrap();
+        assert!(!layout.rotated);
+        assert!((layout.length_mm - 1530.0).abs() < 1e-9);
+
+        // Upright: 1 across × 4 rows of 510 mm; rotated: 2 across × 2 rows of 1010 mm
+        let layout = nest_on_roll(Dimensions::new(1000.0, 500.0), 4, 1100.0, 10.0, 10.0).unwrap();
+        assert_eq!(layout.across, 2);
+        assert!(layout.rotated);
+        assert!((layout.length_mm - 2020.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn rejects_items_wider_than_the_roll() {
+        assert!(nest_on_roll(Dimensions::new(1500.0, 1500.0), 1, 1370.0, 10.0, 10.0).is_none());
+    }
+}
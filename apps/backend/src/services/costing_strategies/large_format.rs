@@ -0,0 +1,352 @@
+use bigdecimal::BigDecimal;
+
+use super::{decimal, finishing_lines, imposition, lamination_line, CostingStrategy, Dimensions};
+use crate::{
+    models::{
+        CostBreakdown, CostCategory, CostExplanation, CostLine, CostParameters, JobSpecifications, Machine, MediaType, PrintProcess,
+    },
+    utils::errors::AppError,
+};
+
+/// Shortest labor charge for a large-format print run.
+const MIN_PRINT_HOURS: f64 = 0.25;
+/// Grommets sit at least on every corner.
+const MIN_GROMMETS: i64 = 4;
+
+/// Roll-fed banners and posters, priced by media used (including nesting
+/// waste), ink by printed area and coverage, and per-edge finishing.
+pub struct LargeFormatStrategy;
+
+/// Finished size, media and options resolved from either the large-format
+/// specification or the plain `paper_size`.
+struct LargeFormatJob<'p> {
+    finished: Dimensions,
+    /// Finished size plus hem and pole pocket allowances.
+    cut: Dimensions,
+    media: &'p MediaType,
+    coverage: f64,
+    grommet_spacing_mm: Option<f64>,
+    hemming: bool,
+    pole_pockets: i32,
+}
+
+impl<'p> LargeFormatJob<'p> {
+    fn new(specifications: &JobSpecifications, parameters: &'p CostParameters) -> Result<Self, AppError> {
+        let large_format = &parameters.large_format;
+        let spec = specifications.large_format.as_ref();
+
+        let finished = match spec {
+            Some(spec) => Dimensions::new(spec.width_cm * 10.0, spec.height_cm * 10.0),
+            None => imposition::parse_paper_size(&specifications.paper_size).ok_or_else(|| {
+                AppError::ValidationError(format!(
+                    "Unrecognised size for large-format work: {}",
+                    specifications.paper_size
+                ))
+            })?,
+        };
+        if finished.width_mm <= 0.0 || finished.height_mm <= 0.0 {
+            return Err(AppError::ValidationError("Width and height must be positive".to_string()));
+        }
+
+        let media = match spec {
+            Some(spec) => {
+                let wanted = normalize(&spec.media_type);
+                large_format
+                    .media
+                    .iter()
+                    .find(|media| normalize(&media.name) == wanted)
+                    .ok_or_else(|| {
+                        let known: Vec<&str> = large_format.media.iter().map(|media| media.name.as_str()).collect();
+                        AppError::ValidationError(format!(
+                            "Unknown media type: {} (expected one of {})",
+                            spec.media_type,
+                            known.join(", ")
+                        ))
+                    })?
+            }
+            None => large_format.media.first().ok_or_else(|| {
+                AppError::InternalServerError("No large-format media configured".to_string())
+            })?,
+        };
+
+        let coverage = spec.and_then(|spec| spec.ink_coverage).unwrap_or(100.0);
+        if !(0.0..=100.0).contains(&coverage) {
+            return Err(AppError::ValidationError("Ink coverage must be between 0 and 100%".to_string()));
+        }
+
+        let grommet_spacing_mm = spec.and_then(|spec| spec.grommet_spacing_cm).map(|cm| cm * 10.0);
+        if grommet_spacing_mm.is_some_and(|spacing| spacing <= 0.0) {
+            return Err(AppError::ValidationError("Grommet spacing must be positive".to_string()));
+        }
+
+        let hemming = spec.map(|spec| spec.hemming).unwrap_or(false);
+        let pole_pockets = spec
+            .and_then(|spec| spec.pole_pockets)
+            .map(|pockets| pockets.count())
+            .unwrap_or(0);
+
+        let hem = if hemming { 2.0 * large_format.hem_allowance_cm * 10.0 } else { 0.0 };
+        let pockets = pole_pockets as f64 * large_format.pole_pocket_allowance_cm * 10.0;
+        let cut = Dimensions::new(finished.width_mm + hem, finished.height_mm + hem + pockets);
+
+        Ok(LargeFormatJob {
+            finished,
+            cut,
+            media,
+            coverage,
+            grommet_spacing_mm,
+            hemming,
+            pole_pockets,
+        })
+    }
+
+    /// Cheapest stocked roll width for `quantity` items.
+    fn nest(&self, quantity: i32, parameters: &CostParameters) -> Result<imposition::RollLayout, AppError> {
+        let large_format = &parameters.large_format;
+        self.media
+            .roll_widths_cm
+            .iter()
+            .filter_map(|width_cm| {
+                imposition::nest_on_roll(
+                    self.cut,
+                    quantity,
+                    width_cm * 10.0,
+                    large_format.roll_margin_cm * 10.0,
+                    large_format.item_gap_cm * 10.0,
+                )
+            })
+            .min_by(|a, b| a.media_square_meters().total_cmp(&b.media_square_meters()))
+            .ok_or_else(|| {
+                AppError::ValidationError(format!(
+                    "{:.0} × {:.0} cm does not fit the widest {} roll",
+                    self.cut.width_mm / 10.0,
+                    self.cut.height_mm / 10.0,
+                    self.media.name
+                ))
+            })
+    }
+}
+
+fn normalize(name: &str) -> String {
+    name.trim().to_lowercase().replace(['-', ' '], "_")
+}
+
+impl CostingStrategy for LargeFormatStrategy {
+    fn validate(
+        &self,
+        quantity: i32,
+        specifications: &JobSpecifications,
+        parameters: &CostParameters,
+    ) -> Result<(), AppError> {
+        LargeFormatJob::new(specifications, parameters)?.nest(quantity, parameters).map(|_| ())
+    }
+
+    fn processes(&self, _specifications: &JobSpecifications, _parameters: &CostParameters) -> Vec<PrintProcess> {
+        vec![PrintProcess::WideFormat]
+    }
+
+    fn calculate(
+        &self,
+        quantity: i32,
+        specifications: &JobSpecifications,
+        parameters: &CostParameters,
+        _machines: &[Machine],
+        _process: PrintProcess,
+    ) -> Result<CostBreakdown, AppError> {
+        let large_format = &parameters.large_format;
+        let job = LargeFormatJob::new(specifications, parameters)?;
+        let layout = job.nest(quantity, parameters)?;
+
+        let printed_area = job.finished.area_square_meters() * quantity as f64;
+        let media_area = layout.media_square_meters();
+        let waste = if media_area > 0.0 { (1.0 - printed_area / media_area) * 100.0 } else { 0.0 };
+        let hours = (printed_area / large_format.print_speed_square_meters_per_hour.max(f64::EPSILON))
+            .max(MIN_PRINT_HOURS);
+
+        let mut lines = vec![
+            CostLine::new(
+                CostCategory::Paper,
+                format!(
+                    "Media: {:.2} m² {} on {:.0} cm roll, {} across, {:.0}% waste",
+                    media_area,
+                    job.media.name,
+                    layout.roll_width_mm / 10.0,
+                    layout.across,
+                    waste
+                ),
+                &job.media.cost_per_square_meter * decimal(media_area),
+            )
+            .explained(
+                CostExplanation::new("roll width × run length × media cost per m²")
+                    .input("quantity", quantity, "items")
+                    .input("rollWidth", layout.roll_width_mm / 10.0, "cm")
+                    .input("mediaCostPerSquareMeter", &job.media.cost_per_square_meter, "per m²")
+                    .intermediate("across", layout.across, "items")
+                    .intermediate("rows", layout.rows, "rows")
+                    .intermediate("runLength", layout.length_mm / 1000.0, "m")
+                    .intermediate("mediaArea", media_area, "m²")
+                    .intermediate("printedArea", printed_area, "m²")
+                    .intermediate("waste", waste, "%"),
+            ),
+            CostLine::new(
+                CostCategory::Ink,
+                format!("Ink: {:.2} m² at {:.0}% coverage", printed_area, job.coverage),
+                &large_format.ink_cost_per_square_meter * decimal(printed_area * job.coverage / 100.0),
+            )
+            .explained(
+                CostExplanation::new("printed area × coverage × ink cost per m²")
+                    .input("printedArea", printed_area, "m²")
+                    .input("coverage", job.coverage, "%")
+                    .input("inkCostPerSquareMeter", &large_format.ink_cost_per_square_meter, "per m²"),
+            ),
+            CostLine::new(CostCategory::Labor, "Setup", large_format.setup_cost.clone()).explained(
+                CostExplanation::new("setup cost").input("setupCost", &large_format.setup_cost, "per job"),
+            ),
+            CostLine::new(CostCategory::Labor, "Print time", &parameters.labor_cost_per_hour * decimal(hours)).explained(
+                CostExplanation::new("max(printed area ÷ print speed, minimum hours) × labor rate")
+                    .input("printedArea", printed_area, "m²")
+                    .input("printSpeed", large_format.print_speed_square_meters_per_hour, "m²/h")
+                    .input("minimumHours", MIN_PRINT_HOURS, "h")
+                    .input("laborCostPerHour", &parameters.labor_cost_per_hour, "per hour")
+                    .intermediate("hours", hours, "h"),
+            ),
+        ];
+
+        let perimeter_m = 2.0 * (job.finished.width_mm + job.finished.height_mm) / 1000.0;
+        if let Some(spacing_mm) = job.grommet_spacing_mm {
+            let per_item = ((perimeter_m * 1000.0 / spacing_mm).round() as i64).max(MIN_GROMMETS);
+            let grommets = per_item * quantity as i64;
+            lines.push(CostLine::new(
+                CostCategory::Finishing,
+                format!("{} grommets", grommets),
+                &large_format.grommet_cost_each * BigDecimal::from(grommets),
+            )
+            .explained(
+                CostExplanation::new("max(perimeter ÷ spacing, 4) × quantity × cost per grommet")
+                    .input("perimeter", perimeter_m, "m")
+                    .input("spacing", spacing_mm / 10.0, "cm")
+                    .input("quantity", quantity, "items")
+                    .input("grommetCost", &large_format.grommet_cost_each, "each")
+                    .intermediate("grommetsPerItem", per_item, "grommets"),
+            ));
+        }
+        if job.hemming {
+            lines.push(CostLine::new(
+                CostCategory::Finishing,
+                "Hemming",
+                &large_format.hemming_cost_per_meter * decimal(perimeter_m * quantity as f64),
+            )
+            .explained(
+                CostExplanation::new("perimeter × quantity × hemming cost per m")
+                    .input("perimeter", perimeter_m, "m")
+                    .input("quantity", quantity, "items")
+                    .input("hemmingCostPerMeter", &large_format.hemming_cost_per_meter, "per m"),
+            ));
+        }
+        if job.pole_pockets > 0 {
+            let pocket_m = job.finished.width_mm / 1000.0 * (job.pole_pockets * quantity) as f64;
+            lines.push(CostLine::new(
+                CostCategory::Finishing,
+                format!("{} pole pocket(s) per item", job.pole_pockets),
+                &large_format.pole_pocket_cost_per_meter * decimal(pocket_m),
+            )
+            .explained(
+                CostExplanation::new("width × pockets × quantity × pole pocket cost per m")
+                    .input("width", job.finished.width_mm / 1000.0, "m")
+                    .input("pockets", job.pole_pockets, "per item")
+                    .input("quantity", quantity, "items")
+                    .input("polePocketCostPerMeter", &large_format.pole_pocket_cost_per_meter, "per m")
+                    .intermediate("pocketLength", pocket_m, "m"),
+            ));
+        }
+        if let Some(lamination) = &specifications.lamination {
+            lines.push(lamination_line(lamination, &job.media.name, None, media_area, parameters)?);
+        }
+        lines.extend(finishing_lines(quantity, specifications.finishing.iter(), parameters)?);
+
+        Ok(CostBreakdown::from_lines(lines).with_production_hours(hours))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        models::{LargeFormatSpecifications, PolePockets},
+        services::costing_strategies::test_support::specifications,
+    };
+
+    fn banner(width_cm: f64, height_cm: f64, media_type: &str) -> LargeFormatSpecifications {
+        LargeFormatSpecifications {
+            width_cm,
+            height_cm,
+            media_type: media_type.to_string(),
+            ink_coverage: None,
+            grommet_spacing_cm: None,
+            hemming: false,
+            pole_pockets: None,
+        }
+    }
+
+    fn job(large_format: LargeFormatSpecifications) -> JobSpecifications {
+        JobSpecifications {
+            large_format: Some(large_format),
+            ..specifications("A0")
+        }
+    }
+
+    #[test]
+    fn nests_on_the_roll_that_uses_least_media() {
+        let parameters = CostParameters::default();
+        let specifications = job(banner(300.0, 100.0, "Frontlit vinyl"));
+        let layout = LargeFormatJob::new(&specifications, &parameters)
+            .unwrap()
+            .nest(1, &parameters)
+            .unwrap();
+
+        // Rotated on 137 or 160 cm rolls it needs 3 m of roll; upright on
+        // 320 cm it needs 1 m
+        assert_eq!(layout.roll_width_mm, 3200.0);
+        assert_eq!(layout.across, 1);
+        assert!(!layout.rotated);
+    }
+
+    #[test]
+    fn adds_hem_and_pole_pocket_allowances() {
+        let parameters = CostParameters::default();
+        let specifications = job(LargeFormatSpecifications {
+            hemming: true,
+            pole_pockets: Some(PolePockets::TopAndBottom),
+            ..banner(200.0, 100.0, "frontlit_vinyl")
+        });
+        let job = LargeFormatJob::new(&specifications, &parameters).unwrap();
+
+        assert_eq!(job.finished, Dimensions::new(2000.0, 1000.0));
+        assert_eq!(job.cut, Dimensions::new(2050.0, 1250.0));
+    }
+
+    #[test]
+    fn rejects_items_wider_than_every_roll() {
+        let parameters = CostParameters::default();
+        let specifications = job(banner(400.0, 400.0, "mesh_vinyl"));
+
+        assert!(LargeFormatStrategy.validate(1, &specifications, &parameters).is_err());
+    }
+
+    #[test]
+    fn charges_media_including_nesting_waste() {
+        let parameters = CostParameters::default();
+        let breakdown = LargeFormatStrategy
+            .calculate(
+                1,
+                &job(banner(300.0, 100.0, "frontlit_vinyl")),
+                &parameters,
+                &[],
+                PrintProcess::WideFormat,
+            )
+            .unwrap();
+
+        let media = breakdown.lines.iter().find(|line| line.category == CostCategory::Paper).unwrap();
+        assert_eq!(media.description, "Media: 3.23 m² frontlit_vinyl on 320 cm roll, 1 across, 7% waste");
+    }
+}
@@ -0,0 +1,419 @@
+//! Per-`JobType` costing strategies.
+//!
+//! Each strategy turns a specification into direct production costs.
+//! Overhead and margin are applied afterwards by `CostingService`, so every
+//! strategy leaves `CostBreakdown::overhead` at zero.
+
+use bigdecimal::BigDecimal;
+use std::str::FromStr;
+
+use crate::{
+    models::{
+        ColorSpecification, CostBreakdown, CostCategory, CostExplanation, CostLine, CostParameters, FinishingOperation,
+        JobSpecifications, JobType, LaminationFilm, LaminationSides, LaminationSpecification, Machine,
+        PrintProcess, RateBasis,
+    },
+    utils::errors::AppError,
+};
+
+pub mod book;
+pub mod business_card;
+pub mod generic;
+pub mod imposition;
+pub mod large_format;
+pub mod routing;
+pub mod sticker;
+
+pub use imposition::{Dimensions, PrintMethod, SheetLayout};
+
+pub use book::BookStrategy;
+pub use business_card::BusinessCardStrategy;
+pub use generic::GenericStrategy;
+pub use large_format::LargeFormatStrategy;
+pub use sticker::StickerStrategy;
+
+pub trait CostingStrategy: Send + Sync {
+    /// Rejects specifications the strategy cannot produce. Runs before
+    /// `calculate`.
+    fn validate(
+        &self,
+        _quantity: i32,
+        _specifications: &JobSpecifications,
+        _parameters: &CostParameters,
+    ) -> Result<(), AppError> {
+        Ok(())
+    }
+
+    /// Processes that can produce the specification, compared by
+    /// `CostingService`.
+    fn processes(&self, specifications: &JobSpecifications, parameters: &CostParameters) -> Vec<PrintProcess> {
+        sheet_fed_processes(&[(&specifications.colors, specifications.paper_weight_gsm())], parameters)
+    }
+
+    /// Direct production costs for `quantity` copies of `specifications`
+    /// printed on `process`.
+    fn calculate(
+        &self,
+        quantity: i32,
+        specifications: &JobSpecifications,
+        parameters: &CostParameters,
+        machines: &[Machine],
+        process: PrintProcess,
+    ) -> Result<CostBreakdown, AppError>;
+}
+
+pub fn strategy_for(job_type: &JobType) -> Box<dyn CostingStrategy> {
+    match job_type {
+        JobType::Book => Box::new(BookStrategy),
+        JobType::Banner | JobType::Poster => Box::new(LargeFormatStrategy),
+        JobType::Sticker => Box::new(StickerStrategy),
+        JobType::BusinessCard => Box::new(BusinessCardStrategy),
+        JobType::Flyer | JobType::Brochure | JobType::Custom => Box::new(GenericStrategy),
+    }
+}
+
+pub(crate) fn decimal(value: f64) -> BigDecimal {
+    BigDecimal::from_str(&value.to_string()).unwrap_or_default()
+}
+
+pub(crate) fn ceil_div(numerator: i64, denominator: i64) -> i64 {
+    let denominator = denominator.max(1);
+    (numerator + denominator - 1) / denominator
+}
+
+/// Offset always; digital too when every run (colors and stock weight) is
+/// within the digital press's limits. Spot colors need offset.
+pub(crate) fn sheet_fed_processes(
+    runs: &[(&ColorSpecification, Option<i32>)],
+    parameters: &CostParameters,
+) -> Vec<PrintProcess> {
+    let digital_eligible = runs.iter().all(|(colors, gsm)| {
+        colors.spot_colors.is_empty() && gsm.is_none_or(|gsm| gsm <= parameters.digital.max_gsm)
+    });
+
+    if digital_eligible {
+        vec![PrintProcess::Offset, PrintProcess::Digital]
+    } else {
+        vec![PrintProcess::Offset]
+    }
+}
+
+/// Plates for one sheet layout and how its two sides are printed.
+pub(crate) struct PressForm {
+    pub method: PrintMethod,
+    pub plates: i32,
+    pub ups: i64,
+}
+
+/// Plates needed for `colors` with `trim` imposed on the press sheet. Work-
+/// and-turn and work-and-tumble share one plate set between both sides.
+pub(crate) fn press_form(
+    colors: &ColorSpecification,
+    trim: Option<Dimensions>,
+    parameters: &CostParameters,
+) -> PressForm {
+    let press_sheet = Dimensions::new(parameters.press_sheet_width_mm, parameters.press_sheet_height_mm);
+    let layout = trim
+        .map(|trim| imposition::sheet_layout(trim, press_sheet, parameters.gutter_mm))
+        .filter(|layout| layout.count() > 0)
+        .unwrap_or(SheetLayout { across: 1, down: 1 });
+
+    let (front, back) = (colors.front_inks(), colors.back_inks());
+    let method = imposition::print_method(layout, back > 0, front == back && colors.spot_colors.is_empty());
+    let plates = match method {
+        PrintMethod::SingleSided | PrintMethod::WorkAndTurn | PrintMethod::WorkAndTumble => front,
+        PrintMethod::Sheetwise => front + back,
+    };
+
+    PressForm {
+        method,
+        plates,
+        ups: layout.count() as i64,
+    }
+}
+
+/// Plates for `forms` distinct sheet layouts printed the same way.
+pub(crate) fn plate_line(form: &PressForm, forms: i64, parameters: &CostParameters) -> CostLine {
+    let plates = form.plates as i64 * forms.max(1);
+    CostLine::new(
+        CostCategory::Plates,
+        format!("{} plates, {}", plates, form.method),
+        &parameters.plate_cost * BigDecimal::from(plates),
+    )
+    .explained(
+        CostExplanation::new("plates per form × forms × plate cost")
+            .input("platesPerForm", form.plates, "plates")
+            .input("forms", forms.max(1), "forms")
+            .input("plateCost", &parameters.plate_cost, "per plate")
+            .intermediate("plates", plates, "plates"),
+    )
+}
+
+/// Process and spot ink for `press_sheets`, from coverage per color.
+pub(crate) fn ink_lines(colors: &ColorSpecification, press_sheets: i64, parameters: &CostParameters) -> Vec<CostLine> {
+    let ink = &parameters.ink;
+    let sheet_area = press_sheets as f64 * press_sheet_area_square_meters(parameters);
+    let ink_line = |description: String, color_count: i32, coverage: Option<f64>, cost_per_kg: &BigDecimal| {
+        let coverage = coverage.unwrap_or(ink.default_coverage_percent).clamp(0.0, 100.0);
+        let kg = sheet_area * color_count as f64 * coverage / 100.0 * ink.grams_per_square_meter / 1000.0;
+        CostLine::new(CostCategory::Ink, format!("{}, {:.2} kg", description, kg), cost_per_kg * decimal(kg)).explained(
+            CostExplanation::new("sheet area × colors × coverage × g/m² ÷ 1000 × ink cost per kg")
+                .input("pressSheets", press_sheets, "sheets")
+                .input("pressSheetArea", press_sheet_area_square_meters(parameters), "m²")
+                .input("colors", color_count, "colors")
+                .input("coverage", coverage, "%")
+                .input("gramsPerSquareMeter", ink.grams_per_square_meter, "g/m²")
+                .input("inkCostPerKg", cost_per_kg, "per kg")
+                .intermediate("sheetArea", sheet_area, "m²")
+                .intermediate("ink", kg, "kg"),
+        )
+    };
+
+    let mut lines = Vec::new();
+
+    let process_colors = colors.front_process_colors() + colors.back_process_colors();
+    if process_colors > 0 {
+        lines.push(ink_line(
+            format!("Process ink, {} colors", process_colors),
+            process_colors,
+            colors.process_coverage,
+            &ink.process_ink_cost_per_kg,
+        ));
+    }
+
+    if !colors.spot_colors.is_empty() {
+        lines.push(ink_line(
+            format!("Spot ink ({})", colors.spot_colors.join(", ")),
+            colors.spot_colors.len() as i32,
+            colors.spot_coverage,
+            &ink.spot_ink_cost_per_kg,
+        ));
+    }
+
+    lines
+}
+
+/// Plates and ink on offset, or click charges on digital, for
+/// `press_sheets` printed from `forms` layouts.
+pub(crate) fn print_lines(
+    process: PrintProcess,
+    form: &PressForm,
+    forms: i64,
+    colors: &ColorSpecification,
+    press_sheets: i64,
+    parameters: &CostParameters,
+) -> Vec<CostLine> {
+    match process {
+        PrintProcess::Digital => vec![click_line(colors, press_sheets, parameters)],
+        _ => {
+            let mut lines = vec![plate_line(form, forms, parameters)];
+            lines.extend(ink_lines(colors, press_sheets, parameters));
+            lines
+        }
+    }
+}
+
+/// Digital click charges: one click per printed side, color for more than
+/// one ink and mono for a single ink.
+pub(crate) fn click_line(colors: &ColorSpecification, press_sheets: i64, parameters: &CostParameters) -> CostLine {
+    let digital = &parameters.digital;
+    let (mut color_sides, mut mono_sides) = (0, 0);
+    for inks in [colors.front_inks(), colors.back_inks()] {
+        match inks {
+            0 => {}
+            1 => mono_sides += 1,
+            _ => color_sides += 1,
+        }
+    }
+
+    let color_clicks = press_sheets * color_sides;
+    let mono_clicks = press_sheets * mono_sides;
+    CostLine::new(
+        CostCategory::Ink,
+        format!("Digital clicks, {} color + {} mono", color_clicks, mono_clicks),
+        &digital.color_click_cost * BigDecimal::from(color_clicks) + &digital.mono_click_cost * BigDecimal::from(mono_clicks),
+    )
+    .explained(
+        CostExplanation::new("sheets × color sides × color click + sheets × mono sides × mono click")
+            .input("pressSheets", press_sheets, "sheets")
+            .input("colorSides", color_sides, "sides")
+            .input("monoSides", mono_sides, "sides")
+            .input("colorClickCost", &digital.color_click_cost, "per click")
+            .input("monoClickCost", &digital.mono_click_cost, "per click")
+            .intermediate("colorClicks", color_clicks, "clicks")
+            .intermediate("monoClicks", mono_clicks, "clicks"),
+    )
+}
+
+pub(crate) fn has_binding(specifications: &JobSpecifications) -> bool {
+    specifications
+        .binding
+        .as_ref()
+        .map(|binding| !binding.trim().is_empty())
+        .unwrap_or(false)
+}
+
+pub(crate) fn press_sheet_area_square_meters(parameters: &CostParameters) -> f64 {
+    Dimensions::new(parameters.press_sheet_width_mm, parameters.press_sheet_height_mm).area_square_meters()
+}
+
+/// Coated (gloss, silk, matt, art) stock, as opposed to uncoated bond or offset.
+pub(crate) fn is_coated_stock(paper_type: &str) -> bool {
+    let paper_type = paper_type.to_lowercase();
+    if paper_type.contains("uncoated") {
+        return false;
+    }
+    ["coated", "gloss", "silk", "satin", "matt", "art"]
+        .iter()
+        .any(|finish| paper_type.contains(finish))
+}
+
+/// One line per finishing operation, each priced from the catalog in
+/// `CostParameters::finishing_operations`.
+pub(crate) fn finishing_lines<'s>(
+    quantity: i32,
+    operations: impl Iterator<Item = &'s String>,
+    parameters: &CostParameters,
+) -> Result<Vec<CostLine>, AppError> {
+    operations
+        .map(|id| Ok(finishing_operation_line(finishing_operation(id, parameters)?, quantity, parameters)))
+        .collect()
+}
+
+/// Looks up a catalog entry by id, rejecting unknown operations.
+pub(crate) fn finishing_operation<'p>(
+    id: &str,
+    parameters: &'p CostParameters,
+) -> Result<&'p FinishingOperation, AppError> {
+    let id = id.trim().to_lowercase();
+    parameters
+        .finishing_operations
+        .iter()
+        .find(|operation| operation.id == id)
+        .ok_or_else(|| AppError::ValidationError(format!("Unknown finishing operation: {}", id)))
+}
+
+/// Setup plus the run rate, raised to the minimum charge, plus machine time
+/// at the labor rate.
+pub(crate) fn finishing_operation_line(
+    operation: &FinishingOperation,
+    quantity: i32,
+    parameters: &CostParameters,
+) -> CostLine {
+    let (units, per) = match operation.rate_basis {
+        RateBasis::PerUnit => (BigDecimal::from(quantity), "per unit"),
+        RateBasis::PerThousand => (BigDecimal::from(quantity) / BigDecimal::from(1000), "per thousand"),
+    };
+    let run_charge = &operation.setup_cost + &operation.rate * &units;
+    let charge = if run_charge < operation.minimum_charge {
+        operation.minimum_charge.clone()
+    } else {
+        run_charge.clone()
+    };
+
+    let hours = operation.setup_minutes / 60.0 + quantity as f64 / operation.units_per_hour.max(1.0);
+    let labor = &parameters.labor_cost_per_hour * decimal(hours);
+
+    CostLine::new(CostCategory::Finishing, operation.name.clone(), &charge + &labor).explained(
+        CostExplanation::new(
+            "max(setup + rate × units, minimum charge) + (setup minutes ÷ 60 + quantity ÷ units per hour) × labor rate",
+        )
+        .input("quantity", quantity, "units")
+        .input("setupCost", &operation.setup_cost, "per job")
+        .input("rate", &operation.rate, per)
+        .input("minimumCharge", &operation.minimum_charge, "per job")
+        .input("setupMinutes", operation.setup_minutes, "min")
+        .input("unitsPerHour", operation.units_per_hour, "units/h")
+        .input("laborCostPerHour", &parameters.labor_cost_per_hour, "per hour")
+        .intermediate("runCharge", run_charge, "total")
+        .intermediate("charge", charge, "total")
+        .intermediate("hours", hours, "h")
+        .intermediate("labor", labor, "total"),
+    )
+}
+
+/// Rejects film on stock that will not take it.
+pub(crate) fn check_lamination(
+    paper_type: &str,
+    paper_weight_gsm: Option<i32>,
+    parameters: &CostParameters,
+) -> Result<(), AppError> {
+    let min_gsm = parameters.lamination.min_uncoated_gsm;
+    match paper_weight_gsm {
+        Some(gsm) if gsm < min_gsm && !is_coated_stock(paper_type) => Err(AppError::ValidationError(format!(
+            "Uncoated stock under {}gsm cannot be laminated ({} is {}gsm)",
+            min_gsm, paper_type, gsm
+        ))),
+        _ => Ok(()),
+    }
+}
+
+/// Film for `area_square_meters` of sheet per side, plus setup.
+pub(crate) fn lamination_line(
+    lamination: &LaminationSpecification,
+    paper_type: &str,
+    paper_weight_gsm: Option<i32>,
+    area_square_meters: f64,
+    parameters: &CostParameters,
+) -> Result<CostLine, AppError> {
+    check_lamination(paper_type, paper_weight_gsm, parameters)?;
+
+    let rates = &parameters.lamination;
+    let cost_per_square_meter = match lamination.film {
+        LaminationFilm::Gloss => &rates.gloss_cost_per_square_meter,
+        LaminationFilm::Matte => &rates.matte_cost_per_square_meter,
+        LaminationFilm::SoftTouch => &rates.soft_touch_cost_per_square_meter,
+        LaminationFilm::AntiScuff => &rates.anti_scuff_cost_per_square_meter,
+    };
+    let film_area = area_square_meters * lamination.sides.count() as f64;
+
+    Ok(CostLine::new(
+        CostCategory::Finishing,
+        format!(
+            "Lamination: {} {}, {:.2} m²",
+            lamination.film,
+            if lamination.sides == LaminationSides::Double { "both sides" } else { "one side" },
+            film_area
+        ),
+        &rates.setup_cost + cost_per_square_meter * decimal(film_area),
+    )
+    .explained(
+        CostExplanation::new("setup + sheet area × sides × film cost per m²")
+            .input("sheetArea", area_square_meters, "m²")
+            .input("sides", lamination.sides.count(), "sides")
+            .input("filmCostPerSquareMeter", cost_per_square_meter, "per m²")
+            .input("setupCost", &rates.setup_cost, "per job")
+            .intermediate("filmArea", film_area, "m²"),
+    ))
+}
+
+/// Specifications shared by the strategy tests.
+#[cfg(test)]
+pub(crate) mod test_support {
+    use crate::models::{ColorSpecification, JobSpecifications};
+
+    /// Full color on both sides of 130gsm gloss, with no binding or finishing.
+    pub fn specifications(paper_size: &str) -> JobSpecifications {
+        JobSpecifications {
+            paper_type: "gloss".to_string(),
+            paper_size: paper_size.to_string(),
+            paper_weight: Some("130gsm".to_string()),
+            colors: ColorSpecification {
+                front_colors: 4,
+                back_colors: 4,
+                spot_colors: Vec::new(),
+                is_full_color: true,
+                process_coverage: None,
+                spot_coverage: None,
+            },
+            pages: None,
+            binding: None,
+            lamination: None,
+            finishing: Vec::new(),
+            special_requirements: None,
+            book: None,
+            large_format: None,
+            process: None,
+        }
+    }
+}
@@ -0,0 +1,139 @@
+//! Routing a job through the shop's machines to cost make-ready and run time.
+
+use bigdecimal::BigDecimal;
+
+use super::decimal;
+use crate::{
+    models::{CostCategory, CostExplanation, CostLine, Machine, MachineHours, MachineKind, PrintProcess},
+    utils::errors::AppError,
+};
+
+/// Stock weight assumed when a job does not state one.
+const DEFAULT_STOCK_GSM: i32 = 115;
+
+/// One machine's share of a job: make-ready plus running `units` through it.
+pub(crate) struct RouteStep<'m> {
+    pub machine: &'m Machine,
+    pub units: i64,
+    pub sheets_per_hour: f64,
+    pub make_ready_hours: f64,
+    pub run_hours: f64,
+}
+
+impl<'m> RouteStep<'m> {
+    /// Routes `units` (sheets, or finished items for binders) through the
+    /// first active machine of `kind`, at the speed for `gsm` stock.
+    pub fn new(
+        machines: &'m [Machine],
+        kind: MachineKind,
+        units: i64,
+        gsm: Option<i32>,
+    ) -> Result<Self, AppError> {
+        let machine = machines
+            .iter()
+            .find(|machine| machine.kind == kind && machine.is_active)
+            .ok_or_else(|| AppError::ValidationError(format!("No active {} machine is configured", kind)))?;
+
+        let units = units.max(0);
+        let sheets_per_hour = machine.sheets_per_hour(gsm.unwrap_or(DEFAULT_STOCK_GSM));
+        Ok(RouteStep {
+            machine,
+            units,
+            sheets_per_hour,
+            make_ready_hours: machine.make_ready_minutes / 60.0,
+            run_hours: units as f64 / sheets_per_hour,
+        })
+    }
+
+    /// Charges only a share of make-ready, for work ganged with other jobs.
+    pub fn with_make_ready_share(mut self, jobs_sharing: i32) -> Self {
+        self.make_ready_hours /= jobs_sharing.max(1) as f64;
+        self
+    }
+
+    pub fn hours(&self) -> f64 {
+        self.make_ready_hours + self.run_hours
+    }
+
+    /// Machine time and operator time, as separate lines.
+    pub fn lines(&self) -> Vec<CostLine> {
+        let hours = decimal(self.hours());
+        let timing = format!(
+            "{:.0} min make-ready + {:.2} h run",
+            self.make_ready_hours * 60.0,
+            self.run_hours
+        );
+
+        vec![
+            CostLine::new(
+                CostCategory::Labor,
+                format!("{} machine time, {}", self.machine.name, timing),
+                &self.machine.machine_rate_per_hour * &hours,
+            )
+            .explained(self.explanation("machineRatePerHour", &self.machine.machine_rate_per_hour)),
+            CostLine::new(
+                CostCategory::Labor,
+                format!("{} operator, {:.2} h", self.machine.name, self.hours()),
+                &self.machine.operator_rate_per_hour * hours,
+            )
+            .explained(self.explanation("operatorRatePerHour", &self.machine.operator_rate_per_hour)),
+        ]
+    }
+
+    fn explanation(&self, rate_name: &str, rate: &BigDecimal) -> CostExplanation {
+        CostExplanation::new("(make-ready + units ÷ speed) × hourly rate")
+            .input("makeReadyHours", self.make_ready_hours, "h")
+            .input("units", self.units, "units")
+            .input("speed", self.sheets_per_hour, "units/h")
+            .input(rate_name, rate, "per hour")
+            .intermediate("runHours", self.run_hours, "h")
+            .intermediate("hours", self.hours(), "h")
+    }
+}
+
+/// The press run for sheet-fed `process`.
+pub(crate) fn press_step<'m>(
+    machines: &'m [Machine],
+    process: PrintProcess,
+    press_sheets: i64,
+    gsm: Option<i32>,
+) -> Result<RouteStep<'m>, AppError> {
+    let kind = process
+        .press()
+        .ok_or_else(|| AppError::ValidationError(format!("{} is not a sheet-fed process", process)))?;
+    RouteStep::new(machines, kind, press_sheets, gsm)
+}
+
+/// Total machine hours across a route.
+pub(crate) fn route_hours<'a, 'm: 'a>(steps: impl IntoIterator<Item = &'a RouteStep<'m>>) -> f64 {
+    steps.into_iter().map(RouteStep::hours).sum()
+}
+
+/// Hours per machine across a route, in the order the route first reaches
+/// each machine.
+pub(crate) fn route_machine_hours<'a, 'm: 'a>(steps: impl IntoIterator<Item = &'a RouteStep<'m>>) -> Vec<MachineHours> {
+    let mut machine_hours: Vec<MachineHours> = Vec::new();
+    for step in steps {
+        match machine_hours.iter_mut().find(|entry| entry.machine_id == step.machine.id) {
+            Some(entry) => entry.hours += step.hours(),
+            None => machine_hours.push(MachineHours {
+                machine_id: step.machine.id,
+                machine_name: step.machine.name.clone(),
+                hours: step.hours(),
+            }),
+        }
+    }
+    machine_hours
+}
+
+/// Lines for every step of a route, tagged with `section` when given.
+pub(crate) fn route_lines(steps: &[RouteStep<'_>], section: Option<&str>) -> Vec<CostLine> {
+    steps
+        .iter()
+        .flat_map(|step| step.lines())
+        .map(|line| match section {
+            Some(section) => line.in_section(section),
+            None => line,
+        })
+        .collect()
+}
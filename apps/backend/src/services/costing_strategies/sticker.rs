@@ -0,0 +1,196 @@
+use bigdecimal::BigDecimal;
+
+use super::{
+    ceil_div, finishing_lines, imposition, lamination_line, press_form, press_sheet_area_square_meters,
+    print_lines,
+    routing::{press_step, route_hours, route_lines, route_machine_hours},
+    CostingStrategy,
+};
+use crate::{
+    models::{
+        CostBreakdown, CostCategory, CostExplanation, CostLine, CostParameters, JobSpecifications, LaminationSides, Machine,
+        PrintProcess,
+    },
+    utils::errors::AppError,
+};
+
+/// Stickers printed on label stock and cut to shape. Kiss-cutting (through
+/// the face stock only) is used when requested in `finishing`, otherwise
+/// the stickers are die-cut through the backing.
+pub struct StickerStrategy;
+
+impl CostingStrategy for StickerStrategy {
+    fn validate(
+        &self,
+        _quantity: i32,
+        specifications: &JobSpecifications,
+        _parameters: &CostParameters,
+    ) -> Result<(), AppError> {
+        match &specifications.lamination {
+            // The backing is peeled off, so only the face takes film
+            Some(lamination) if lamination.sides == LaminationSides::Double => Err(AppError::ValidationError(
+                "Stickers can only be laminated on one side".to_string(),
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    fn calculate(
+        &self,
+        quantity: i32,
+        specifications: &JobSpecifications,
+        parameters: &CostParameters,
+        machines: &[Machine],
+        process: PrintProcess,
+    ) -> Result<CostBreakdown, AppError> {
+        let sticker = &parameters.sticker;
+        let form = press_form(&specifications.colors, imposition::parse_paper_size(&specifications.paper_size), parameters);
+        let press_sheets = ceil_div(quantity as i64, form.ups);
+
+        let (cut, setup_cost, cost_per_thousand) = if is_kiss_cut(specifications) {
+            ("Kiss-cutting", &sticker.kiss_cut_setup_cost, &sticker.kiss_cut_cost_per_thousand)
+        } else {
+            ("Die-cutting", &sticker.die_cut_setup_cost, &sticker.die_cut_cost_per_thousand)
+        };
+        let thousands = BigDecimal::from(quantity) / BigDecimal::from(1000);
+        let cutting_cost = setup_cost + cost_per_thousand * &thousands;
+        let cutting = CostExplanation::new("setup + quantity ÷ 1000 × cost per thousand")
+            .input("quantity", quantity, "stickers")
+            .input("setupCost", setup_cost, "per job")
+            .input("costPerThousand", cost_per_thousand, "per thousand")
+            .intermediate("thousands", thousands, "thousands");
+
+        let other_finishing = specifications
+            .finishing
+            .iter()
+            .filter(|operation| !is_cut_operation(operation));
+
+        let mut lines = vec![
+            CostLine::new(
+                CostCategory::Paper,
+                format!("{} label stock sheets", press_sheets),
+                &sticker.label_stock_cost_per_sheet * BigDecimal::from(press_sheets),
+            )
+            .explained(
+                CostExplanation::new("⌈quantity ÷ ups⌉ × label stock cost per sheet")
+                    .input("quantity", quantity, "stickers")
+                    .input("ups", form.ups, "per sheet")
+                    .input("labelStockCostPerSheet", &sticker.label_stock_cost_per_sheet, "per sheet")
+                    .intermediate("pressSheets", press_sheets, "sheets"),
+            ),
+            CostLine::new(CostCategory::Finishing, cut, cutting_cost).explained(cutting),
+        ];
+        let route = [press_step(machines, process, press_sheets, specifications.paper_weight_gsm())?];
+        lines.extend(print_lines(process, &form, 1, &specifications.colors, press_sheets, parameters));
+        lines.extend(route_lines(&route, None));
+        if let Some(lamination) = &specifications.lamination {
+            lines.push(lamination_line(
+                lamination,
+                &specifications.paper_type,
+                specifications.paper_weight_gsm(),
+                press_sheets as f64 * press_sheet_area_square_meters(parameters),
+                parameters,
+            )?);
+        }
+        lines.extend(finishing_lines(quantity, other_finishing, parameters)?);
+
+        Ok(CostBreakdown::from_lines(lines)
+            .with_production_hours(route_hours(&route))
+            .with_machine_hours(route_machine_hours(&route)))
+    }
+}
+
+fn normalize(operation: &str) -> String {
+    operation.trim().to_lowercase().replace(['-', ' '], "_")
+}
+
+fn is_cut_operation(operation: &str) -> bool {
+    matches!(normalize(operation).as_str(), "kiss_cut" | "die_cut")
+}
+
+fn is_kiss_cut(specifications: &JobSpecifications) -> bool {
+    specifications
+        .finishing
+        .iter()
+        .any(|operation| normalize(operation) == "kiss_cut")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        models::{default_machines, LaminationFilm, LaminationSpecification},
+        services::costing_strategies::test_support::specifications,
+    };
+    use std::str::FromStr;
+
+    fn stickers(finishing: &[&str]) -> JobSpecifications {
+        let mut specifications = specifications("50x50");
+        specifications.colors.back_colors = 0;
+        specifications.finishing = finishing.iter().map(|operation| operation.to_string()).collect();
+        specifications
+    }
+
+    fn cut_line(breakdown: &CostBreakdown) -> &CostLine {
+        breakdown
+            .lines
+            .iter()
+            .find(|line| line.description.ends_with("-cutting"))
+            .unwrap()
+    }
+
+    #[test]
+    fn die_cuts_by_default() {
+        let parameters = CostParameters::default();
+        let breakdown = StickerStrategy
+            .calculate(1000, &stickers(&[]), &parameters, &default_machines(), PrintProcess::Offset)
+            .unwrap();
+
+        // 40.00 setup + 1 thousand × 12.00
+        let cut = cut_line(&breakdown);
+        assert_eq!(cut.description, "Die-cutting");
+        assert_eq!(cut.amount, BigDecimal::from(52));
+    }
+
+    #[test]
+    fn kiss_cuts_when_requested() {
+        let parameters = CostParameters::default();
+        let breakdown = StickerStrategy
+            .calculate(2000, &stickers(&["Kiss cut"]), &parameters, &default_machines(), PrintProcess::Offset)
+            .unwrap();
+
+        // 25.00 setup + 2 thousand × 8.00, and no separate finishing line
+        let cut = cut_line(&breakdown);
+        assert_eq!(cut.description, "Kiss-cutting");
+        assert_eq!(cut.amount, BigDecimal::from(41));
+        assert_eq!(
+            breakdown.lines.iter().filter(|line| line.category == CostCategory::Finishing).count(),
+            1
+        );
+    }
+
+    #[test]
+    fn buys_label_stock_by_the_sheet() {
+        let parameters = CostParameters::default();
+        let breakdown = StickerStrategy
+            .calculate(1000, &stickers(&[]), &parameters, &default_machines(), PrintProcess::Offset)
+            .unwrap();
+
+        // 50 mm stickers go 6 × 8 on SRA3
+        let stock = breakdown.lines.iter().find(|line| line.category == CostCategory::Paper).unwrap();
+        assert_eq!(stock.description, "21 label stock sheets");
+        assert_eq!(stock.amount, BigDecimal::from_str("6.30").unwrap());
+    }
+
+    #[test]
+    fn laminates_the_face_only() {
+        let parameters = CostParameters::default();
+        let mut specifications = stickers(&[]);
+        specifications.lamination = Some(LaminationSpecification {
+            film: LaminationFilm::Gloss,
+            sides: LaminationSides::Double,
+        });
+
+        assert!(StickerStrategy.validate(1000, &specifications, &parameters).is_err());
+    }
+}
@@ -1,9 +1,22 @@
 use std::collections::HashMap;
 use reqwest;
-use serde_json::Value;
-use crate::models::currency::{Currency, ExchangeRates, CurrencyConversionRequest, CurrencyConversionResponse};
+use crate::config::Config;
+use crate::models::currency::{Currency, CurrencySettings, ExchangeRates, CurrencyConversionRequest, CurrencyConversionResponse};
 use crate::utils::errors::AppError;
 
+/// Sample exchange rates against USD (approximate). Only these currencies
+/// can be priced in.
+const USD_RATES: &[(&str, f64)] = &[
+    ("USD", 1.0),
+    ("XAF", 620.0),  // 1 USD = 620 FCFA
+    ("XOF", 620.0),  // Pegged to EUR at the same parity as XAF
+    ("EUR", 0.85),
+    ("GBP", 0.73),
+    ("CAD", 1.35),
+    ("NGN", 1550.0),
+    ("GHS", 15.5),
+];
+
 pub struct CurrencyService {
     client: reqwest::Client,
     // Using a free exchange rate API
@@ -19,40 +32,53 @@ impl CurrencyService {
         }
     }
 
-    pub async fn get_exchange_rates(&self) -> Result<ExchangeRates, AppError> {
+    /// Returns rates quoted against `base`, i.e. how many units of each
+    /// currency one unit of `base` buys.
+    pub async fn get_exchange_rates(&self, base: Currency) -> Result<ExchangeRates, AppError> {
         // For demo purposes, return hardcoded rates
         // In production, you would fetch from a real API
-        let mut rates = HashMap::new();
-        
-        // Sample exchange rates (USD base)
-        rates.insert("USD".to_string(), 1.0);
-        rates.insert("XAF".to_string(), 620.0); // 1 USD = 620 FCFA (approximate)
-        rates.insert("EUR".to_string(), 0.85);  // 1 USD = 0.85 EUR (approximate)
-        rates.insert("GBP".to_string(), 0.73);  // 1 USD = 0.73 GBP (approximate)
-        rates.insert("CAD".to_string(), 1.35);  // 1 USD = 1.35 CAD (approximate)
+        let usd_rates: HashMap<String, f64> = USD_RATES
+            .iter()
+            .map(|(code, rate)| (code.to_string(), *rate))
+            .collect();
+
+        let base_rate = usd_rates.get(base.code()).copied().ok_or_else(|| {
+            AppError::BadRequest(format!("No exchange rate available for {}", base.code()))
+        })?;
+
+        let rates = usd_rates
+            .into_iter()
+            .map(|(code, rate)| (code, rate / base_rate))
+            .collect();
 
         Ok(ExchangeRates {
-            base: Currency::USD,
+            base,
             rates,
             last_updated: chrono::Utc::now(),
         })
     }
 
+    /// Whether there is an exchange rate for `currency`, so quotes can be
+    /// priced in it.
+    pub fn has_rate(currency: Currency) -> bool {
+        USD_RATES.iter().any(|(code, _)| *code == currency.code())
+    }
+
+    /// Units of `to` that one unit of `from` buys.
+    pub async fn get_rate(&self, from: Currency, to: Currency) -> Result<f64, AppError> {
+        if from == to {
+            return Ok(1.0);
+        }
+
+        let rates = self.get_exchange_rates(from).await?;
+        rates.rates.get(to.code()).copied().ok_or_else(|| {
+            AppError::BadRequest(format!("No exchange rate available for {}", to.code()))
+        })
+    }
+
     pub async fn convert_currency(&self, request: CurrencyConversionRequest) -> Result<CurrencyConversionResponse, AppError> {
-        let rates = self.get_exchange_rates().await?;
-        
-        let from_rate = rates.rates.get(request.from_currency.code())
-            .copied()
-            .unwrap_or(1.0);
-        
-        let to_rate = rates.rates.get(request.to_currency.code())
-            .copied()
-            .unwrap_or(1.0);
-        
-        // Convert from source currency to USD, then to target currency
-        let usd_amount = request.amount / from_rate;
-        let converted_amount = usd_amount * to_rate;
-        let exchange_rate = to_rate / from_rate;
+        let exchange_rate = self.get_rate(request.from_currency, request.to_currency).await?;
+        let converted_amount = request.amount * exchange_rate;
 
         Ok(CurrencyConversionResponse {
             original_amount: request.amount,
@@ -63,29 +89,19 @@ impl CurrencyService {
         })
     }
 
-    pub fn get_supported_currencies() -> Vec<Currency> {
-        vec![
-            Currency::USD,
-            Currency::FCFA,
-            Currency::EUR,
-            Currency::GBP,
-            Currency::CAD,
-        ]
-    }
-
-    // Helper method to convert a single amount
-    pub async fn convert_amount(&self, amount: f64, from: Currency, to: Currency) -> Result<f64, AppError> {
-        if from.code() == to.code() {
-            return Ok(amount);
+    /// Currency settings derived from the environment configuration.
+    pub fn default_settings(config: &Config) -> CurrencySettings {
+        // Currencies without an exchange rate cannot be quoted in
+        let mut supported_currencies = config.supported_currencies.clone();
+        supported_currencies.retain(|currency| Self::has_rate(*currency));
+        if !supported_currencies.contains(&config.base_currency) {
+            supported_currencies.insert(0, config.base_currency);
         }
 
-        let request = CurrencyConversionRequest {
-            amount,
-            from_currency: from,
-            to_currency: to,
-        };
-
-        let response = self.convert_currency(request).await?;
-        Ok(response.converted_amount)
+        CurrencySettings {
+            base_currency: config.base_currency,
+            default_currency: config.base_currency,
+            supported_currencies,
+        }
     }
 }
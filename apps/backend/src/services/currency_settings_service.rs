@@ -0,0 +1,197 @@
+use chrono::Utc;
+use sea_orm::{ActiveModelTrait, EntityTrait, Set};
+use uuid::Uuid;
+
+use crate::{
+    config::Config,
+    entities::currency_settings,
+    models::{Currency, CurrencySettings, UpdateCurrencySettingsRequest},
+    services::{currency_service::CurrencyService, customer_service::CustomerService, user_service::UserService},
+    utils::errors::AppError,
+};
+
+pub struct CurrencySettingsService<'a> {
+    db: &'a sea_orm::DatabaseConnection,
+    config: &'a Config,
+}
+
+impl<'a> CurrencySettingsService<'a> {
+    pub fn new(db: &'a sea_orm::DatabaseConnection, config: &'a Config) -> Self {
+        Self { db, config }
+    }
+
+    /// Stored company settings, or the environment defaults when nothing
+    /// has been saved yet.
+    pub async fn get_settings(&self) -> Result<CurrencySettings, AppError> {
+        let stored = currency_settings::Entity::find().one(self.db).await?;
+
+        Ok(match stored {
+            Some(model) => to_settings(&model, CurrencyService::default_settings(self.config)),
+            None => CurrencyService::default_settings(self.config),
+        })
+    }
+
+    pub async fn update_settings(
+        &self,
+        request: UpdateCurrencySettingsRequest,
+    ) -> Result<CurrencySettings, AppError> {
+        let mut settings = self.get_settings().await?;
+
+        if let Some(base_currency) = request.base_currency {
+            settings.base_currency = base_currency;
+        }
+
+        if let Some(default_currency) = request.default_currency {
+            settings.default_currency = default_currency;
+        }
+
+        if let Some(supported_currencies) = request.supported_currencies {
+            settings.supported_currencies = supported_currencies;
+        }
+
+        validate_settings(&settings)?;
+
+        let supported = settings
+            .supported_currencies
+            .iter()
+            .map(|currency| currency.code())
+            .collect::<Vec<_>>()
+            .join(",");
+
+        match currency_settings::Entity::find().one(self.db).await? {
+            Some(model) => {
+                let mut active: currency_settings::ActiveModel = model.into();
+                active.base_currency = Set(settings.base_currency.code().to_string());
+                active.default_currency = Set(settings.default_currency.code().to_string());
+                active.supported_currencies = Set(supported);
+                active.updated_at = Set(Utc::now().into());
+                active.update(self.db).await?;
+            }
+            None => {
+                currency_settings::ActiveModel {
+                    id: Set(Uuid::new_v4()),
+                    base_currency: Set(settings.base_currency.code().to_string()),
+                    default_currency: Set(settings.default_currency.code().to_string()),
+                    supported_currencies: Set(supported),
+                    created_at: Set(Utc::now().into()),
+                    updated_at: Set(Utc::now().into()),
+                }
+                .insert(self.db)
+                .await?;
+            }
+        }
+
+        Ok(settings)
+    }
+
+    /// Picks the display currency for a quote: the explicitly requested
+    /// currency, then the customer's preference, then the user's, then the
+    /// company default. Preferences for currencies that are no longer
+    /// enabled are skipped.
+    pub async fn resolve_display_currency(
+        &self,
+        requested: Option<Currency>,
+        customer_id: Option<Uuid>,
+        user_id: Option<Uuid>,
+    ) -> Result<Currency, AppError> {
+        let settings = self.get_settings().await?;
+
+        if let Some(currency) = requested {
+            if !settings.supported_currencies.contains(&currency) {
+                return Err(AppError::BadRequest(format!(
+                    "Currency {} is not enabled",
+                    currency.code()
+                )));
+            }
+            return Ok(currency);
+        }
+
+        if let Some(customer_id) = customer_id {
+            let customer = CustomerService::new(self.db).find_by_id(customer_id).await?;
+            if let Some(currency) = customer.and_then(|c| c.preferred_currency) {
+                if settings.supported_currencies.contains(&currency) {
+                    return Ok(currency);
+                }
+            }
+        }
+
+        if let Some(user_id) = user_id {
+            let preferred = UserService::new(self.db).get_preferred_currency(user_id).await?;
+            if let Some(currency) = preferred {
+                if settings.supported_currencies.contains(&currency) {
+                    return Ok(currency);
+                }
+            }
+        }
+
+        Ok(settings.default_currency)
+    }
+
+    /// Rejects `currency` unless it is currently enabled.
+    pub async fn ensure_supported(&self, currency: Currency) -> Result<(), AppError> {
+        let settings = self.get_settings().await?;
+        if !settings.supported_currencies.contains(&currency) {
+            return Err(AppError::BadRequest(format!(
+                "Currency {} is not enabled",
+                currency.code()
+            )));
+        }
+        Ok(())
+    }
+}
+
+fn to_settings(model: &currency_settings::Model, defaults: CurrencySettings) -> CurrencySettings {
+    let supported_currencies: Vec<Currency> = model
+        .supported_currencies
+        .split(',')
+        .filter_map(Currency::from_code)
+        .filter(|currency| CurrencyService::has_rate(*currency))
+        .collect();
+
+    CurrencySettings {
+        base_currency: Currency::from_code(&model.base_currency)
+            .filter(|currency| CurrencyService::has_rate(*currency))
+            .unwrap_or(defaults.base_currency),
+        default_currency: Currency::from_code(&model.default_currency).unwrap_or(defaults.default_currency),
+        supported_currencies: if supported_currencies.is_empty() {
+            defaults.supported_currencies
+        } else {
+            supported_currencies
+        },
+    }
+}
+
+fn validate_settings(settings: &CurrencySettings) -> Result<(), AppError> {
+    if settings.supported_currencies.is_empty() {
+        return Err(AppError::ValidationError(
+            "At least one currency must be enabled".to_string(),
+        ));
+    }
+
+    if let Some(currency) = settings
+        .supported_currencies
+        .iter()
+        .find(|currency| !CurrencyService::has_rate(**currency))
+    {
+        return Err(AppError::ValidationError(format!(
+            "Currency {} has no exchange rate source and cannot be enabled",
+            currency.code()
+        )));
+    }
+
+    if !settings.supported_currencies.contains(&settings.base_currency) {
+        return Err(AppError::ValidationError(format!(
+            "Base currency {} must be enabled",
+            settings.base_currency.code()
+        )));
+    }
+
+    if !settings.supported_currencies.contains(&settings.default_currency) {
+        return Err(AppError::ValidationError(format!(
+            "Default currency {} must be enabled",
+            settings.default_currency.code()
+        )));
+    }
+
+    Ok(())
+}
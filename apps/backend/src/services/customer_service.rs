@@ -0,0 +1,151 @@
+use bigdecimal::BigDecimal;
+use chrono::Utc;
+use sea_orm::{prelude::Decimal, ActiveModelTrait, EntityTrait, QueryOrder, Set};
+use std::str::FromStr;
+use uuid::Uuid;
+
+use crate::{
+    entities::customers,
+    models::{CreateCustomerRequest, Currency, Customer, Discount, DiscountKind, UpdateCustomerRequest},
+    utils::errors::AppError,
+};
+
+pub struct CustomerService<'a> {
+    db: &'a sea_orm::DatabaseConnection,
+}
+
+impl<'a> CustomerService<'a> {
+    pub fn new(db: &'a sea_orm::DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    pub async fn create_customer(&self, request: CreateCustomerRequest) -> Result<Customer, AppError> {
+        let model = customers::ActiveModel {
+            id: Set(Uuid::new_v4()),
+            name: Set(request.name),
+            email: Set(request.email),
+            phone: Set(request.phone),
+            preferred_currency: Set(request.preferred_currency.map(|c| c.code().to_string())),
+            profit_rate: Set(request.profit_rate.as_ref().map(to_decimal)),
+            discount_kind: Set(request.discount.as_ref().map(|discount| discount_kind_code(discount.kind).to_string())),
+            discount_value: Set(request.discount.as_ref().map(|discount| to_decimal(&discount.value))),
+            country: Set(request.country.map(|country| country.to_uppercase())),
+            tax_id: Set(request.tax_id),
+            tax_exempt: Set(request.tax_exempt),
+            created_at: Set(Utc::now().into()),
+            updated_at: Set(Utc::now().into()),
+        }
+        .insert(self.db)
+        .await?;
+
+        Ok(to_customer(model))
+    }
+
+    pub async fn find_by_id(&self, customer_id: Uuid) -> Result<Option<Customer>, AppError> {
+        let model = customers::Entity::find_by_id(customer_id).one(self.db).await?;
+        Ok(model.map(to_customer))
+    }
+
+    pub async fn list_customers(&self) -> Result<Vec<Customer>, AppError> {
+        let models = customers::Entity::find()
+            .order_by_asc(customers::Column::Name)
+            .all(self.db)
+            .await?;
+        Ok(models.into_iter().map(to_customer).collect())
+    }
+
+    pub async fn update_customer(
+        &self,
+        customer_id: Uuid,
+        request: UpdateCustomerRequest,
+    ) -> Result<Customer, AppError> {
+        let model = customers::Entity::find_by_id(customer_id)
+            .one(self.db)
+            .await?
+            .ok_or(AppError::NotFound("Customer not found".to_string()))?;
+
+        let mut active: customers::ActiveModel = model.into();
+
+        if let Some(name) = request.name {
+            active.name = Set(name);
+        }
+
+        if let Some(email) = request.email {
+            active.email = Set(Some(email));
+        }
+
+        if let Some(phone) = request.phone {
+            active.phone = Set(Some(phone));
+        }
+
+        if let Some(preferred_currency) = request.preferred_currency {
+            active.preferred_currency = Set(Some(preferred_currency.code().to_string()));
+        }
+
+        if let Some(profit_rate) = request.profit_rate {
+            active.profit_rate = Set(Some(to_decimal(&profit_rate)));
+        }
+
+        if let Some(discount) = request.discount {
+            active.discount_kind = Set(Some(discount_kind_code(discount.kind).to_string()));
+            active.discount_value = Set(Some(to_decimal(&discount.value)));
+        }
+
+        if let Some(country) = request.country {
+            active.country = Set(Some(country.to_uppercase()));
+        }
+
+        if let Some(tax_id) = request.tax_id {
+            active.tax_id = Set(Some(tax_id));
+        }
+
+        if let Some(tax_exempt) = request.tax_exempt {
+            active.tax_exempt = Set(tax_exempt);
+        }
+
+        active.updated_at = Set(Utc::now().into());
+
+        let model = active.update(self.db).await?;
+        Ok(to_customer(model))
+    }
+}
+
+fn to_customer(model: customers::Model) -> Customer {
+    Customer {
+        id: model.id,
+        name: model.name,
+        email: model.email,
+        phone: model.phone,
+        preferred_currency: model.preferred_currency.as_deref().and_then(Currency::from_code),
+        profit_rate: model
+            .profit_rate
+            .and_then(|rate| BigDecimal::from_str(&rate.to_string()).ok()),
+        discount: to_discount(model.discount_kind.as_deref(), model.discount_value),
+        country: model.country,
+        tax_id: model.tax_id,
+        tax_exempt: model.tax_exempt,
+        created_at: model.created_at.into(),
+        updated_at: model.updated_at.into(),
+    }
+}
+
+fn to_decimal(value: &BigDecimal) -> Decimal {
+    Decimal::from_str(&value.to_string()).unwrap_or_default()
+}
+
+fn discount_kind_code(kind: DiscountKind) -> &'static str {
+    match kind {
+        DiscountKind::Percentage => "percentage",
+        DiscountKind::Fixed => "fixed",
+    }
+}
+
+fn to_discount(kind: Option<&str>, value: Option<Decimal>) -> Option<Discount> {
+    let kind = match kind? {
+        "percentage" => DiscountKind::Percentage,
+        "fixed" => DiscountKind::Fixed,
+        _ => return None,
+    };
+    let value = BigDecimal::from_str(&value?.to_string()).ok()?;
+    Some(Discount { kind, value })
+}
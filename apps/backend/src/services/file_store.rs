@@ -0,0 +1,135 @@
+use async_trait::async_trait;
+use axum::body::Bytes;
+use object_store::{aws::AmazonS3Builder, path::Path as ObjectPath, ObjectStore};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use crate::{config::Config, utils::errors::AppError};
+
+/// Blob storage for uploaded files, addressed by `/`-separated keys such as
+/// `jobs/<job id>/<attachment id>`.
+#[async_trait]
+pub trait FileStore: Send + Sync {
+    async fn put(&self, key: &str, bytes: Bytes) -> Result<(), AppError>;
+
+    async fn get(&self, key: &str) -> Result<Bytes, AppError>;
+
+    /// Removes the file; succeeds if it is already gone.
+    async fn delete(&self, key: &str) -> Result<(), AppError>;
+}
+
+/// The store selected by `FILE_STORAGE`.
+pub fn from_config(config: &Config) -> Result<Arc<dyn FileStore>, AppError> {
+    match config.file_storage.as_str() {
+        "local" => Ok(Arc::new(LocalFileStore::new(&config.file_storage_path))),
+        "s3" => {
+            let bucket = config
+                .s3_bucket
+                .as_deref()
+                .ok_or(AppError::InternalServerError("S3_BUCKET is required for S3 storage".to_string()))?;
+            Ok(Arc::new(S3FileStore::new(bucket, config.s3_endpoint.as_deref(), &config.s3_region)?))
+        }
+        other => Err(AppError::InternalServerError(format!("Unknown file storage: {}", other))),
+    }
+}
+
+/// Files under a directory on the server's disk.
+pub struct LocalFileStore {
+    root: PathBuf,
+}
+
+impl LocalFileStore {
+    pub fn new(root: impl AsRef<Path>) -> Self {
+        Self {
+            root: root.as_ref().to_path_buf(),
+        }
+    }
+
+    fn path(&self, key: &str) -> Result<PathBuf, AppError> {
+        // Keys are generated, but never let one climb out of the root
+        if key.split('/').any(|part| part.is_empty() || part == "." || part == "..") {
+            return Err(AppError::BadRequest(format!("Invalid file key: {}", key)));
+        }
+        Ok(self.root.join(key))
+    }
+}
+
+#[async_trait]
+impl FileStore for LocalFileStore {
+    async fn put(&self, key: &str, bytes: Bytes) -> Result<(), AppError> {
+        let path = self.path(key)?;
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent).await.map_err(storage_error)?;
+        }
+        tokio::fs::write(&path, &bytes).await.map_err(storage_error)
+    }
+
+    async fn get(&self, key: &str) -> Result<Bytes, AppError> {
+        match tokio::fs::read(self.path(key)?).await {
+            Ok(bytes) => Ok(Bytes::from(bytes)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Err(AppError::NotFound("File not found".to_string())),
+            Err(e) => Err(storage_error(e)),
+        }
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), AppError> {
+        match tokio::fs::remove_file(self.path(key)?).await {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(storage_error(e)),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Objects in an S3 bucket, or one on an S3-compatible service. Credentials
+/// come from the usual `AWS_*` environment variables.
+pub struct S3FileStore {
+    store: object_store::aws::AmazonS3,
+}
+
+impl S3FileStore {
+    pub fn new(bucket: &str, endpoint: Option<&str>, region: &str) -> Result<Self, AppError> {
+        let mut builder = AmazonS3Builder::from_env()
+            .with_bucket_name(bucket)
+            .with_region(region);
+        if let Some(endpoint) = endpoint {
+            // S3-compatible services are usually addressed by path, not subdomain
+            builder = builder
+                .with_endpoint(endpoint)
+                .with_virtual_hosted_style_request(false)
+                .with_allow_http(endpoint.starts_with("http://"));
+        }
+        let store = builder.build().map_err(storage_error)?;
+        Ok(Self { store })
+    }
+}
+
+#[async_trait]
+impl FileStore for S3FileStore {
+    async fn put(&self, key: &str, bytes: Bytes) -> Result<(), AppError> {
+        self.store
+            .put(&ObjectPath::from(key), bytes.into())
+            .await
+            .map(|_| ())
+            .map_err(storage_error)
+    }
+
+    async fn get(&self, key: &str) -> Result<Bytes, AppError> {
+        let object = match self.store.get(&ObjectPath::from(key)).await {
+            Ok(object) => object,
+            Err(object_store::Error::NotFound { .. }) => return Err(AppError::NotFound("File not found".to_string())),
+            Err(e) => return Err(storage_error(e)),
+        };
+        object.bytes().await.map_err(storage_error)
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), AppError> {
+        match self.store.delete(&ObjectPath::from(key)).await {
+            Err(e) if !matches!(e, object_store::Error::NotFound { .. }) => Err(storage_error(e)),
+            _ => Ok(()),
+        }
+    }
+}
+
+fn storage_error(error: impl std::fmt::Display) -> AppError {
+    AppError::InternalServerError(format!("File storage error: {}", error))
+}
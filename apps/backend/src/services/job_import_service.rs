@@ -0,0 +1,296 @@
+use chrono::NaiveDate;
+use std::collections::HashMap;
+use std::io::Cursor;
+use std::str::FromStr;
+use uuid::Uuid;
+
+use calamine::{Data, Reader, Xlsx};
+
+use crate::{
+    models::{
+        ColorSpecification, CreateJobRequest, ImportFormat, ImportRowError, JobSpecifications, JobType,
+        LaminationFilm, LaminationSides, LaminationSpecification, PrintProcess, QuoteTerms,
+    },
+    utils::errors::AppError,
+};
+
+/// Columns the importer understands, matched case-insensitively against the
+/// header row. See `docs/JOB_IMPORT.md` for what each one holds.
+pub const IMPORT_COLUMNS: &[&str] = &[
+    "title",
+    "job_type",
+    "quantity",
+    "paper_type",
+    "paper_size",
+    "paper_weight",
+    "front_colors",
+    "back_colors",
+    "full_color",
+    "spot_colors",
+    "pages",
+    "binding",
+    "lamination",
+    "lamination_sides",
+    "finishing",
+    "process",
+    "special_requirements",
+    "customer_id",
+    "promo_code",
+    "requested_delivery_date",
+];
+
+const REQUIRED_COLUMNS: &[&str] = &["title", "job_type", "quantity", "paper_type", "paper_size"];
+
+/// Most process or back colors a press can lay down in one pass.
+const MAX_COLORS: i32 = 8;
+
+/// One data row keyed by column name.
+pub struct ImportRow {
+    /// Row number in the spreadsheet, counting the header as row 1.
+    pub row: usize,
+    pub values: HashMap<String, String>,
+}
+
+/// Reads the first sheet of an upload into rows. Rejects files whose header
+/// misses a required column or names one the importer does not know, so a
+/// typo cannot silently drop a value.
+pub fn read_rows(bytes: &[u8], format: ImportFormat) -> Result<Vec<ImportRow>, AppError> {
+    let table = match format {
+        ImportFormat::Csv => read_csv(bytes)?,
+        ImportFormat::Xlsx => read_xlsx(bytes)?,
+    };
+
+    let mut lines = table.into_iter();
+    let header: Vec<String> = lines
+        .next()
+        .ok_or(AppError::BadRequest("The file is empty".to_string()))?
+        .iter()
+        .map(|name| name.trim().to_lowercase())
+        .collect();
+
+    let unknown: Vec<&str> = header
+        .iter()
+        .filter(|name| !name.is_empty() && !IMPORT_COLUMNS.contains(&name.as_str()))
+        .map(String::as_str)
+        .collect();
+    if !unknown.is_empty() {
+        return Err(AppError::BadRequest(format!("Unknown columns: {}", unknown.join(", "))));
+    }
+    let missing: Vec<&str> = REQUIRED_COLUMNS
+        .iter()
+        .copied()
+        .filter(|column| !header.iter().any(|name| name == column))
+        .collect();
+    if !missing.is_empty() {
+        return Err(AppError::BadRequest(format!("Missing columns: {}", missing.join(", "))));
+    }
+
+    Ok(lines
+        .enumerate()
+        .filter(|(_, cells)| cells.iter().any(|cell| !cell.trim().is_empty()))
+        .map(|(index, cells)| ImportRow {
+            row: index + 2,
+            values: header
+                .iter()
+                .zip(cells)
+                .filter(|(name, _)| !name.is_empty())
+                .map(|(name, cell)| (name.clone(), cell.trim().to_string()))
+                .collect(),
+        })
+        .collect())
+}
+
+/// Turns a row into a job request, collecting every problem in the row
+/// rather than stopping at the first.
+pub fn parse_row(row: &ImportRow) -> Result<CreateJobRequest, Vec<ImportRowError>> {
+    let mut parser = RowParser { row, errors: Vec::new() };
+
+    let title = parser.required("title");
+    let job_type = parser.required_with("job_type", JobType::from_str);
+    let quantity = parser.required_with("quantity", |value| match value.parse::<i32>() {
+        Ok(quantity) if quantity > 0 => Ok(quantity),
+        _ => Err("Quantity must be a whole number greater than zero".to_string()),
+    });
+    let paper_type = parser.required("paper_type");
+    let paper_size = parser.required("paper_size");
+
+    let colors = parser.optional_with("front_colors", parse_colors).unwrap_or(4);
+    let back_colors = parser.optional_with("back_colors", parse_colors).unwrap_or(0);
+    let is_full_color = parser.optional_with("full_color", parse_flag).unwrap_or(colors >= 4);
+    let pages = parser.optional_with("pages", |value| match value.parse::<i32>() {
+        Ok(pages) if pages > 0 => Ok(pages),
+        _ => Err("Pages must be a whole number greater than zero".to_string()),
+    });
+
+    let film = parser.optional_with("lamination", |value| parse_snake_case::<LaminationFilm>(value, "lamination film"));
+    let sides = parser.optional_with("lamination_sides", |value| parse_snake_case::<LaminationSides>(value, "lamination sides"));
+    if film.is_none() && sides.is_some() {
+        parser.error("lamination_sides", "Lamination sides given without a lamination film");
+    }
+
+    let process = parser.optional_with("process", PrintProcess::from_str);
+    let customer_id = parser.optional_with("customer_id", |value| {
+        Uuid::parse_str(value).map_err(|_| format!("Invalid customer id: {}", value))
+    });
+    let requested_delivery_date = parser.optional_with("requested_delivery_date", |value| {
+        NaiveDate::parse_from_str(value, "%Y-%m-%d").map_err(|_| format!("Invalid date, expected YYYY-MM-DD: {}", value))
+    });
+
+    if !parser.errors.is_empty() {
+        return Err(parser.errors);
+    }
+
+    Ok(CreateJobRequest {
+        title: title.unwrap_or_default(),
+        job_type: job_type.unwrap_or(JobType::Custom),
+        quantity: quantity.unwrap_or_default(),
+        specifications: JobSpecifications {
+            paper_type: paper_type.unwrap_or_default(),
+            paper_size: paper_size.unwrap_or_default(),
+            paper_weight: parser.text("paper_weight"),
+            colors: ColorSpecification {
+                front_colors: colors,
+                back_colors,
+                spot_colors: parser.list("spot_colors"),
+                is_full_color,
+                process_coverage: None,
+                spot_coverage: None,
+            },
+            pages,
+            binding: parser.text("binding"),
+            lamination: film.map(|film| LaminationSpecification {
+                film,
+                sides: sides.unwrap_or_default(),
+            }),
+            finishing: parser.list("finishing"),
+            special_requirements: parser.text("special_requirements"),
+            book: None,
+            large_format: None,
+            process,
+        },
+        customer_id,
+        terms: QuoteTerms {
+            promo_code: parser.text("promo_code"),
+            requested_delivery_date,
+        },
+    })
+}
+
+struct RowParser<'a> {
+    row: &'a ImportRow,
+    errors: Vec<ImportRowError>,
+}
+
+impl RowParser<'_> {
+    fn text(&self, column: &str) -> Option<String> {
+        self.row
+            .values
+            .get(column)
+            .filter(|value| !value.is_empty())
+            .cloned()
+    }
+
+    /// `;`-separated values, e.g. `cutting;folding_half`.
+    fn list(&self, column: &str) -> Vec<String> {
+        self.text(column)
+            .map(|value| {
+                value
+                    .split(';')
+                    .map(str::trim)
+                    .filter(|item| !item.is_empty())
+                    .map(str::to_string)
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    fn required(&mut self, column: &str) -> Option<String> {
+        self.required_with(column, |value| Ok::<_, String>(value.to_string()))
+    }
+
+    fn required_with<T>(&mut self, column: &str, parse: impl Fn(&str) -> Result<T, String>) -> Option<T> {
+        if self.text(column).is_none() {
+            self.error(column, "A value is required");
+            return None;
+        }
+        self.optional_with(column, parse)
+    }
+
+    fn optional_with<T>(&mut self, column: &str, parse: impl Fn(&str) -> Result<T, String>) -> Option<T> {
+        let value = self.text(column)?;
+        match parse(&value) {
+            Ok(parsed) => Some(parsed),
+            Err(message) => {
+                self.error(column, message);
+                None
+            }
+        }
+    }
+
+    fn error(&mut self, column: &str, message: impl Into<String>) {
+        self.errors.push(ImportRowError {
+            row: self.row.row,
+            column: Some(column.to_string()),
+            message: message.into(),
+        });
+    }
+}
+
+fn parse_colors(value: &str) -> Result<i32, String> {
+    match value.parse::<i32>() {
+        Ok(colors) if (0..=MAX_COLORS).contains(&colors) => Ok(colors),
+        _ => Err(format!("Colors must be a whole number from 0 to {}", MAX_COLORS)),
+    }
+}
+
+fn parse_flag(value: &str) -> Result<bool, String> {
+    match value.to_lowercase().as_str() {
+        "true" | "yes" | "y" | "1" => Ok(true),
+        "false" | "no" | "n" | "0" => Ok(false),
+        _ => Err(format!("Expected yes or no: {}", value)),
+    }
+}
+
+/// Parses the snake_case form an enum is serialized as.
+fn parse_snake_case<T: serde::de::DeserializeOwned>(value: &str, what: &str) -> Result<T, String> {
+    serde_json::from_value(serde_json::Value::String(value.to_lowercase()))
+        .map_err(|_| format!("Invalid {}: {}", what, value))
+}
+
+fn read_csv(bytes: &[u8]) -> Result<Vec<Vec<String>>, AppError> {
+    csv::ReaderBuilder::new()
+        .has_headers(false)
+        .flexible(true)
+        .from_reader(bytes)
+        .records()
+        .map(|record| {
+            record
+                .map(|record| record.iter().map(str::to_string).collect())
+                .map_err(|e| AppError::BadRequest(format!("Invalid CSV: {}", e)))
+        })
+        .collect()
+}
+
+fn read_xlsx(bytes: &[u8]) -> Result<Vec<Vec<String>>, AppError> {
+    let mut workbook: Xlsx<_> = Xlsx::new(Cursor::new(bytes))
+        .map_err(|e| AppError::BadRequest(format!("Invalid XLSX: {}", e)))?;
+    let sheet = workbook
+        .worksheet_range_at(0)
+        .ok_or(AppError::BadRequest("The workbook has no sheets".to_string()))?
+        .map_err(|e| AppError::BadRequest(format!("Invalid XLSX: {}", e)))?;
+
+    Ok(sheet.rows().map(|cells| cells.iter().map(cell_text).collect()).collect())
+}
+
+fn cell_text(cell: &Data) -> String {
+    match cell {
+        Data::Empty => String::new(),
+        // Spreadsheets store every number as a float
+        Data::Float(value) if value.fract() == 0.0 => format!("{}", *value as i64),
+        Data::DateTime(value) => value
+            .as_datetime()
+            .map(|datetime| datetime.date().to_string())
+            .unwrap_or_default(),
+        other => other.to_string(),
+    }
+}
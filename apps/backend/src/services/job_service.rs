@@ -34,6 +34,16 @@ impl<'a> JobService<'a> {
             cost_breakdown: cost_calculation.cost_breakdown,
             total_cost: cost_calculation.total_cost,
             unit_cost: cost_calculation.unit_cost,
+            pricing: Some(cost_calculation.pricing),
+            tax: Some(cost_calculation.tax),
+            customer_id: request.customer_id,
+            terms: request.terms,
+            revision: 1,
+            reorder_of: None,
+            price_held_until: None,
+            promised_date: None,
+            margin_approved_by: None,
+            print_process: Some(cost_calculation.process),
             status: JobStatus::Draft,
             created_at: chrono::Utc::now(),
             updated_at: chrono::Utc::now(),
@@ -0,0 +1,186 @@
+use chrono::Utc;
+use sea_orm::{ActiveModelTrait, ColumnTrait, Condition, EntityTrait, QueryFilter, QueryOrder, Set};
+use uuid::Uuid;
+
+use crate::{
+    entities::job_templates,
+    models::{
+        CreateJobTemplateRequest, JobSpecifications, JobTemplate, JobType, TemplateScope, UpdateJobTemplateRequest,
+    },
+    services::costing_service::CostingService,
+    utils::errors::AppError,
+};
+
+pub struct JobTemplateService<'a> {
+    db: &'a sea_orm::DatabaseConnection,
+}
+
+impl<'a> JobTemplateService<'a> {
+    pub fn new(db: &'a sea_orm::DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// Shared templates and the user's own, by name.
+    pub async fn list_templates(&self, user_id: Uuid) -> Result<Vec<JobTemplate>, AppError> {
+        let models = job_templates::Entity::find()
+            .filter(visible_to(user_id))
+            .order_by_asc(job_templates::Column::Name)
+            .all(self.db)
+            .await?;
+        models.into_iter().map(to_template).collect()
+    }
+
+    pub async fn find_by_id(&self, template_id: Uuid, user_id: Uuid) -> Result<Option<JobTemplate>, AppError> {
+        job_templates::Entity::find_by_id(template_id)
+            .filter(visible_to(user_id))
+            .one(self.db)
+            .await?
+            .map(to_template)
+            .transpose()
+    }
+
+    /// Saves a template owned by `user_id`. Only managers may share one.
+    pub async fn create_template(
+        &self,
+        user_id: Uuid,
+        manager: bool,
+        request: CreateJobTemplateRequest,
+    ) -> Result<JobTemplate, AppError> {
+        ensure_can_publish(request.scope, manager)?;
+        self.validate(&request.job_type, request.quantity, &request.specifications).await?;
+
+        let model = job_templates::ActiveModel {
+            id: Set(Uuid::new_v4()),
+            name: Set(request.name),
+            description: Set(request.description),
+            job_type: Set(request.job_type.to_string()),
+            quantity: Set(request.quantity),
+            specifications: Set(specifications_to_json(&request.specifications)?),
+            scope: Set(request.scope.to_string()),
+            owner_id: Set(user_id),
+            created_at: Set(Utc::now().into()),
+            updated_at: Set(Utc::now().into()),
+        }
+        .insert(self.db)
+        .await?;
+
+        to_template(model)
+    }
+
+    pub async fn update_template(
+        &self,
+        template_id: Uuid,
+        user_id: Uuid,
+        manager: bool,
+        request: UpdateJobTemplateRequest,
+    ) -> Result<JobTemplate, AppError> {
+        let template = self
+            .find_by_id(template_id, user_id)
+            .await?
+            .ok_or(AppError::NotFound("Template not found".to_string()))?;
+        ensure_can_edit(&template, user_id, manager)?;
+        if let Some(scope) = request.scope {
+            ensure_can_publish(scope, manager)?;
+        }
+
+        let quantity = request.quantity.unwrap_or(template.quantity);
+        let specifications = request.specifications.unwrap_or(template.specifications);
+        self.validate(&template.job_type, quantity, &specifications).await?;
+
+        let mut active = job_templates::ActiveModel {
+            id: Set(template.id),
+            ..Default::default()
+        };
+
+        if let Some(name) = request.name {
+            active.name = Set(name);
+        }
+
+        if let Some(description) = request.description {
+            active.description = Set(Some(description));
+        }
+
+        if let Some(scope) = request.scope {
+            active.scope = Set(scope.to_string());
+        }
+
+        active.quantity = Set(quantity);
+        active.specifications = Set(specifications_to_json(&specifications)?);
+        active.updated_at = Set(Utc::now().into());
+
+        let model = active.update(self.db).await?;
+        to_template(model)
+    }
+
+    pub async fn delete_template(&self, template_id: Uuid, user_id: Uuid, manager: bool) -> Result<(), AppError> {
+        let template = self
+            .find_by_id(template_id, user_id)
+            .await?
+            .ok_or(AppError::NotFound("Template not found".to_string()))?;
+        ensure_can_edit(&template, user_id, manager)?;
+
+        job_templates::Entity::delete_by_id(template_id).exec(self.db).await?;
+        Ok(())
+    }
+
+    /// Templates are held to the same rules as jobs: the specification must
+    /// price on an available process.
+    async fn validate(
+        &self,
+        job_type: &JobType,
+        quantity: i32,
+        specifications: &JobSpecifications,
+    ) -> Result<(), AppError> {
+        CostingService::new(self.db)
+            .validate_job(job_type, quantity, specifications)
+            .await
+    }
+}
+
+fn visible_to(user_id: Uuid) -> Condition {
+    Condition::any()
+        .add(job_templates::Column::Scope.eq(TemplateScope::Shared.to_string()))
+        .add(job_templates::Column::OwnerId.eq(user_id))
+}
+
+fn ensure_can_publish(scope: TemplateScope, manager: bool) -> Result<(), AppError> {
+    if scope == TemplateScope::Shared && !manager {
+        return Err(AppError::Forbidden("Only managers can share templates".to_string()));
+    }
+    Ok(())
+}
+
+fn ensure_can_edit(template: &JobTemplate, user_id: Uuid, manager: bool) -> Result<(), AppError> {
+    let allowed = match template.scope {
+        TemplateScope::User => template.owner_id == user_id,
+        TemplateScope::Shared => manager,
+    };
+    if !allowed {
+        return Err(AppError::Forbidden("You cannot change this template".to_string()));
+    }
+    Ok(())
+}
+
+fn specifications_to_json(specifications: &JobSpecifications) -> Result<String, AppError> {
+    serde_json::to_string(specifications).map_err(|e| AppError::InternalServerError(e.to_string()))
+}
+
+fn to_template(model: job_templates::Model) -> Result<JobTemplate, AppError> {
+    let job_type = model.job_type.parse().map_err(AppError::InternalServerError)?;
+    let scope = model.scope.parse().map_err(AppError::InternalServerError)?;
+    let specifications: JobSpecifications = serde_json::from_str(&model.specifications)
+        .map_err(|e| AppError::InternalServerError(format!("Invalid template specifications: {}", e)))?;
+
+    Ok(JobTemplate {
+        id: model.id,
+        name: model.name,
+        description: model.description,
+        job_type,
+        quantity: model.quantity,
+        specifications,
+        scope,
+        owner_id: model.owner_id,
+        created_at: model.created_at.into(),
+        updated_at: model.updated_at.into(),
+    })
+}
@@ -0,0 +1,183 @@
+use bigdecimal::BigDecimal;
+use chrono::Utc;
+use sea_orm::{prelude::Decimal, ActiveModelTrait, ColumnTrait, EntityTrait, QueryFilter, QueryOrder, Set};
+use std::str::FromStr;
+use uuid::Uuid;
+
+use crate::{
+    entities::machines,
+    models::{
+        default_hours_per_day, default_working_days, CreateMachineRequest, Machine, SpeedBand,
+        UpdateMachineRequest,
+    },
+    utils::errors::AppError,
+};
+
+pub struct MachineService<'a> {
+    db: &'a sea_orm::DatabaseConnection,
+}
+
+impl<'a> MachineService<'a> {
+    pub fn new(db: &'a sea_orm::DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    pub async fn list_machines(&self) -> Result<Vec<Machine>, AppError> {
+        let models = machines::Entity::find()
+            .order_by_asc(machines::Column::Kind)
+            .order_by_asc(machines::Column::Name)
+            .all(self.db)
+            .await?;
+        models.into_iter().map(to_machine).collect()
+    }
+
+    /// Machines available for routing jobs.
+    pub async fn active_machines(&self) -> Result<Vec<Machine>, AppError> {
+        let models = machines::Entity::find()
+            .filter(machines::Column::IsActive.eq(true))
+            .order_by_asc(machines::Column::Name)
+            .all(self.db)
+            .await?;
+        models.into_iter().map(to_machine).collect()
+    }
+
+    pub async fn find_by_id(&self, machine_id: Uuid) -> Result<Option<Machine>, AppError> {
+        machines::Entity::find_by_id(machine_id)
+            .one(self.db)
+            .await?
+            .map(to_machine)
+            .transpose()
+    }
+
+    pub async fn create_machine(&self, request: CreateMachineRequest) -> Result<Machine, AppError> {
+        let working_days = request.working_days.unwrap_or_else(default_working_days);
+        validate_working_days(&working_days)?;
+
+        let model = machines::ActiveModel {
+            id: Set(Uuid::new_v4()),
+            name: Set(request.name),
+            kind: Set(request.kind.to_string()),
+            make_ready_minutes: Set(to_decimal(request.make_ready_minutes)?),
+            speed_bands: Set(speed_bands_to_json(&request.speed_bands)?),
+            machine_rate_per_hour: Set(to_decimal(request.machine_rate_per_hour)?),
+            operator_rate_per_hour: Set(to_decimal(request.operator_rate_per_hour)?),
+            hours_per_day: Set(to_decimal(request.hours_per_day.unwrap_or_else(default_hours_per_day))?),
+            working_days: Set(working_days_to_json(&working_days)?),
+            is_active: Set(true),
+            created_at: Set(Utc::now().into()),
+            updated_at: Set(Utc::now().into()),
+        }
+        .insert(self.db)
+        .await?;
+
+        to_machine(model)
+    }
+
+    pub async fn update_machine(
+        &self,
+        machine_id: Uuid,
+        request: UpdateMachineRequest,
+    ) -> Result<Machine, AppError> {
+        let model = machines::Entity::find_by_id(machine_id)
+            .one(self.db)
+            .await?
+            .ok_or(AppError::NotFound("Machine not found".to_string()))?;
+
+        let mut active: machines::ActiveModel = model.into();
+
+        if let Some(name) = request.name {
+            active.name = Set(name);
+        }
+
+        if let Some(make_ready_minutes) = request.make_ready_minutes {
+            active.make_ready_minutes = Set(to_decimal(make_ready_minutes)?);
+        }
+
+        if let Some(speed_bands) = request.speed_bands {
+            active.speed_bands = Set(speed_bands_to_json(&speed_bands)?);
+        }
+
+        if let Some(machine_rate_per_hour) = request.machine_rate_per_hour {
+            active.machine_rate_per_hour = Set(to_decimal(machine_rate_per_hour)?);
+        }
+
+        if let Some(operator_rate_per_hour) = request.operator_rate_per_hour {
+            active.operator_rate_per_hour = Set(to_decimal(operator_rate_per_hour)?);
+        }
+
+        if let Some(hours_per_day) = request.hours_per_day {
+            active.hours_per_day = Set(to_decimal(hours_per_day)?);
+        }
+
+        if let Some(working_days) = request.working_days {
+            validate_working_days(&working_days)?;
+            active.working_days = Set(working_days_to_json(&working_days)?);
+        }
+
+        if let Some(is_active) = request.is_active {
+            active.is_active = Set(is_active);
+        }
+
+        active.updated_at = Set(Utc::now().into());
+
+        let model = active.update(self.db).await?;
+        to_machine(model)
+    }
+
+    pub async fn delete_machine(&self, machine_id: Uuid) -> Result<(), AppError> {
+        let result = machines::Entity::delete_by_id(machine_id).exec(self.db).await?;
+        if result.rows_affected == 0 {
+            return Err(AppError::NotFound("Machine not found".to_string()));
+        }
+        Ok(())
+    }
+}
+
+fn to_decimal(value: impl ToString) -> Result<Decimal, AppError> {
+    let value = value.to_string();
+    Decimal::from_str(&value).map_err(|_| AppError::ValidationError(format!("{} is not a valid machine value", value)))
+}
+
+fn speed_bands_to_json(speed_bands: &[SpeedBand]) -> Result<String, AppError> {
+    serde_json::to_string(speed_bands).map_err(|e| AppError::InternalServerError(e.to_string()))
+}
+
+fn validate_working_days(working_days: &[u32]) -> Result<(), AppError> {
+    if working_days.is_empty() || working_days.iter().any(|day| !(1..=7).contains(day)) {
+        return Err(AppError::ValidationError(
+            "Working days must list at least one weekday, numbered 1 (Monday) to 7 (Sunday)".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+fn working_days_to_json(working_days: &[u32]) -> Result<String, AppError> {
+    let mut days = working_days.to_vec();
+    days.sort_unstable();
+    days.dedup();
+    serde_json::to_string(&days).map_err(|e| AppError::InternalServerError(e.to_string()))
+}
+
+fn to_machine(model: machines::Model) -> Result<Machine, AppError> {
+    let kind = model.kind.parse().map_err(AppError::InternalServerError)?;
+    let speed_bands: Vec<SpeedBand> = serde_json::from_str(&model.speed_bands)
+        .map_err(|e| AppError::InternalServerError(format!("Invalid speed bands: {}", e)))?;
+
+    Ok(Machine {
+        id: model.id,
+        name: model.name,
+        kind,
+        make_ready_minutes: model.make_ready_minutes.to_string().parse().unwrap_or(0.0),
+        speed_bands,
+        machine_rate_per_hour: BigDecimal::from_str(&model.machine_rate_per_hour.to_string())
+            .map_err(|e| AppError::InternalServerError(format!("Invalid machine rate: {}", e)))?,
+        operator_rate_per_hour: BigDecimal::from_str(&model.operator_rate_per_hour.to_string())
+            .map_err(|e| AppError::InternalServerError(format!("Invalid operator rate: {}", e)))?,
+        hours_per_day: model.hours_per_day.to_string().parse().unwrap_or_else(|_| default_hours_per_day()),
+        working_days: serde_json::from_str(&model.working_days)
+            .map_err(|e| AppError::InternalServerError(format!("Invalid working days: {}", e)))?,
+        is_active: model.is_active,
+        created_at: model.created_at.into(),
+        updated_at: model.updated_at.into(),
+    })
+}
@@ -1,7 +1,19 @@
 pub mod user_service;
+pub mod artwork_service;
+pub mod attachment_service;
 pub mod branding_service;
+pub mod comment_service;
 pub mod costing_service;
+pub mod costing_strategies;
 pub mod cost_parameters_service;
 pub mod currency_service;
+pub mod currency_settings_service;
+pub mod customer_service;
+pub mod file_store;
+pub mod job_import_service;
 pub mod job_service;
+pub mod job_template_service;
+pub mod machine_service;
+pub mod schedule_service;
 pub mod settings_service;
+pub mod tax_settings_service;
@@ -0,0 +1,536 @@
+use chrono::{Datelike, NaiveDate};
+use sea_orm::{ActiveModelTrait, EntityTrait, QueryOrder, Set};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::{
+    entities::holidays,
+    models::{
+        default_working_days, CreateHolidayRequest, Holiday, Job, JobSchedule, JobStatus, Machine, MachineDay,
+        ProductionSchedule, ScheduleDay, ScheduledRun, TurnaroundParameters,
+    },
+    services::{cost_parameters_service::CostParametersService, machine_service::MachineService},
+    utils::errors::AppError,
+};
+
+/// How far ahead the scheduler looks for capacity before giving up on a job.
+const HORIZON_DAYS: i64 = 730;
+
+/// Longest calendar the schedule API lays out at once.
+pub const MAX_CALENDAR_DAYS: i64 = 92;
+
+/// Line for work that is not routed through a configured machine, such as
+/// wide-format printing, run at the shop's production hours per day.
+const GENERAL_LINE: &str = "General production";
+
+/// Hours below this are rounding, not work.
+const EPSILON_HOURS: f64 = 1e-6;
+
+pub struct ScheduleService<'a> {
+    db: &'a sea_orm::DatabaseConnection,
+}
+
+impl<'a> ScheduleService<'a> {
+    pub fn new(db: &'a sea_orm::DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    pub async fn list_holidays(&self) -> Result<Vec<Holiday>, AppError> {
+        let models = holidays::Entity::find()
+            .order_by_asc(holidays::Column::Date)
+            .all(self.db)
+            .await?;
+        Ok(models.into_iter().map(to_holiday).collect())
+    }
+
+    pub async fn add_holiday(&self, request: CreateHolidayRequest) -> Result<Holiday, AppError> {
+        if holidays::Entity::find_by_id(request.date).one(self.db).await?.is_some() {
+            return Err(AppError::ValidationError(format!("{} is already a holiday", request.date)));
+        }
+
+        let model = holidays::ActiveModel {
+            date: Set(request.date),
+            name: Set(request.name),
+        }
+        .insert(self.db)
+        .await?;
+        Ok(to_holiday(model))
+    }
+
+    pub async fn delete_holiday(&self, date: NaiveDate) -> Result<(), AppError> {
+        let result = holidays::Entity::delete_by_id(date).exec(self.db).await?;
+        if result.rows_affected == 0 {
+            return Err(AppError::NotFound("Holiday not found".to_string()));
+        }
+        Ok(())
+    }
+
+    /// Places `jobs` on machine capacity from `from` and lays out the first
+    /// `days` days as a calendar.
+    pub async fn production_schedule(&self, mut jobs: Vec<Job>, from: NaiveDate, days: i64) -> Result<ProductionSchedule, AppError> {
+        sort_for_production(&mut jobs);
+        let plan = self.plan(jobs, from).await?;
+        let to = from + chrono::Duration::days(days.clamp(1, MAX_CALENDAR_DAYS) - 1);
+        Ok(plan.into_schedule(from, to))
+    }
+
+    /// When `job` would run and finish if it joined `workload`, the jobs
+    /// already approved for production.
+    pub async fn estimate(&self, workload: Vec<Job>, job: Job, from: NaiveDate) -> Result<JobSchedule, AppError> {
+        let job_id = job.id;
+        let plan = self.plan(queue_for_estimate(workload, job), from).await?;
+        plan.jobs
+            .into_iter()
+            .find(|scheduled| scheduled.job_id == job_id)
+            .ok_or(AppError::InternalServerError("Job was not scheduled".to_string()))
+    }
+
+    async fn plan(&self, jobs: Vec<Job>, from: NaiveDate) -> Result<Plan, AppError> {
+        let machines = MachineService::new(self.db).active_machines().await?;
+        let holidays: HashMap<NaiveDate, String> = self
+            .list_holidays()
+            .await?
+            .into_iter()
+            .map(|holiday| (holiday.date, holiday.name))
+            .collect();
+        let turnaround = CostParametersService::new(self.db)
+            .get_current_parameters()
+            .await?
+            .turnaround;
+
+        Ok(plan_jobs(jobs, &machines, holidays, &turnaround, from))
+    }
+}
+
+/// A machine, or the general line, with the next free slot on it.
+struct Line {
+    machine_id: Option<Uuid>,
+    name: String,
+    hours_per_day: f64,
+    working_days: Vec<u32>,
+    /// Day the next job can start on, and hours already booked that day.
+    free_from: (NaiveDate, f64),
+}
+
+impl Line {
+    fn capacity(&self, date: NaiveDate, holidays: &HashMap<NaiveDate, String>) -> f64 {
+        if holidays.contains_key(&date) || !self.working_days.contains(&date.weekday().number_from_monday()) {
+            0.0
+        } else {
+            self.hours_per_day
+        }
+    }
+}
+
+struct Booking {
+    line: usize,
+    date: NaiveDate,
+    job_id: Uuid,
+    title: String,
+    hours: f64,
+}
+
+struct Plan {
+    lines: Vec<Line>,
+    holidays: HashMap<NaiveDate, String>,
+    bookings: Vec<Booking>,
+    jobs: Vec<JobSchedule>,
+}
+
+impl Plan {
+    fn into_schedule(self, from: NaiveDate, to: NaiveDate) -> ProductionSchedule {
+        let mut runs: HashMap<(usize, NaiveDate), Vec<ScheduledRun>> = HashMap::new();
+        for booking in self.bookings.iter().filter(|booking| booking.date >= from && booking.date <= to) {
+            runs.entry((booking.line, booking.date)).or_default().push(ScheduledRun {
+                job_id: booking.job_id,
+                title: booking.title.clone(),
+                hours: round_hours(booking.hours),
+            });
+        }
+        let general_used = self.bookings.iter().any(|booking| self.lines[booking.line].machine_id.is_none());
+
+        let days = from
+            .iter_days()
+            .take_while(|date| *date <= to)
+            .map(|date| ScheduleDay {
+                date,
+                holiday: self.holidays.get(&date).cloned(),
+                machines: self
+                    .lines
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, line)| line.machine_id.is_some() || general_used)
+                    .map(|(index, line)| {
+                        let runs = runs.remove(&(index, date)).unwrap_or_default();
+                        MachineDay {
+                            machine_id: line.machine_id,
+                            machine_name: line.name.clone(),
+                            capacity_hours: line.capacity(date, &self.holidays),
+                            booked_hours: round_hours(runs.iter().fold(0.0, |total, run| total + run.hours)),
+                            runs,
+                        }
+                    })
+                    .collect(),
+            })
+            .collect();
+
+        ProductionSchedule {
+            from,
+            to,
+            days,
+            jobs: self.jobs,
+        }
+    }
+}
+
+/// Production order: work already in production first, then by due date,
+/// then by age.
+fn sort_for_production(jobs: &mut [Job]) {
+    jobs.sort_by_key(|job| {
+        let due = due_date(job);
+        (!matches!(job.status, JobStatus::InProduction), due.is_none(), due, job.created_at)
+    });
+}
+
+/// `workload` in production order with `job` in it. A job already in the
+/// workload keeps its place; any other joins the back of the queue, behind
+/// the work already approved whatever its due date.
+fn queue_for_estimate(mut workload: Vec<Job>, job: Job) -> Vec<Job> {
+    sort_for_production(&mut workload);
+    match workload.iter().position(|scheduled| scheduled.id == job.id) {
+        Some(index) => workload[index] = job,
+        None => workload.push(job),
+    }
+    workload
+}
+
+/// Places jobs one after another in the order given. Each job runs through
+/// its machines in route order, and a machine takes the next job once the
+/// previous one is off it.
+fn plan_jobs(
+    jobs: Vec<Job>,
+    machines: &[Machine],
+    holidays: HashMap<NaiveDate, String>,
+    turnaround: &TurnaroundParameters,
+    from: NaiveDate,
+) -> Plan {
+    let mut lines: Vec<Line> = machines
+        .iter()
+        .map(|machine| Line {
+            machine_id: Some(machine.id),
+            name: machine.name.clone(),
+            hours_per_day: machine.hours_per_day,
+            working_days: machine.working_days.clone(),
+            free_from: (from, 0.0),
+        })
+        .collect();
+    lines.push(Line {
+        machine_id: None,
+        name: GENERAL_LINE.to_string(),
+        hours_per_day: turnaround.production_hours_per_day.max(1.0),
+        working_days: default_working_days(),
+        free_from: (from, 0.0),
+    });
+    let general = lines.len() - 1;
+
+    let mut bookings = Vec::new();
+    let mut scheduled = Vec::new();
+
+    for job in jobs {
+        // Jobs not yet on the floor still need their pre-press lead time
+        let lead_days = match (&job.status, job.print_process) {
+            (JobStatus::InProduction, _) | (_, None) => 0,
+            (_, Some(process)) => turnaround.lead_days(process),
+        };
+        let earliest = add_working_days(&lines[general], from, lead_days, &holidays);
+
+        let steps: Vec<(usize, f64)> = if job.cost_breakdown.machine_hours.is_empty() {
+            vec![(general, job.cost_breakdown.production_hours)]
+        } else {
+            job.cost_breakdown
+                .machine_hours
+                .iter()
+                .map(|entry| {
+                    let line = lines
+                        .iter()
+                        .position(|line| line.machine_id == Some(entry.machine_id))
+                        .unwrap_or(general);
+                    (line, entry.hours)
+                })
+                .collect()
+        };
+
+        let mut ready = earliest;
+        let mut start_date = None;
+        let mut completion_date = Some(earliest);
+
+        for (index, hours) in steps.into_iter().filter(|(_, hours)| *hours > EPSILON_HOURS) {
+            let line = &mut lines[index];
+            let (mut date, mut used) = if line.free_from.0 >= ready { line.free_from } else { (ready, 0.0) };
+            let mut remaining = hours;
+
+            loop {
+                if (date - from).num_days() > HORIZON_DAYS {
+                    completion_date = None;
+                    break;
+                }
+                let free = line.capacity(date, &holidays) - used;
+                if free > EPSILON_HOURS {
+                    let booked = free.min(remaining);
+                    bookings.push(Booking {
+                        line: index,
+                        date,
+                        job_id: job.id,
+                        title: job.title.clone(),
+                        hours: booked,
+                    });
+                    start_date.get_or_insert(date);
+                    used += booked;
+                    remaining -= booked;
+                    if remaining <= EPSILON_HOURS {
+                        break;
+                    }
+                }
+                date = date.succ_opt().unwrap_or(date);
+                used = 0.0;
+            }
+
+            line.free_from = (date, used);
+            ready = date;
+            if completion_date.is_none() {
+                break;
+            }
+            completion_date = Some(date);
+        }
+
+        let due = due_date(&job);
+        let (late, days_late) = match (due, completion_date) {
+            (Some(due), Some(completion)) => (completion > due, (completion - due).num_days().max(0)),
+            (Some(_), None) => (true, 0),
+            (None, _) => (false, 0),
+        };
+
+        scheduled.push(JobSchedule {
+            job_id: job.id,
+            title: job.title,
+            status: job.status,
+            requested_date: job.terms.requested_delivery_date,
+            promised_date: job.promised_date,
+            start_date: start_date.unwrap_or(earliest),
+            completion_date,
+            late,
+            days_late,
+        });
+    }
+
+    Plan {
+        lines,
+        holidays,
+        bookings,
+        jobs: scheduled,
+    }
+}
+
+/// The date a job is held to: what the shop promised, otherwise what the
+/// customer asked for.
+fn due_date(job: &Job) -> Option<NaiveDate> {
+    job.promised_date.or(job.terms.requested_delivery_date)
+}
+
+/// `days` working days on `line`'s calendar after `from`.
+fn add_working_days(line: &Line, from: NaiveDate, days: i32, holidays: &HashMap<NaiveDate, String>) -> NaiveDate {
+    let mut date = from;
+    let mut remaining = days;
+    while remaining > 0 && (date - from).num_days() <= HORIZON_DAYS {
+        date = date.succ_opt().unwrap_or(date);
+        if line.capacity(date, holidays) > 0.0 {
+            remaining -= 1;
+        }
+    }
+    date
+}
+
+fn round_hours(hours: f64) -> f64 {
+    (hours * 100.0).round() / 100.0
+}
+
+fn to_holiday(model: holidays::Model) -> Holiday {
+    Holiday {
+        date: model.date,
+        name: model.name,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        models::{default_machines, CostBreakdown, MachineHours, QuoteTerms},
+        services::costing_strategies::test_support::specifications,
+    };
+    use bigdecimal::BigDecimal;
+    use chrono::{Duration, TimeZone, Utc};
+
+    /// A Monday.
+    fn monday() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 10, 19).unwrap()
+    }
+
+    fn press() -> Machine {
+        Machine {
+            hours_per_day: 8.0,
+            working_days: default_working_days(),
+            ..default_machines().remove(0)
+        }
+    }
+
+    fn turnaround() -> TurnaroundParameters {
+        TurnaroundParameters {
+            offset_lead_days: 2,
+            digital_lead_days: 1,
+            wide_format_lead_days: 1,
+            production_hours_per_day: 8.0,
+        }
+    }
+
+    /// An approved job needing `hours` on `machine`, created `age` minutes
+    /// into the day.
+    fn job(title: &str, machine: &Machine, hours: f64, age: i64) -> Job {
+        let mut cost_breakdown = CostBreakdown::from_lines(Vec::new());
+        cost_breakdown.machine_hours = vec![MachineHours {
+            machine_id: machine.id,
+            machine_name: machine.name.clone(),
+            hours,
+        }];
+        let created_at = Utc.with_ymd_and_hms(2026, 10, 1, 0, 0, 0).unwrap() + Duration::minutes(age);
+
+        Job {
+            id: Uuid::new_v4(),
+            user_id: Uuid::new_v4(),
+            title: title.to_string(),
+            job_type: crate::models::JobType::Flyer,
+            quantity: 1000,
+            specifications: specifications("A4"),
+            cost_breakdown,
+            total_cost: BigDecimal::from(0),
+            unit_cost: BigDecimal::from(0),
+            pricing: None,
+            tax: None,
+            customer_id: None,
+            terms: QuoteTerms::default(),
+            revision: 1,
+            reorder_of: None,
+            price_held_until: None,
+            promised_date: None,
+            margin_approved_by: None,
+            print_process: None,
+            status: JobStatus::Approved,
+            created_at,
+            updated_at: created_at,
+        }
+    }
+
+    fn plan(jobs: Vec<Job>, machine: &Machine, holidays: HashMap<NaiveDate, String>) -> Plan {
+        plan_jobs(jobs, std::slice::from_ref(machine), holidays, &turnaround(), monday())
+    }
+
+    #[test]
+    fn hours_beyond_a_day_spill_into_the_next_working_day() {
+        let press = press();
+        let plan = plan(vec![job("Catalogue", &press, 12.0, 0)], &press, HashMap::new());
+
+        let scheduled = &plan.jobs[0];
+        assert_eq!(scheduled.start_date, monday());
+        assert_eq!(scheduled.completion_date, Some(monday() + Duration::days(1)));
+        let hours: Vec<(NaiveDate, f64)> = plan.bookings.iter().map(|booking| (booking.date, booking.hours)).collect();
+        assert_eq!(hours, vec![(monday(), 8.0), (monday() + Duration::days(1), 4.0)]);
+    }
+
+    #[test]
+    fn weekends_are_skipped() {
+        let press = press();
+        let thursday = monday() + Duration::days(3);
+        let plan = plan_jobs(vec![job("Posters", &press, 20.0, 0)], &[press], HashMap::new(), &turnaround(), thursday);
+
+        assert_eq!(plan.jobs[0].start_date, thursday);
+        assert_eq!(plan.jobs[0].completion_date, Some(monday() + Duration::days(7)));
+    }
+
+    #[test]
+    fn nothing_is_booked_on_a_holiday() {
+        let press = press();
+        let holidays = HashMap::from([(monday(), "National Day".to_string())]);
+        let plan = plan(vec![job("Flyers", &press, 4.0, 0)], &press, holidays);
+
+        assert_eq!(plan.jobs[0].start_date, monday() + Duration::days(1));
+        assert!(plan.bookings.iter().all(|booking| booking.date != monday()));
+    }
+
+    #[test]
+    fn a_job_waits_for_the_one_before_it_on_the_same_machine() {
+        let press = press();
+        let plan = plan(
+            vec![job("First", &press, 6.0, 0), job("Second", &press, 4.0, 1)],
+            &press,
+            HashMap::new(),
+        );
+
+        let second = &plan.jobs[1];
+        assert_eq!(second.start_date, monday());
+        assert_eq!(second.completion_date, Some(monday() + Duration::days(1)));
+    }
+
+    #[test]
+    fn a_job_finishing_after_its_promised_date_is_late() {
+        let press = press();
+        let mut job = job("Annual report", &press, 20.0, 0);
+        job.promised_date = Some(monday());
+        let plan = plan(vec![job], &press, HashMap::new());
+
+        let scheduled = &plan.jobs[0];
+        assert!(scheduled.late);
+        assert_eq!(scheduled.days_late, 2);
+    }
+
+    #[test]
+    fn a_job_finishing_on_time_is_not_late() {
+        let press = press();
+        let mut job = job("Menus", &press, 4.0, 0);
+        job.terms.requested_delivery_date = Some(monday());
+        let plan = plan(vec![job], &press, HashMap::new());
+
+        assert!(!plan.jobs[0].late);
+        assert_eq!(plan.jobs[0].days_late, 0);
+    }
+
+    #[test]
+    fn an_estimated_job_queues_behind_approved_work() {
+        let press = press();
+        let mut approved = job("Approved", &press, 8.0, 0);
+        approved.promised_date = Some(monday() + Duration::days(10));
+        let mut draft = job("Draft", &press, 4.0, 1);
+        draft.status = JobStatus::Draft;
+        draft.terms.requested_delivery_date = Some(monday());
+        let draft_id = draft.id;
+
+        let queue = queue_for_estimate(vec![approved], draft);
+        assert_eq!(queue.last().map(|job| job.id), Some(draft_id));
+
+        let plan = plan(queue, &press, HashMap::new());
+        let estimate = plan.jobs.iter().find(|job| job.job_id == draft_id).unwrap();
+        assert_eq!(estimate.start_date, monday() + Duration::days(1));
+    }
+
+    #[test]
+    fn an_estimated_job_already_in_the_workload_keeps_its_place() {
+        let press = press();
+        let first = job("First", &press, 8.0, 0);
+        let second = job("Second", &press, 4.0, 1);
+        let first_id = first.id;
+
+        let queue = queue_for_estimate(vec![second, first.clone()], first);
+
+        assert_eq!(queue.len(), 2);
+        assert_eq!(queue[0].id, first_id);
+    }
+}
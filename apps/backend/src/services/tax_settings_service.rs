@@ -0,0 +1,145 @@
+use bigdecimal::BigDecimal;
+use chrono::Utc;
+use sea_orm::{ActiveModelTrait, EntityTrait, Set};
+use uuid::Uuid;
+
+use crate::{
+    entities::tax_settings,
+    models::{TaxRate, TaxSettings, UpdateTaxSettingsRequest},
+    utils::errors::AppError,
+};
+
+pub struct TaxSettingsService<'a> {
+    db: &'a sea_orm::DatabaseConnection,
+}
+
+impl<'a> TaxSettingsService<'a> {
+    pub fn new(db: &'a sea_orm::DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// Stored tax rules, or the Cameroon VAT defaults when nothing has been
+    /// saved yet.
+    pub async fn get_settings(&self) -> Result<TaxSettings, AppError> {
+        let stored = tax_settings::Entity::find().one(self.db).await?;
+
+        match stored {
+            Some(model) => to_settings(&model),
+            None => Ok(TaxSettings::default()),
+        }
+    }
+
+    pub async fn update_settings(&self, request: UpdateTaxSettingsRequest) -> Result<TaxSettings, AppError> {
+        let mut settings = self.get_settings().await?;
+
+        if let Some(company_country) = request.company_country {
+            settings.company_country = company_country.trim().to_uppercase();
+        }
+
+        if let Some(company_tax_id) = request.company_tax_id {
+            settings.company_tax_id = Some(company_tax_id).filter(|id| !id.trim().is_empty());
+        }
+
+        if let Some(rates) = request.rates {
+            settings.rates = rates;
+        }
+
+        if let Some(countries) = request.reverse_charge_countries {
+            settings.reverse_charge_countries = countries.iter().map(|code| code.trim().to_uppercase()).collect();
+        }
+
+        if let Some(reverse_charge_note) = request.reverse_charge_note {
+            settings.reverse_charge_note = reverse_charge_note;
+        }
+
+        if let Some(exemption_note) = request.exemption_note {
+            settings.exemption_note = exemption_note;
+        }
+
+        validate_settings(&settings)?;
+
+        let rates = serde_json::to_string(&settings.rates).map_err(|e| AppError::InternalServerError(e.to_string()))?;
+        let countries = settings.reverse_charge_countries.join(",");
+
+        match tax_settings::Entity::find().one(self.db).await? {
+            Some(model) => {
+                let mut active: tax_settings::ActiveModel = model.into();
+                active.company_country = Set(settings.company_country.clone());
+                active.company_tax_id = Set(settings.company_tax_id.clone());
+                active.rates = Set(rates);
+                active.reverse_charge_countries = Set(countries);
+                active.reverse_charge_note = Set(settings.reverse_charge_note.clone());
+                active.exemption_note = Set(settings.exemption_note.clone());
+                active.updated_at = Set(Utc::now().into());
+                active.update(self.db).await?;
+            }
+            None => {
+                tax_settings::ActiveModel {
+                    id: Set(Uuid::new_v4()),
+                    company_country: Set(settings.company_country.clone()),
+                    company_tax_id: Set(settings.company_tax_id.clone()),
+                    rates: Set(rates),
+                    reverse_charge_countries: Set(countries),
+                    reverse_charge_note: Set(settings.reverse_charge_note.clone()),
+                    exemption_note: Set(settings.exemption_note.clone()),
+                    created_at: Set(Utc::now().into()),
+                    updated_at: Set(Utc::now().into()),
+                }
+                .insert(self.db)
+                .await?;
+            }
+        }
+
+        Ok(settings)
+    }
+}
+
+fn to_settings(model: &tax_settings::Model) -> Result<TaxSettings, AppError> {
+    let rates: Vec<TaxRate> = serde_json::from_str(&model.rates)
+        .map_err(|e| AppError::InternalServerError(format!("Invalid stored tax rates: {}", e)))?;
+
+    Ok(TaxSettings {
+        company_country: model.company_country.clone(),
+        company_tax_id: model.company_tax_id.clone(),
+        rates,
+        reverse_charge_countries: model
+            .reverse_charge_countries
+            .split(',')
+            .filter(|code| !code.is_empty())
+            .map(str::to_string)
+            .collect(),
+        reverse_charge_note: model.reverse_charge_note.clone(),
+        exemption_note: model.exemption_note.clone(),
+    })
+}
+
+fn validate_settings(settings: &TaxSettings) -> Result<(), AppError> {
+    let is_country_code = |code: &str| code.len() == 2 && code.chars().all(|c| c.is_ascii_alphabetic());
+
+    if !is_country_code(&settings.company_country) {
+        return Err(AppError::ValidationError(format!(
+            "{} is not a two-letter country code",
+            settings.company_country
+        )));
+    }
+
+    if let Some(code) = settings.reverse_charge_countries.iter().find(|code| !is_country_code(code)) {
+        return Err(AppError::ValidationError(format!("{} is not a two-letter country code", code)));
+    }
+
+    let zero = BigDecimal::from(0);
+    let one = BigDecimal::from(1);
+    for rate in &settings.rates {
+        if rate.name.trim().is_empty() {
+            return Err(AppError::ValidationError("Tax rates need a name".to_string()));
+        }
+        if rate.rate < zero || rate.rate >= one {
+            return Err(AppError::ValidationError(format!(
+                "{} must be a fraction between 0 and 1",
+                rate.name
+            )));
+        }
+    }
+
+    Ok(())
+}
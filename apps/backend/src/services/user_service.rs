@@ -1,9 +1,11 @@
 use bcrypt::{hash, DEFAULT_COST};
+use sea_orm::{ActiveModelTrait, ColumnTrait, EntityTrait, QueryFilter, Set};
 use uuid::Uuid;
 
 use crate::{
     db::Database,
-    models::{CreateUserRequest, UpdateUserRequest, User, UserRole},
+    entities::users,
+    models::{CreateUserRequest, Currency, UpdateUserRequest, User, UserRole},
     utils::errors::AppError,
 };
 
@@ -28,6 +30,7 @@ impl<'a> UserService<'a> {
             first_name: request.first_name,
             last_name: request.last_name,
             is_active: true,
+            preferred_currency: None,
             created_at: chrono::Utc::now(),
             updated_at: chrono::Utc::now(),
         };
@@ -37,9 +40,17 @@ impl<'a> UserService<'a> {
         Ok(user)
     }
 
+    /// Stored user with `email`. The demo admin is available until the
+    /// shop stores its own users.
     pub async fn find_by_email(&self, email: &str) -> Result<Option<User>, AppError> {
-        // Placeholder implementation - in reality this would query the database
-        // For demo purposes, create a default admin user
+        let stored = users::Entity::find()
+            .filter(users::Column::Email.eq(email))
+            .one(self.db)
+            .await?;
+        if let Some(model) = stored {
+            return Ok(Some(to_user(model)));
+        }
+
         if email == "admin@costprint.com" {
             let password_hash = hash("admin123", DEFAULT_COST)
                 .map_err(|_| AppError::InternalServerError("Password hashing failed".to_string()))?;
@@ -52,6 +63,7 @@ impl<'a> UserService<'a> {
                 first_name: Some("Admin".to_string()),
                 last_name: Some("User".to_string()),
                 is_active: true,
+                preferred_currency: None,
                 created_at: chrono::Utc::now(),
                 updated_at: chrono::Utc::now(),
             }))
@@ -61,8 +73,8 @@ impl<'a> UserService<'a> {
     }
 
     pub async fn find_by_id(&self, user_id: Uuid) -> Result<Option<User>, AppError> {
-        // Placeholder implementation
-        Ok(None)
+        let user = users::Entity::find_by_id(user_id).one(self.db).await?;
+        Ok(user.map(to_user))
     }
 
     pub async fn update_user(&self, user_id: Uuid, request: UpdateUserRequest) -> Result<User, AppError> {
@@ -70,6 +82,31 @@ impl<'a> UserService<'a> {
         Err(AppError::NotFound("User not found".to_string()))
     }
 
+    pub async fn get_preferred_currency(&self, user_id: Uuid) -> Result<Option<Currency>, AppError> {
+        let user = users::Entity::find_by_id(user_id).one(self.db).await?;
+        Ok(user
+            .and_then(|user| user.preferred_currency)
+            .and_then(|code| Currency::from_code(&code)))
+    }
+
+    pub async fn set_preferred_currency(
+        &self,
+        user_id: Uuid,
+        currency: Option<Currency>,
+    ) -> Result<(), AppError> {
+        let user = users::Entity::find_by_id(user_id)
+            .one(self.db)
+            .await?
+            .ok_or(AppError::NotFound("User not found".to_string()))?;
+
+        let mut active: users::ActiveModel = user.into();
+        active.preferred_currency = Set(currency.map(|c| c.code().to_string()));
+        active.updated_at = Set(chrono::Utc::now().into());
+        active.update(self.db).await?;
+
+        Ok(())
+    }
+
     pub async fn delete_user(&self, user_id: Uuid) -> Result<(), AppError> {
         // Placeholder implementation
         Ok(())
@@ -80,3 +117,19 @@ impl<'a> UserService<'a> {
         Ok(vec![])
     }
 }
+
+fn to_user(model: users::Model) -> User {
+    User {
+        id: model.id,
+        email: model.email,
+        password_hash: model.password_hash,
+        // Unknown roles get the least access
+        role: model.role.parse().unwrap_or(UserRole::User),
+        first_name: model.first_name,
+        last_name: model.last_name,
+        is_active: model.is_active,
+        preferred_currency: model.preferred_currency.as_deref().and_then(Currency::from_code),
+        created_at: model.created_at.into(),
+        updated_at: model.updated_at.into(),
+    }
+}
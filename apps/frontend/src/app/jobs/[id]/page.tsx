@@ -3,9 +3,9 @@
 import { useState, useEffect } from 'react';
 import { useParams, useRouter } from 'next/navigation';
 import { useAuth } from '@/contexts/AuthContext';
-import { jobsApi } from '@/lib/api';
-import type { Job } from '@/types';
-import { ArrowLeft, Calendar, DollarSign, Package, User, Edit, Trash2 } from 'lucide-react';
+import { jobsApi, attachmentsApi, commentsApi, scheduleApi, exportApi } from '@/lib/api';
+import type { Job, JobAttachment, AttachmentKind, ArtworkAnalysis, JobComment, JobSchedule } from '@/types';
+import { ArrowLeft, Calendar, DollarSign, Package, User, Edit, Trash2, Copy, Repeat, Paperclip, Download, Ruler, Printer } from 'lucide-react';
 import { formatCurrency, CURRENCY_INFO } from '@/lib/currency';
 import Link from 'next/link';
 
@@ -16,6 +16,15 @@ export default function JobDetailPage() {
   const jobId = params.id as string;
   
   const [job, setJob] = useState<Job | null>(null);
+  const [reorders, setReorders] = useState<Job[]>([]);
+  const [attachments, setAttachments] = useState<JobAttachment[]>([]);
+  const [attachmentKind, setAttachmentKind] = useState<AttachmentKind>('artwork');
+  const [uploading, setUploading] = useState(false);
+  const [analysis, setAnalysis] = useState<ArtworkAnalysis | null>(null);
+  const [comments, setComments] = useState<JobComment[]>([]);
+  const [commentBody, setCommentBody] = useState('');
+  const [customerVisible, setCustomerVisible] = useState(false);
+  const [schedule, setSchedule] = useState<JobSchedule | null>(null);
   const [loading, setLoading] = useState(true);
   const [error, setError] = useState('');
 
@@ -25,8 +34,17 @@ export default function JobDetailPage() {
       
       try {
         setLoading(true);
-        const jobData = await jobsApi.getById(jobId);
+        const [jobData, chain, files, estimate] = await Promise.all([
+          jobsApi.getById(jobId),
+          jobsApi.reorderChain(jobId),
+          attachmentsApi.list(jobId),
+          scheduleApi.forJob(jobId),
+        ]);
         setJob(jobData);
+        setComments(jobData.comments);
+        setReorders(chain);
+        setAttachments(files);
+        setSchedule(estimate);
       } catch (error: unknown) {
         console.error('Failed to fetch job:', error);
         const errorMessage = error instanceof Error ? error.message : 'Failed to load job details';
@@ -51,6 +69,173 @@ export default function JobDetailPage() {
     }
   };
 
+  const handleDuplicate = async () => {
+    if (!job) return;
+    const recost = confirm('Re-cost the reorder with current prices? Cancel keeps the original price for 30 days.');
+
+    try {
+      const reorder = await jobsApi.duplicate(job.id, { recost });
+      router.push(`/jobs/${reorder.id}`);
+    } catch (error: unknown) {
+      console.error('Failed to duplicate job:', error);
+      setError('Failed to duplicate job. Please try again.');
+    }
+  };
+
+  const handleSaveAsTemplate = async () => {
+    if (!job) return;
+    const name = prompt('Template name', job.title);
+    if (!name) return;
+
+    try {
+      await jobsApi.saveAsTemplate(job.id, { name, scope: 'user' });
+    } catch (error: unknown) {
+      console.error('Failed to save template:', error);
+      setError('Failed to save template. Please try again.');
+    }
+  };
+
+  const handleUpload = async (event: React.ChangeEvent<HTMLInputElement>) => {
+    const file = event.target.files?.[0];
+    event.target.value = '';
+    if (!job || !file) return;
+
+    try {
+      setUploading(true);
+      const attachment = await attachmentsApi.upload(job.id, file, attachmentKind);
+      setAttachments(current => [...current, attachment]);
+    } catch (error: unknown) {
+      console.error('Failed to upload attachment:', error);
+      setError('Failed to upload file. Check its type and size and try again.');
+    } finally {
+      setUploading(false);
+    }
+  };
+
+  const handleDownload = async (attachment: JobAttachment) => {
+    try {
+      const blob = await attachmentsApi.download(attachment.jobId, attachment.id);
+      const url = URL.createObjectURL(blob);
+      const link = document.createElement('a');
+      link.href = url;
+      link.download = attachment.filename;
+      link.click();
+      URL.revokeObjectURL(url);
+    } catch (error: unknown) {
+      console.error('Failed to download attachment:', error);
+      setError('Failed to download file. Please try again.');
+    }
+  };
+
+  const handlePrintTicket = async () => {
+    if (!job) return;
+
+    try {
+      const blob = await exportApi.exportTicket(job.id);
+      const url = URL.createObjectURL(blob);
+      const link = document.createElement('a');
+      link.href = url;
+      link.download = `ticket_${job.id}.pdf`;
+      link.click();
+      URL.revokeObjectURL(url);
+    } catch (error: unknown) {
+      console.error('Failed to export job ticket:', error);
+      setError('Failed to export the job ticket. Please try again.');
+    }
+  };
+
+  const handleAnalyze = async (attachment: JobAttachment) => {
+    try {
+      setAnalysis(await attachmentsApi.analyze(attachment.jobId, attachment.id));
+    } catch (error: unknown) {
+      console.error('Failed to analyze attachment:', error);
+      setError('Failed to read the PDF. Please check the file.');
+    }
+  };
+
+  const handleApplyAnalysis = async () => {
+    if (!job || !analysis) return;
+
+    try {
+      const updated = await jobsApi.update(job.id, {
+        specifications: {
+          ...job.specifications,
+          pages: analysis.suggestedPages,
+          paperSize: analysis.suggestedPaperSize,
+        },
+      });
+      setJob(updated);
+      setAnalysis(null);
+    } catch (error: unknown) {
+      console.error('Failed to update job:', error);
+      setError('Failed to update the job specifications. Please try again.');
+    }
+  };
+
+  const handlePromise = async (promisedDate: string) => {
+    if (!job || !promisedDate) return;
+
+    try {
+      const updated = await jobsApi.update(job.id, { promisedDate });
+      setJob(updated);
+      setSchedule(await scheduleApi.forJob(job.id));
+    } catch (error: unknown) {
+      console.error('Failed to set promised date:', error);
+      setError('Failed to set the promised date. Please try again.');
+    }
+  };
+
+  const handlePostComment = async () => {
+    if (!job || !commentBody.trim()) return;
+
+    try {
+      const comment = await commentsApi.create(job.id, { body: commentBody, customerVisible });
+      setComments(current => [...current, comment]);
+      setCommentBody('');
+      setCustomerVisible(false);
+    } catch (error: unknown) {
+      console.error('Failed to post comment:', error);
+      setError('Failed to post comment. Please try again.');
+    }
+  };
+
+  const handleEditComment = async (comment: JobComment) => {
+    const body = prompt('Edit comment', comment.body);
+    if (!body || body === comment.body) return;
+
+    try {
+      const updated = await commentsApi.update(comment.jobId, comment.id, { body });
+      setComments(current => current.map(item => (item.id === updated.id ? updated : item)));
+    } catch (error: unknown) {
+      console.error('Failed to edit comment:', error);
+      setError('Failed to edit comment. Please try again.');
+    }
+  };
+
+  const handleDeleteComment = async (comment: JobComment) => {
+    if (!confirm('Delete this comment?')) return;
+
+    try {
+      await commentsApi.delete(comment.jobId, comment.id);
+      setComments(current => current.filter(item => item.id !== comment.id));
+    } catch (error: unknown) {
+      console.error('Failed to delete comment:', error);
+      setError('Failed to delete comment. Please try again.');
+    }
+  };
+
+  const handleDeleteAttachment = async (attachment: JobAttachment) => {
+    if (!confirm(`Delete ${attachment.filename}?`)) return;
+
+    try {
+      await attachmentsApi.delete(attachment.jobId, attachment.id);
+      setAttachments(current => current.filter(item => item.id !== attachment.id));
+    } catch (error: unknown) {
+      console.error('Failed to delete attachment:', error);
+      setError('Failed to delete file. Please try again.');
+    }
+  };
+
   if (loading) {
     return (
       <div className="min-h-screen bg-gray-50 flex items-center justify-center">
@@ -82,6 +267,15 @@ export default function JobDetailPage() {
     );
   }
 
+  // Calendar dates carry no time zone; parse them as local days
+  const formatDay = (day: string) => {
+    return new Date(`${day}T00:00:00`).toLocaleDateString('en-US', {
+      year: 'numeric',
+      month: 'long',
+      day: 'numeric',
+    });
+  };
+
   const formatDate = (dateString: string) => {
     return new Date(dateString).toLocaleDateString('en-US', {
       year: 'numeric',
@@ -129,6 +323,15 @@ export default function JobDetailPage() {
             </div>
             
             <div className="flex space-x-2">
+              {['Approved', 'InProduction', 'Completed'].includes(job.status) && (
+                <button
+                  onClick={handlePrintTicket}
+                  className="inline-flex items-center px-3 py-2 border border-gray-300 shadow-sm text-sm leading-4 font-medium rounded-md text-gray-700 bg-white hover:bg-gray-50"
+                >
+                  <Printer className="h-4 w-4 mr-2" />
+                  Job Ticket
+                </button>
+              )}
               <button
                 onClick={() => router.push(`/jobs/${job.id}/edit`)}
                 className="inline-flex items-center px-3 py-2 border border-gray-300 shadow-sm text-sm leading-4 font-medium rounded-md text-gray-700 bg-white hover:bg-gray-50"
@@ -136,6 +339,20 @@ export default function JobDetailPage() {
                 <Edit className="h-4 w-4 mr-2" />
                 Edit
               </button>
+              <button
+                onClick={handleDuplicate}
+                className="inline-flex items-center px-3 py-2 border border-gray-300 shadow-sm text-sm leading-4 font-medium rounded-md text-gray-700 bg-white hover:bg-gray-50"
+              >
+                <Repeat className="h-4 w-4 mr-2" />
+                Reorder
+              </button>
+              <button
+                onClick={handleSaveAsTemplate}
+                className="inline-flex items-center px-3 py-2 border border-gray-300 shadow-sm text-sm leading-4 font-medium rounded-md text-gray-700 bg-white hover:bg-gray-50"
+              >
+                <Copy className="h-4 w-4 mr-2" />
+                Save as Template
+              </button>
               <button
                 onClick={handleDelete}
                 className="inline-flex items-center px-3 py-2 border border-red-300 shadow-sm text-sm leading-4 font-medium rounded-md text-red-700 bg-white hover:bg-red-50"
@@ -189,7 +406,17 @@ export default function JobDetailPage() {
                 {job.specifications.lamination && (
                   <div>
                     <label className="block text-sm font-medium text-gray-500">Lamination</label>
-                    <p className="mt-1 text-sm text-gray-900">{job.specifications.lamination}</p>
+                    <p className="mt-1 text-sm text-gray-900">
+                      {job.specifications.lamination.film.replace('_', ' ')} ({job.specifications.lamination.sides === 'double' ? 'both sides' : 'one side'})
+                    </p>
+                  </div>
+                )}
+                {job.printProcess && (
+                  <div>
+                    <label className="block text-sm font-medium text-gray-500">Print Process</label>
+                    <p className="mt-1 text-sm text-gray-900 capitalize">
+                      {job.printProcess.replace('_', ' ')}{job.specifications.process ? ' (pinned)' : ''}
+                    </p>
                   </div>
                 )}
               </div>
@@ -236,6 +463,213 @@ export default function JobDetailPage() {
                     <p className="text-sm text-gray-500">{formatDate(job.updatedAt)}</p>
                   </div>
                 </div>
+                {job.priceHeldUntil && (
+                  <div className="flex items-center">
+                    <Calendar className="h-5 w-5 text-gray-400 mr-3" />
+                    <div>
+                      <p className="text-sm font-medium text-gray-900">Original Price Held Until</p>
+                      <p className="text-sm text-gray-500">{formatDate(job.priceHeldUntil)}</p>
+                    </div>
+                  </div>
+                )}
+                {job.terms.requestedDeliveryDate && (
+                  <div className="flex items-center">
+                    <Calendar className="h-5 w-5 text-gray-400 mr-3" />
+                    <div>
+                      <p className="text-sm font-medium text-gray-900">Requested Delivery</p>
+                      <p className="text-sm text-gray-500">{formatDay(job.terms.requestedDeliveryDate)}</p>
+                    </div>
+                  </div>
+                )}
+                <div className="flex items-center">
+                  <Calendar className="h-5 w-5 text-gray-400 mr-3" />
+                  <div>
+                    <p className="text-sm font-medium text-gray-900">Promised</p>
+                    <input
+                      type="date"
+                      value={job.promisedDate ?? ''}
+                      onChange={e => handlePromise(e.target.value)}
+                      className="text-sm text-gray-500 border-gray-300 rounded-md"
+                    />
+                  </div>
+                </div>
+                {schedule && (
+                  <div className="flex items-center">
+                    <Calendar className="h-5 w-5 text-gray-400 mr-3" />
+                    <div>
+                      <p className="text-sm font-medium text-gray-900">
+                        Estimated Completion
+                        {schedule.late && (
+                          <span className="ml-2 inline-flex items-center px-2 py-0.5 rounded-full text-xs font-medium bg-red-100 text-red-800">
+                            {schedule.daysLate > 0 ? `${schedule.daysLate} days late` : 'Late'}
+                          </span>
+                        )}
+                      </p>
+                      <p className="text-sm text-gray-500">
+                        {schedule.completionDate
+                          ? `${formatDay(schedule.startDate)} – ${formatDay(schedule.completionDate)}`
+                          : 'Beyond the planning horizon'}
+                      </p>
+                    </div>
+                  </div>
+                )}
+              </div>
+            </div>
+
+            {/* Reorder chain */}
+            {reorders.length > 1 && (
+              <div className="bg-white shadow rounded-lg p-6">
+                <h2 className="text-lg font-medium text-gray-900 mb-4">Reorders</h2>
+                <ul className="space-y-2">
+                  {reorders.map(order => (
+                    <li key={order.id} className="flex justify-between text-sm">
+                      {order.id === job.id ? (
+                        <span className="font-medium text-gray-900">{order.title} (this job)</span>
+                      ) : (
+                        <Link href={`/jobs/${order.id}`} className="text-blue-600 hover:text-blue-800">
+                          {order.title}
+                        </Link>
+                      )}
+                      <span className="text-gray-500">{formatDate(order.createdAt)}</span>
+                    </li>
+                  ))}
+                </ul>
+              </div>
+            )}
+
+            {/* Attachments */}
+            <div className="bg-white shadow rounded-lg p-6">
+              <div className="flex items-center justify-between mb-4">
+                <h2 className="text-lg font-medium text-gray-900">Files</h2>
+                <div className="flex items-center space-x-2">
+                  <select
+                    value={attachmentKind}
+                    onChange={(e) => setAttachmentKind(e.target.value as AttachmentKind)}
+                    className="text-sm border border-gray-300 rounded-md px-2 py-1"
+                  >
+                    <option value="artwork">Artwork</option>
+                    <option value="proof">Proof</option>
+                    <option value="reference">Reference</option>
+                    <option value="other">Other</option>
+                  </select>
+                  <label className="inline-flex items-center px-3 py-1 border border-gray-300 rounded-md text-sm font-medium text-gray-700 bg-white hover:bg-gray-50 cursor-pointer">
+                    <Paperclip className="h-4 w-4 mr-1" />
+                    {uploading ? 'Uploading...' : 'Upload'}
+                    <input type="file" className="hidden" onChange={handleUpload} disabled={uploading} />
+                  </label>
+                </div>
+              </div>
+              {attachments.length === 0 ? (
+                <p className="text-sm text-gray-500">No files attached.</p>
+              ) : (
+                <ul className="divide-y divide-gray-200">
+                  {attachments.map(attachment => (
+                    <li key={attachment.id} className="flex items-center justify-between py-2 text-sm">
+                      <div>
+                        <p className="font-medium text-gray-900">{attachment.filename}</p>
+                        <p className="text-gray-500">
+                          <span className="capitalize">{attachment.kind}</span> · {(attachment.sizeBytes / 1024).toFixed(0)} KB · {formatDate(attachment.createdAt)}
+                        </p>
+                      </div>
+                      <div className="flex items-center space-x-2">
+                        {attachment.mimeType === 'application/pdf' && (
+                          <button onClick={() => handleAnalyze(attachment)} className="text-gray-600 hover:text-gray-800" title="Check pages and size">
+                            <Ruler className="h-4 w-4" />
+                          </button>
+                        )}
+                        <button onClick={() => handleDownload(attachment)} className="text-blue-600 hover:text-blue-800" title="Download">
+                          <Download className="h-4 w-4" />
+                        </button>
+                        <button onClick={() => handleDeleteAttachment(attachment)} className="text-red-600 hover:text-red-800" title="Delete">
+                          <Trash2 className="h-4 w-4" />
+                        </button>
+                      </div>
+                    </li>
+                  ))}
+                </ul>
+              )}
+              {analysis && (
+                <div className="mt-4 rounded-md bg-gray-50 p-4 text-sm">
+                  <p className="text-gray-900">
+                    {analysis.pageCount} pages · trim {analysis.trimBox.widthMm} × {analysis.trimBox.heightMm} mm ·{' '}
+                    {analysis.hasBleed ? `${analysis.bleedMm} mm bleed` : 'no bleed'}
+                  </p>
+                  {analysis.warnings.length > 0 && (
+                    <ul className="mt-2 list-disc pl-5 text-amber-700">
+                      {analysis.warnings.map(warning => (
+                        <li key={warning.kind}>{warning.message}</li>
+                      ))}
+                    </ul>
+                  )}
+                  <button onClick={handleApplyAnalysis} className="mt-3 text-blue-600 hover:text-blue-800">
+                    Use {analysis.suggestedPages} pages, {analysis.suggestedPaperSize}
+                  </button>
+                </div>
+              )}
+            </div>
+
+            {/* Comments */}
+            <div className="bg-white shadow rounded-lg p-6">
+              <h2 className="text-lg font-medium text-gray-900 mb-4">Comments</h2>
+              {comments.length === 0 ? (
+                <p className="text-sm text-gray-500">No comments yet.</p>
+              ) : (
+                <ul className="space-y-4">
+                  {comments.map(comment => (
+                    <li key={comment.id} className="text-sm">
+                      <div className="flex items-center justify-between">
+                        <p className="text-gray-500">
+                          <span className="font-medium text-gray-900">{comment.authorName ?? 'Unknown user'}</span>
+                          {' · '}{formatDate(comment.createdAt)}
+                          {comment.edited && ' · edited'}
+                          {comment.customerVisible && (
+                            <span className="ml-2 inline-flex px-2 rounded-full text-xs bg-green-100 text-green-800">Customer visible</span>
+                          )}
+                        </p>
+                        <div className="flex items-center space-x-2">
+                          {user?.id === comment.authorId && (
+                            <button onClick={() => handleEditComment(comment)} className="text-gray-600 hover:text-gray-800" title="Edit">
+                              <Edit className="h-4 w-4" />
+                            </button>
+                          )}
+                          {(user?.id === comment.authorId || user?.role === 'Admin' || user?.role === 'Manager') && (
+                            <button onClick={() => handleDeleteComment(comment)} className="text-red-600 hover:text-red-800" title="Delete">
+                              <Trash2 className="h-4 w-4" />
+                            </button>
+                          )}
+                        </div>
+                      </div>
+                      <p className="mt-1 whitespace-pre-wrap text-gray-900">{comment.body}</p>
+                    </li>
+                  ))}
+                </ul>
+              )}
+              <div className="mt-4 space-y-2">
+                <textarea
+                  value={commentBody}
+                  onChange={(e) => setCommentBody(e.target.value)}
+                  rows={3}
+                  placeholder="Add a note. Mention someone with @name."
+                  className="w-full border border-gray-300 rounded-md px-3 py-2 text-sm"
+                />
+                <div className="flex items-center justify-between">
+                  <label className="flex items-center text-sm text-gray-700">
+                    <input
+                      type="checkbox"
+                      checked={customerVisible}
+                      onChange={(e) => setCustomerVisible(e.target.checked)}
+                      className="mr-2"
+                    />
+                    Visible to customer
+                  </label>
+                  <button
+                    onClick={handlePostComment}
+                    disabled={!commentBody.trim()}
+                    className="px-3 py-1 rounded-md text-sm font-medium text-white bg-blue-600 hover:bg-blue-700 disabled:opacity-50"
+                  >
+                    Post
+                  </button>
+                </div>
               </div>
             </div>
           </div>
@@ -281,6 +715,21 @@ export default function JobDetailPage() {
                   <span className="text-sm text-gray-500">Per Unit:</span>
                   <span className="font-medium">{formatCurrency(parseFloat(job.unitCost.toString()), 'USD')}</span>
                 </div>
+                {job.tax && (
+                  <>
+                    {job.tax.lines.map(line => (
+                      <div key={line.name} className="flex justify-between items-center mt-2">
+                        <span className="text-sm text-gray-600">{line.name} ({(line.rate * 100).toFixed(2)}%):</span>
+                        <span className="font-medium">{formatCurrency(parseFloat(line.amount.toString()), 'USD')}</span>
+                      </div>
+                    ))}
+                    <div className="flex justify-between items-center mt-2 font-semibold">
+                      <span>Total incl. Tax:</span>
+                      <span>{formatCurrency(parseFloat(job.tax.gross.toString()), 'USD')}</span>
+                    </div>
+                    {job.tax.note && <p className="text-xs text-gray-500 mt-2">{job.tax.note}</p>}
+                  </>
+                )}
               </div>
             </div>
           </div>
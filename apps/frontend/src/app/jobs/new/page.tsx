@@ -3,8 +3,8 @@
 import { useState, useEffect } from 'react';
 import { useRouter } from 'next/navigation';
 import { useAuth } from '@/contexts/AuthContext';
-import { jobsApi, costingApi, currencyApi } from '@/lib/api';
-import type { JobType, JobSpecifications, CostCalculationResponse, Currency } from '@/types';
+import { jobsApi, costingApi, currencyApi, templatesApi } from '@/lib/api';
+import type { JobType, JobSpecifications, JobTemplate, CostCalculationResponse, Currency, LaminationFilm, PrintProcess } from '@/types';
 import { ArrowLeft, Calculator, Save, DollarSign, Zap } from 'lucide-react';
 import { formatCurrency, CURRENCY_INFO, getDefaultCurrency } from '@/lib/currency';
 import Link from 'next/link';
@@ -29,12 +29,16 @@ export default function NewJobPage() {
       },
       pages: 1,
       binding: '',
-      lamination: '',
+      lamination: null,
+      process: null,
       finishing: [],
       specialRequirements: '',
     } as JobSpecifications,
   });
 
+  const [templates, setTemplates] = useState<JobTemplate[]>([]);
+  const [promoCode, setPromoCode] = useState('');
+  const [requestedDeliveryDate, setRequestedDeliveryDate] = useState('');
   const [costCalculation, setCostCalculation] = useState<CostCalculationResponse | null>(null);
   const [loading, setLoading] = useState(false);
   const [calculating, setCalculating] = useState(false);
@@ -56,8 +60,9 @@ export default function NewJobPage() {
   const paperSizes = ['A4', 'A3', 'A2', 'A1', 'Letter', 'Legal', 'Tabloid'];
   const paperTypes = ['80gsm_offset', '120gsm_coated', '160gsm_coated', '250gsm_card'];
   const bindingOptions = ['', 'saddle_stitch', 'perfect_bind', 'spiral_bind', 'wire_bind'];
-  const laminationOptions = ['', 'gloss', 'matte', 'soft_touch'];
-  const finishingOptions = ['cutting', 'folding', 'scoring', 'perforation', 'embossing'];
+  const laminationOptions = ['', 'gloss', 'matte', 'soft_touch', 'anti_scuff'];
+  const processOptions = ['', 'offset', 'digital'];
+  const finishingOptions = ['cutting', 'folding_half', 'creasing', 'perforating', 'embossing'];
 
   useEffect(() => {
     const loadSupportedCurrencies = async () => {
@@ -72,6 +77,24 @@ export default function NewJobPage() {
     loadSupportedCurrencies();
   }, []);
 
+  useEffect(() => {
+    templatesApi.list()
+      .then(setTemplates)
+      .catch((error) => console.error('Failed to load templates:', error));
+  }, []);
+
+  const handleTemplateChange = (templateId: string) => {
+    const template = templates.find(t => t.id === templateId);
+    if (!template) return;
+    setFormData(prev => ({
+      ...prev,
+      jobType: template.jobType,
+      quantity: template.quantity,
+      specifications: template.specifications,
+    }));
+    setCostCalculation(null);
+  };
+
   const handleInputChange = (field: string, value: string | number | boolean) => {
     if (field.startsWith('specifications.')) {
       const specField = field.replace('specifications.', '');
@@ -107,6 +130,28 @@ export default function NewJobPage() {
     setCostCalculation(null);
   };
 
+  const handleLaminationChange = (film: string, sides: 'single' | 'double') => {
+    setFormData(prev => ({
+      ...prev,
+      specifications: {
+        ...prev.specifications,
+        lamination: film ? { film: film as LaminationFilm, sides } : null,
+      },
+    }));
+    setCostCalculation(null);
+  };
+
+  const handleProcessChange = (process: string) => {
+    setFormData(prev => ({
+      ...prev,
+      specifications: {
+        ...prev.specifications,
+        process: process ? (process as PrintProcess) : null,
+      },
+    }));
+    setCostCalculation(null);
+  };
+
   const handleFinishingChange = (finishing: string, checked: boolean) => {
     setFormData(prev => ({
       ...prev,
@@ -129,6 +174,8 @@ export default function NewJobPage() {
         quantity: formData.quantity,
         specifications: formData.specifications,
         currency: selectedCurrency,
+        promoCode: promoCode || undefined,
+        requestedDeliveryDate: requestedDeliveryDate || undefined,
       });
       setCostCalculation(result);
     } catch (error: unknown) {
@@ -206,6 +253,8 @@ export default function NewJobPage() {
         jobType: formData.jobType,
         quantity: formData.quantity,
         specifications: formData.specifications,
+        promoCode: promoCode || undefined,
+        requestedDeliveryDate: requestedDeliveryDate || undefined,
       });
       
       // Show success message briefly before redirecting
@@ -258,6 +307,26 @@ export default function NewJobPage() {
               <div className="bg-white shadow rounded-lg p-6">
                 <h2 className="text-lg font-medium text-gray-900 mb-4">Job Information</h2>
                 
+                {templates.length > 0 && (
+                  <div className="mb-4">
+                    <label className="block text-sm font-medium text-gray-700 mb-2">
+                      Start from Template
+                    </label>
+                    <select
+                      className="w-full px-3 py-2 border border-gray-300 rounded-md focus:outline-none focus:ring-2 focus:ring-blue-500 text-gray-900 bg-white"
+                      defaultValue=""
+                      onChange={(e) => handleTemplateChange(e.target.value)}
+                    >
+                      <option value="">None</option>
+                      {templates.map(template => (
+                        <option key={template.id} value={template.id}>
+                          {template.name}{template.scope === 'shared' ? ' (shared)' : ''}
+                        </option>
+                      ))}
+                    </select>
+                  </div>
+                )}
+
                 <div className="grid grid-cols-1 md:grid-cols-2 gap-4">
                   <div>
                     <label className="block text-sm font-medium text-gray-700 mb-2">
@@ -461,16 +530,67 @@ export default function NewJobPage() {
                     </label>
                     <select
                       className="w-full px-3 py-2 border border-gray-300 rounded-md focus:outline-none focus:ring-2 focus:ring-blue-500 text-gray-900 bg-white"
-                      value={formData.specifications.lamination || ''}
-                      onChange={(e) => handleInputChange('specifications.lamination', e.target.value)}
+                      value={formData.specifications.lamination?.film || ''}
+                      onChange={(e) => handleLaminationChange(e.target.value, formData.specifications.lamination?.sides || 'single')}
                     >
                       {laminationOptions.map(option => (
                         <option key={option} value={option}>
-                          {option ? option.charAt(0).toUpperCase() + option.slice(1) : 'None'}
+                          {option ? option.charAt(0).toUpperCase() + option.slice(1).replace('_', ' ') : 'None'}
+                        </option>
+                      ))}
+                    </select>
+                    {formData.specifications.lamination && (
+                      <select
+                        className="mt-2 w-full px-3 py-2 border border-gray-300 rounded-md focus:outline-none focus:ring-2 focus:ring-blue-500 text-gray-900 bg-white"
+                        value={formData.specifications.lamination.sides}
+                        onChange={(e) => handleLaminationChange(formData.specifications.lamination?.film || '', e.target.value as 'single' | 'double')}
+                      >
+                        <option value="single">One side</option>
+                        <option value="double">Both sides</option>
+                      </select>
+                    )}
+                  </div>
+
+                  <div>
+                    <label className="block text-sm font-medium text-gray-700 mb-2">
+                      Print Process
+                    </label>
+                    <select
+                      className="w-full px-3 py-2 border border-gray-300 rounded-md focus:outline-none focus:ring-2 focus:ring-blue-500 text-gray-900 bg-white"
+                      value={formData.specifications.process || ''}
+                      onChange={(e) => handleProcessChange(e.target.value)}
+                    >
+                      {processOptions.map(option => (
+                        <option key={option} value={option}>
+                          {option ? option.charAt(0).toUpperCase() + option.slice(1) : 'Cheapest (auto)'}
                         </option>
                       ))}
                     </select>
                   </div>
+
+                  <div>
+                    <label className="block text-sm font-medium text-gray-700 mb-2">
+                      Promo Code
+                    </label>
+                    <input
+                      type="text"
+                      className="w-full px-3 py-2 border border-gray-300 rounded-md focus:outline-none focus:ring-2 focus:ring-blue-500 text-gray-900 bg-white"
+                      value={promoCode}
+                      onChange={(e) => setPromoCode(e.target.value)}
+                    />
+                  </div>
+
+                  <div>
+                    <label className="block text-sm font-medium text-gray-700 mb-2">
+                      Requested Delivery Date
+                    </label>
+                    <input
+                      type="date"
+                      className="w-full px-3 py-2 border border-gray-300 rounded-md focus:outline-none focus:ring-2 focus:ring-blue-500 text-gray-900 bg-white"
+                      value={requestedDeliveryDate}
+                      onChange={(e) => setRequestedDeliveryDate(e.target.value)}
+                    />
+                  </div>
                 </div>
 
                 <div className="mt-4">
@@ -487,7 +607,7 @@ export default function NewJobPage() {
                           onChange={(e) => handleFinishingChange(option, e.target.checked)}
                         />
                         <span className="ml-2 text-sm text-gray-700 capitalize">
-                          {option}
+                          {option.replace('_', ' ')}
                         </span>
                       </label>
                     ))}
@@ -586,11 +706,56 @@ export default function NewJobPage() {
                     </div>
                   </div>
                   
+                  {costCalculation.pricing && (
+                    <div className="space-y-2 border-b border-gray-200 pb-4 mb-4">
+                      <div className="flex justify-between items-center">
+                        <span className="text-sm text-gray-600">Full Cost:</span>
+                        <span className="font-medium">{formatCurrency(costCalculation.pricing.fullCost, costCalculation.currency || selectedCurrency)}</span>
+                      </div>
+                      <div className="flex justify-between items-center">
+                        <span className="text-sm text-gray-600">Profit ({(costCalculation.pricing.marginPercentage * 100).toFixed(1)}% margin):</span>
+                        <span className="font-medium">{formatCurrency(costCalculation.pricing.profit, costCalculation.currency || selectedCurrency)}</span>
+                      </div>
+                      {costCalculation.pricing.adjustments.length > 0 && (
+                        <div className="flex justify-between items-center">
+                          <span className="text-sm text-gray-600">Selling Price:</span>
+                          <span className="font-medium">{formatCurrency(costCalculation.pricing.sellingPrice, costCalculation.currency || selectedCurrency)}</span>
+                        </div>
+                      )}
+                      {costCalculation.pricing.adjustments.map(adjustment => (
+                        <div key={adjustment.kind} className="flex justify-between items-center">
+                          <span className="text-sm text-gray-600">{adjustment.description}:</span>
+                          <span className={`font-medium ${adjustment.amount < 0 ? 'text-green-600' : ''}`}>{formatCurrency(adjustment.amount, costCalculation.currency || selectedCurrency)}</span>
+                        </div>
+                      ))}
+                      {costCalculation.pricing.requiresApproval && (
+                        <p className="text-xs text-amber-600">Margin is below the minimum; a manager must approve this quote.</p>
+                      )}
+                    </div>
+                  )}
+
                   <div className="space-y-2">
                     <div className="flex justify-between items-center text-lg font-semibold">
-                      <span>Total Cost:</span>
+                      <span>{costCalculation.pricing?.adjustments.length ? 'Quoted Price:' : 'Selling Price:'}</span>
                       <span className="text-blue-600">{formatCurrency(costCalculation.totalCost, costCalculation.currency || selectedCurrency)}</span>
                     </div>
+                    {costCalculation.tax && (
+                      <>
+                        {costCalculation.tax.lines.map(line => (
+                          <div key={line.name} className="flex justify-between items-center">
+                            <span className="text-sm text-gray-600">{line.name} ({(line.rate * 100).toFixed(2)}%):</span>
+                            <span className="font-medium">{formatCurrency(line.amount, costCalculation.currency || selectedCurrency)}</span>
+                          </div>
+                        ))}
+                        <div className="flex justify-between items-center font-semibold">
+                          <span>Total incl. Tax:</span>
+                          <span>{formatCurrency(costCalculation.tax.gross, costCalculation.currency || selectedCurrency)}</span>
+                        </div>
+                        {costCalculation.tax.note && (
+                          <p className="text-xs text-gray-500">{costCalculation.tax.note}</p>
+                        )}
+                      </>
+                    )}
                     <div className="flex justify-between items-center">
                       <span className="text-sm text-gray-500">Per Unit:</span>
                       <span className="font-medium">{formatCurrency(costCalculation.unitCost, costCalculation.currency || selectedCurrency)}</span>
@@ -606,6 +771,27 @@ export default function NewJobPage() {
                       <span className="font-medium">{costCalculation.estimatedDeliveryDays} days</span>
                     </div>
                   </div>
+
+                  {costCalculation.alternatives.length > 1 && (
+                    <div className="mt-4 pt-4 border-t border-gray-200 space-y-1">
+                      <h3 className="text-sm font-medium text-gray-700 mb-2">Process Comparison</h3>
+                      {costCalculation.alternatives.map(alternative => (
+                        <div key={alternative.process} className="flex justify-between items-center">
+                          <span className={`text-sm capitalize ${alternative.selected ? 'font-semibold text-blue-600' : 'text-gray-600'}`}>
+                            {alternative.process.replace('_', ' ')}
+                          </span>
+                          <span className="text-sm">
+                            {formatCurrency(alternative.totalCost, costCalculation.currency || selectedCurrency)} · {alternative.estimatedDeliveryDays} days
+                          </span>
+                        </div>
+                      ))}
+                      {costCalculation.crossoverQuantity && (
+                        <p className="text-xs text-gray-500 mt-2">
+                          Offset is cheaper from {costCalculation.crossoverQuantity} copies.
+                        </p>
+                      )}
+                    </div>
+                  )}
                 </div>
               )}
             </div>
@@ -0,0 +1,230 @@
+'use client';
+
+import { useState, useEffect } from 'react';
+import { scheduleApi } from '@/lib/api';
+import type { Holiday, ProductionSchedule } from '@/types';
+import { ArrowLeft, CalendarDays, Trash2 } from 'lucide-react';
+import Link from 'next/link';
+
+export default function SchedulePage() {
+  const [schedule, setSchedule] = useState<ProductionSchedule | null>(null);
+  const [holidays, setHolidays] = useState<Holiday[]>([]);
+  const [holidayDate, setHolidayDate] = useState('');
+  const [holidayName, setHolidayName] = useState('');
+  const [loading, setLoading] = useState(true);
+  const [error, setError] = useState('');
+
+  const fetchSchedule = async () => {
+    try {
+      const [plan, closed] = await Promise.all([scheduleApi.get(), scheduleApi.holidays()]);
+      setSchedule(plan);
+      setHolidays(closed);
+    } catch (error) {
+      console.error('Failed to fetch schedule:', error);
+      setError('Failed to load the schedule');
+    } finally {
+      setLoading(false);
+    }
+  };
+
+  useEffect(() => {
+    fetchSchedule();
+  }, []);
+
+  const handleAddHoliday = async () => {
+    if (!holidayDate || !holidayName.trim()) return;
+
+    try {
+      await scheduleApi.addHoliday({ date: holidayDate, name: holidayName });
+      setHolidayDate('');
+      setHolidayName('');
+      await fetchSchedule();
+    } catch (error) {
+      console.error('Failed to add holiday:', error);
+      setError('Failed to add the holiday. Only managers can change holidays.');
+    }
+  };
+
+  const handleDeleteHoliday = async (date: string) => {
+    try {
+      await scheduleApi.deleteHoliday(date);
+      await fetchSchedule();
+    } catch (error) {
+      console.error('Failed to delete holiday:', error);
+      setError('Failed to delete the holiday. Only managers can change holidays.');
+    }
+  };
+
+  // Calendar dates carry no time zone; parse them as local days
+  const formatDay = (day: string) => {
+    return new Date(`${day}T00:00:00`).toLocaleDateString('en-US', {
+      weekday: 'short',
+      month: 'short',
+      day: 'numeric',
+    });
+  };
+
+  if (loading) {
+    return (
+      <div className="min-h-screen bg-gray-50 flex items-center justify-center">
+        <div className="text-center">
+          <div className="animate-spin rounded-full h-12 w-12 border-b-2 border-blue-600 mx-auto"></div>
+          <p className="mt-4 text-gray-600">Loading schedule...</p>
+        </div>
+      </div>
+    );
+  }
+
+  const machineNames = schedule?.days[0]?.machines.map(machine => machine.machineName) ?? [];
+
+  return (
+    <div className="min-h-screen bg-gray-50">
+      <div className="bg-white shadow">
+        <div className="max-w-7xl mx-auto px-4 sm:px-6 lg:px-8">
+          <div className="flex items-center py-6">
+            <Link
+              href="/"
+              className="mr-4 inline-flex items-center px-3 py-2 border border-gray-300 rounded-md text-sm font-medium text-gray-700 bg-white hover:bg-gray-50"
+            >
+              <ArrowLeft className="h-4 w-4 mr-2" />
+              Back to Dashboard
+            </Link>
+            <CalendarDays className="h-8 w-8 text-blue-600 mr-3" />
+            <div>
+              <h1 className="text-2xl font-bold text-gray-900">Production Schedule</h1>
+              <p className="text-sm text-gray-500">Approved jobs placed on machine capacity</p>
+            </div>
+          </div>
+        </div>
+      </div>
+
+      <div className="max-w-7xl mx-auto py-6 sm:px-6 lg:px-8 space-y-6">
+        {error && (
+          <div className="bg-red-50 border border-red-200 rounded-md p-4">
+            <p className="text-red-700 text-sm">{error}</p>
+          </div>
+        )}
+
+        {/* Jobs */}
+        <div className="bg-white shadow rounded-lg p-6">
+          <h2 className="text-lg font-medium text-gray-900 mb-4">Jobs</h2>
+          {schedule?.jobs.length ? (
+            <table className="min-w-full text-sm">
+              <thead>
+                <tr className="text-left text-gray-500">
+                  <th className="py-2">Job</th>
+                  <th className="py-2">Due</th>
+                  <th className="py-2">Start</th>
+                  <th className="py-2">Completion</th>
+                </tr>
+              </thead>
+              <tbody>
+                {schedule.jobs.map(job => {
+                  const due = job.promisedDate ?? job.requestedDate;
+                  return (
+                    <tr key={job.jobId} className="border-t border-gray-100">
+                      <td className="py-2">
+                        <Link href={`/jobs/${job.jobId}`} className="text-blue-600 hover:text-blue-800">
+                          {job.title}
+                        </Link>
+                      </td>
+                      <td className="py-2 text-gray-700">{due ? formatDay(due) : '—'}</td>
+                      <td className="py-2 text-gray-700">{formatDay(job.startDate)}</td>
+                      <td className="py-2 text-gray-700">
+                        {job.completionDate ? formatDay(job.completionDate) : 'Beyond the planning horizon'}
+                        {job.late && (
+                          <span className="ml-2 inline-flex items-center px-2 py-0.5 rounded-full text-xs font-medium bg-red-100 text-red-800">
+                            {job.daysLate > 0 ? `${job.daysLate} days late` : 'Late'}
+                          </span>
+                        )}
+                      </td>
+                    </tr>
+                  );
+                })}
+              </tbody>
+            </table>
+          ) : (
+            <p className="text-sm text-gray-500">No approved jobs to schedule.</p>
+          )}
+        </div>
+
+        {/* Calendar */}
+        <div className="bg-white shadow rounded-lg p-6 overflow-x-auto">
+          <h2 className="text-lg font-medium text-gray-900 mb-4">Machines</h2>
+          <table className="min-w-full text-xs">
+            <thead>
+              <tr className="text-left text-gray-500">
+                <th className="py-2 pr-4">Day</th>
+                {machineNames.map(name => (
+                  <th key={name} className="py-2 pr-4">{name}</th>
+                ))}
+              </tr>
+            </thead>
+            <tbody>
+              {schedule?.days.map(day => (
+                <tr key={day.date} className={`border-t border-gray-100 align-top ${day.holiday ? 'bg-gray-50' : ''}`}>
+                  <td className="py-2 pr-4 whitespace-nowrap text-gray-700">
+                    {formatDay(day.date)}
+                    {day.holiday && <p className="text-gray-400">{day.holiday}</p>}
+                  </td>
+                  {day.machines.map(machine => (
+                    <td key={machine.machineName} className="py-2 pr-4">
+                      {machine.capacityHours === 0 ? (
+                        <span className="text-gray-300">Closed</span>
+                      ) : (
+                        <>
+                          <p className="text-gray-500">{machine.bookedHours} / {machine.capacityHours} h</p>
+                          {machine.runs.map(run => (
+                            <p key={run.jobId} className="text-gray-900">
+                              {run.title} ({run.hours} h)
+                            </p>
+                          ))}
+                        </>
+                      )}
+                    </td>
+                  ))}
+                </tr>
+              ))}
+            </tbody>
+          </table>
+        </div>
+
+        {/* Holidays */}
+        <div className="bg-white shadow rounded-lg p-6">
+          <h2 className="text-lg font-medium text-gray-900 mb-4">Holidays</h2>
+          <ul className="space-y-2 mb-4">
+            {holidays.map(holiday => (
+              <li key={holiday.date} className="flex justify-between text-sm">
+                <span className="text-gray-900">{formatDay(holiday.date)} — {holiday.name}</span>
+                <button onClick={() => handleDeleteHoliday(holiday.date)} className="text-red-600 hover:text-red-800">
+                  <Trash2 className="h-4 w-4" />
+                </button>
+              </li>
+            ))}
+          </ul>
+          <div className="flex space-x-2">
+            <input
+              type="date"
+              value={holidayDate}
+              onChange={e => setHolidayDate(e.target.value)}
+              className="border-gray-300 rounded-md text-sm"
+            />
+            <input
+              type="text"
+              value={holidayName}
+              onChange={e => setHolidayName(e.target.value)}
+              placeholder="Name"
+              className="border-gray-300 rounded-md text-sm"
+            />
+            <button
+              onClick={handleAddHoliday}
+              className="px-4 py-2 bg-blue-600 text-white rounded-md text-sm hover:bg-blue-700"
+            >
+              Add Holiday
+            </button>
+          </div>
+        </div>
+      </div>
+    </div>
+  );
+}
@@ -3,7 +3,7 @@
 import { useState, useEffect } from 'react';
 import { useAuth } from '@/contexts/AuthContext';
 import { settingsApi } from '@/lib/api';
-import type { CostParameters, BrandingSettings } from '@/types';
+import type { CostParameters, BrandingSettings, PricingParameters, ProfitMode, TaxRate, TaxSettings } from '@/types';
 import { Settings, Save, ArrowLeft } from 'lucide-react';
 import Link from 'next/link';
 
@@ -11,6 +11,7 @@ export default function SettingsPage() {
   const { user } = useAuth();
   const [costParameters, setCostParameters] = useState<CostParameters | null>(null);
   const [branding, setBranding] = useState<BrandingSettings | null>(null);
+  const [taxSettings, setTaxSettings] = useState<TaxSettings | null>(null);
   const [loading, setLoading] = useState(true);
   const [saving, setSaving] = useState(false);
   const [error, setError] = useState('');
@@ -19,12 +20,14 @@ export default function SettingsPage() {
   useEffect(() => {
     const fetchSettings = async () => {
       try {
-        const [costParams, brandingSettings] = await Promise.all([
+        const [costParams, brandingSettings, tax] = await Promise.all([
           settingsApi.getCostParameters(),
           settingsApi.getBranding(),
+          settingsApi.getTaxSettings(),
         ]);
         setCostParameters(costParams);
         setBranding(brandingSettings);
+        setTaxSettings(tax);
       } catch (error) {
         console.error('Failed to fetch settings:', error);
         setError('Failed to load settings');
@@ -45,6 +48,18 @@ export default function SettingsPage() {
     }
   };
 
+  const handlePricingChange = (field: keyof PricingParameters, value: string | number) => {
+    if (costParameters) {
+      setCostParameters({
+        ...costParameters,
+        pricing: {
+          ...costParameters.pricing,
+          [field]: value,
+        },
+      });
+    }
+  };
+
   const handleBrandingChange = (field: keyof BrandingSettings, value: string) => {
     if (branding) {
       setBranding({
@@ -54,6 +69,24 @@ export default function SettingsPage() {
     }
   };
 
+  const handleTaxChange = (field: keyof TaxSettings, value: string) => {
+    if (taxSettings) {
+      setTaxSettings({
+        ...taxSettings,
+        [field]: value,
+      });
+    }
+  };
+
+  const handleTaxRateChange = (index: number, field: keyof TaxRate, value: string | number) => {
+    if (taxSettings) {
+      setTaxSettings({
+        ...taxSettings,
+        rates: taxSettings.rates.map((rate, i) => (i === index ? { ...rate, [field]: value } : rate)),
+      });
+    }
+  };
+
   const saveCostParameters = async () => {
     if (!costParameters) return;
 
@@ -67,7 +100,26 @@ export default function SettingsPage() {
       setSuccess('Cost parameters saved successfully');
     } catch (error) {
       console.error('Failed to save cost parameters:', error);
-      setError('Failed to save cost parameters');
+      setError('Failed to save cost parameters. Only managers can change them.');
+    } finally {
+      setSaving(false);
+    }
+  };
+
+  const saveTaxSettings = async () => {
+    if (!taxSettings) return;
+
+    setSaving(true);
+    setError('');
+    setSuccess('');
+
+    try {
+      const updated = await settingsApi.updateTaxSettings(taxSettings);
+      setTaxSettings(updated);
+      setSuccess('Tax settings saved successfully');
+    } catch (error) {
+      console.error('Failed to save tax settings:', error);
+      setError('Failed to save tax settings. Only managers can change them.');
     } finally {
       setSaving(false);
     }
@@ -177,15 +229,15 @@ export default function SettingsPage() {
 
                 <div>
                   <label className="block text-sm font-medium text-gray-700 mb-2">
-                    Plate Cost Per Job ($)
+                    Cost Per Plate ($)
                   </label>
                   <input
                     type="number"
                     step="0.01"
                     min="0"
                     className="w-full px-3 py-2 border border-gray-300 rounded-md focus:outline-none focus:ring-2 focus:ring-blue-500 text-gray-900 bg-white"
-                    value={costParameters.plateCostPerJob}
-                    onChange={(e) => handleCostParametersChange('plateCostPerJob', parseFloat(e.target.value) || 0)}
+                    value={costParameters.plateCost}
+                    onChange={(e) => handleCostParametersChange('plateCost', parseFloat(e.target.value) || 0)}
                   />
                 </div>
 
@@ -234,7 +286,7 @@ export default function SettingsPage() {
 
                 <div>
                   <label className="block text-sm font-medium text-gray-700 mb-2">
-                    Profit Margin Percentage (%)
+                    Default Profit Rate (%)
                   </label>
                   <input
                     type="number"
@@ -246,6 +298,134 @@ export default function SettingsPage() {
                     onChange={(e) => handleCostParametersChange('profitMarginPercentage', (parseFloat(e.target.value) || 0) / 100)}
                   />
                 </div>
+
+                <div>
+                  <label className="block text-sm font-medium text-gray-700 mb-2">
+                    Profit Applied As
+                  </label>
+                  <select
+                    className="w-full px-3 py-2 border border-gray-300 rounded-md focus:outline-none focus:ring-2 focus:ring-blue-500 text-gray-900 bg-white"
+                    value={costParameters.pricing.mode}
+                    onChange={(e) => handlePricingChange('mode', e.target.value as ProfitMode)}
+                  >
+                    <option value="markup">Markup on cost</option>
+                    <option value="margin">Margin on price</option>
+                  </select>
+                </div>
+
+                <div>
+                  <label className="block text-sm font-medium text-gray-700 mb-2">
+                    Minimum Margin Before Approval (%)
+                  </label>
+                  <input
+                    type="number"
+                    step="0.01"
+                    min="0"
+                    max="100"
+                    className="w-full px-3 py-2 border border-gray-300 rounded-md focus:outline-none focus:ring-2 focus:ring-blue-500 text-gray-900 bg-white"
+                    value={costParameters.pricing.minimumMarginPercentage * 100}
+                    onChange={(e) => handlePricingChange('minimumMarginPercentage', (parseFloat(e.target.value) || 0) / 100)}
+                  />
+                </div>
+              </div>
+            )}
+          </div>
+
+          {/* Tax Settings */}
+          <div className="bg-white shadow rounded-lg p-6">
+            <div className="flex justify-between items-center mb-6">
+              <h2 className="text-lg font-medium text-gray-900">Tax Settings</h2>
+              <button
+                onClick={saveTaxSettings}
+                disabled={saving}
+                className="inline-flex items-center px-4 py-2 border border-transparent rounded-md shadow-sm text-sm font-medium text-white bg-blue-600 hover:bg-blue-700 disabled:opacity-50"
+              >
+                <Save className="h-4 w-4 mr-2" />
+                {saving ? 'Saving...' : 'Save Tax Settings'}
+              </button>
+            </div>
+
+            {taxSettings && (
+              <div className="grid grid-cols-1 md:grid-cols-2 gap-6">
+                <div>
+                  <label className="block text-sm font-medium text-gray-700 mb-2">
+                    Company Country
+                  </label>
+                  <input
+                    type="text"
+                    maxLength={2}
+                    className="w-full px-3 py-2 border border-gray-300 rounded-md focus:outline-none focus:ring-2 focus:ring-blue-500 text-gray-900 bg-white"
+                    value={taxSettings.companyCountry}
+                    onChange={(e) => handleTaxChange('companyCountry', e.target.value.toUpperCase())}
+                    placeholder="CM"
+                  />
+                </div>
+
+                <div>
+                  <label className="block text-sm font-medium text-gray-700 mb-2">
+                    Company Tax ID
+                  </label>
+                  <input
+                    type="text"
+                    className="w-full px-3 py-2 border border-gray-300 rounded-md focus:outline-none focus:ring-2 focus:ring-blue-500 text-gray-900 bg-white"
+                    value={taxSettings.companyTaxId || ''}
+                    onChange={(e) => handleTaxChange('companyTaxId', e.target.value)}
+                  />
+                </div>
+
+                {taxSettings.rates.map((rate, index) => (
+                  <div key={index} className="md:col-span-2 grid grid-cols-2 gap-6">
+                    <div>
+                      <label className="block text-sm font-medium text-gray-700 mb-2">
+                        Tax Name
+                      </label>
+                      <input
+                        type="text"
+                        className="w-full px-3 py-2 border border-gray-300 rounded-md focus:outline-none focus:ring-2 focus:ring-blue-500 text-gray-900 bg-white"
+                        value={rate.name}
+                        onChange={(e) => handleTaxRateChange(index, 'name', e.target.value)}
+                      />
+                    </div>
+                    <div>
+                      <label className="block text-sm font-medium text-gray-700 mb-2">
+                        Rate (%)
+                      </label>
+                      <input
+                        type="number"
+                        step="0.01"
+                        min="0"
+                        max="100"
+                        className="w-full px-3 py-2 border border-gray-300 rounded-md focus:outline-none focus:ring-2 focus:ring-blue-500 text-gray-900 bg-white"
+                        value={rate.rate * 100}
+                        onChange={(e) => handleTaxRateChange(index, 'rate', (parseFloat(e.target.value) || 0) / 100)}
+                      />
+                    </div>
+                  </div>
+                ))}
+
+                <div>
+                  <label className="block text-sm font-medium text-gray-700 mb-2">
+                    Reverse Charge Note
+                  </label>
+                  <input
+                    type="text"
+                    className="w-full px-3 py-2 border border-gray-300 rounded-md focus:outline-none focus:ring-2 focus:ring-blue-500 text-gray-900 bg-white"
+                    value={taxSettings.reverseChargeNote}
+                    onChange={(e) => handleTaxChange('reverseChargeNote', e.target.value)}
+                  />
+                </div>
+
+                <div>
+                  <label className="block text-sm font-medium text-gray-700 mb-2">
+                    Exemption Note
+                  </label>
+                  <input
+                    type="text"
+                    className="w-full px-3 py-2 border border-gray-300 rounded-md focus:outline-none focus:ring-2 focus:ring-blue-500 text-gray-900 bg-white"
+                    value={taxSettings.exemptionNote}
+                    onChange={(e) => handleTaxChange('exemptionNote', e.target.value)}
+                  />
+                </div>
               </div>
             )}
           </div>
@@ -4,7 +4,7 @@ import { useState, useEffect } from 'react';
 import { useAuth } from '@/contexts/AuthContext';
 import { jobsApi } from '@/lib/api';
 import type { Job, JobListResponse } from '@/types';
-import { Calculator, Settings, FileText, Plus } from 'lucide-react';
+import { Calculator, Settings, FileText, Plus, CalendarDays } from 'lucide-react';
 import Link from 'next/link';
 
 export default function Dashboard() {
@@ -93,6 +93,13 @@ export default function Dashboard() {
               <p className="text-gray-600">Welcome back, {user?.name || user?.email}</p>
             </div>
             <div className="flex items-center space-x-4">
+              <Link
+                href="/schedule"
+                className="text-gray-500 hover:text-gray-700 flex items-center space-x-2"
+              >
+                <CalendarDays className="h-5 w-5" />
+                <span>Schedule</span>
+              </Link>
               <Link
                 href="/settings"
                 className="text-gray-500 hover:text-gray-700 flex items-center space-x-2"
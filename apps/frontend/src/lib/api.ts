@@ -4,13 +4,35 @@ import type {
   LoginRequest, 
   LoginResponse, 
   Job, 
+  JobDetail,
+  JobComment,
+  CommentEdit,
   CreateJobRequest, 
+  UpdateJobRequest,
+  JobRevision,
+  RevisionDiff,
+  JobTemplate,
+  TemplateScope,
+  CreateJobTemplateRequest,
+  CreateJobFromTemplateRequest,
+  DuplicateJobRequest,
+  JobImportReport,
+  JobAttachment,
+  AttachmentKind,
+  ArtworkAnalysis,
+  Holiday,
+  JobSchedule,
+  ProductionSchedule,
   CostCalculationRequest, 
   CostCalculationResponse,
+  BudgetRequest,
+  BudgetResponse,
+  BatchCostResponse,
   JobListQuery,
   JobListResponse,
   CostParameters,
   BrandingSettings,
+  TaxSettings,
   Currency,
   ExchangeRates,
   CurrencyConversionResponse,
@@ -89,12 +111,12 @@ export const jobsApi = {
     return response.data;
   },
 
-  getById: async (id: string): Promise<Job> => {
+  getById: async (id: string): Promise<JobDetail> => {
     const response = await api.get(`/api/jobs/${id}`);
     return response.data;
   },
 
-  update: async (id: string, updates: Partial<CreateJobRequest>): Promise<Job> => {
+  update: async (id: string, updates: UpdateJobRequest): Promise<Job> => {
     const response = await api.put(`/api/jobs/${id}`, updates);
     return response.data;
   },
@@ -102,12 +124,177 @@ export const jobsApi = {
   delete: async (id: string): Promise<void> => {
     await api.delete(`/api/jobs/${id}`);
   },
+
+  listRevisions: async (id: string): Promise<JobRevision[]> => {
+    const response = await api.get(`/api/jobs/${id}/revisions`);
+    return response.data;
+  },
+
+  getRevision: async (id: string, revision: number): Promise<JobRevision> => {
+    const response = await api.get(`/api/jobs/${id}/revisions/${revision}`);
+    return response.data;
+  },
+
+  diffRevisions: async (id: string, from: number, to: number): Promise<RevisionDiff> => {
+    const response = await api.get(`/api/jobs/${id}/revisions/diff`, { params: { from, to } });
+    return response.data;
+  },
+
+  import: async (file: File, dryRun = false): Promise<JobImportReport> => {
+    const form = new FormData();
+    form.append('file', file);
+    const response = await api.post('/api/jobs/import', form, {
+      params: { dryRun },
+      headers: { 'Content-Type': 'multipart/form-data' },
+    });
+    return response.data;
+  },
+
+  duplicate: async (id: string, request: DuplicateJobRequest = {}): Promise<Job> => {
+    const response = await api.post(`/api/jobs/${id}/duplicate`, request);
+    return response.data;
+  },
+
+  reorderChain: async (id: string): Promise<Job[]> => {
+    const response = await api.get(`/api/jobs/${id}/reorders`);
+    return response.data;
+  },
+
+  saveAsTemplate: async (id: string, template: { name: string; description?: string; scope: TemplateScope }): Promise<JobTemplate> => {
+    const response = await api.post(`/api/jobs/${id}/template`, template);
+    return response.data;
+  },
+};
+
+// Job attachments API
+export const attachmentsApi = {
+  list: async (jobId: string): Promise<JobAttachment[]> => {
+    const response = await api.get(`/api/jobs/${jobId}/attachments`);
+    return response.data;
+  },
+
+  upload: async (jobId: string, file: File, kind: AttachmentKind = 'artwork'): Promise<JobAttachment> => {
+    const form = new FormData();
+    form.append('kind', kind);
+    form.append('file', file);
+    const response = await api.post(`/api/jobs/${jobId}/attachments`, form, {
+      headers: { 'Content-Type': 'multipart/form-data' },
+    });
+    return response.data;
+  },
+
+  download: async (jobId: string, attachmentId: string): Promise<Blob> => {
+    const response = await api.get(`/api/jobs/${jobId}/attachments/${attachmentId}`, {
+      responseType: 'blob',
+    });
+    return response.data;
+  },
+
+  analyze: async (jobId: string, attachmentId: string): Promise<ArtworkAnalysis> => {
+    const response = await api.get(`/api/jobs/${jobId}/attachments/${attachmentId}/analysis`);
+    return response.data;
+  },
+
+  delete: async (jobId: string, attachmentId: string): Promise<void> => {
+    await api.delete(`/api/jobs/${jobId}/attachments/${attachmentId}`);
+  },
+};
+
+// Job comments API
+export const commentsApi = {
+  list: async (jobId: string): Promise<JobComment[]> => {
+    const response = await api.get(`/api/jobs/${jobId}/comments`);
+    return response.data;
+  },
+
+  create: async (jobId: string, comment: { body: string; customerVisible?: boolean }): Promise<JobComment> => {
+    const response = await api.post(`/api/jobs/${jobId}/comments`, comment);
+    return response.data;
+  },
+
+  update: async (jobId: string, commentId: string, updates: { body?: string; customerVisible?: boolean }): Promise<JobComment> => {
+    const response = await api.put(`/api/jobs/${jobId}/comments/${commentId}`, updates);
+    return response.data;
+  },
+
+  history: async (jobId: string, commentId: string): Promise<CommentEdit[]> => {
+    const response = await api.get(`/api/jobs/${jobId}/comments/${commentId}/history`);
+    return response.data;
+  },
+
+  delete: async (jobId: string, commentId: string): Promise<void> => {
+    await api.delete(`/api/jobs/${jobId}/comments/${commentId}`);
+  },
+
+  mentions: async (): Promise<JobComment[]> => {
+    const response = await api.get('/api/comments/mentions');
+    return response.data;
+  },
+};
+
+// Production schedule API
+export const scheduleApi = {
+  get: async (query: { from?: string; days?: number } = {}): Promise<ProductionSchedule> => {
+    const response = await api.get('/api/schedule', { params: query });
+    return response.data;
+  },
+
+  forJob: async (jobId: string): Promise<JobSchedule> => {
+    const response = await api.get(`/api/jobs/${jobId}/schedule`);
+    return response.data;
+  },
+
+  holidays: async (): Promise<Holiday[]> => {
+    const response = await api.get('/api/schedule/holidays');
+    return response.data;
+  },
+
+  addHoliday: async (holiday: Holiday): Promise<Holiday> => {
+    const response = await api.post('/api/schedule/holidays', holiday);
+    return response.data;
+  },
+
+  deleteHoliday: async (date: string): Promise<void> => {
+    await api.delete(`/api/schedule/holidays/${date}`);
+  },
+};
+
+// Job templates API
+export const templatesApi = {
+  list: async (): Promise<JobTemplate[]> => {
+    const response = await api.get('/api/job-templates');
+    return response.data;
+  },
+
+  get: async (id: string): Promise<JobTemplate> => {
+    const response = await api.get(`/api/job-templates/${id}`);
+    return response.data;
+  },
+
+  create: async (template: CreateJobTemplateRequest): Promise<JobTemplate> => {
+    const response = await api.post('/api/job-templates', template);
+    return response.data;
+  },
+
+  update: async (id: string, updates: Partial<Omit<CreateJobTemplateRequest, 'jobType'>>): Promise<JobTemplate> => {
+    const response = await api.put(`/api/job-templates/${id}`, updates);
+    return response.data;
+  },
+
+  delete: async (id: string): Promise<void> => {
+    await api.delete(`/api/job-templates/${id}`);
+  },
+
+  createJob: async (id: string, request: CreateJobFromTemplateRequest): Promise<Job> => {
+    const response = await api.post(`/api/job-templates/${id}/jobs`, request);
+    return response.data;
+  },
 };
 
 // Costing API
 export const costingApi = {
-  calculate: async (request: CostCalculationRequest): Promise<CostCalculationResponse> => {
-    const response = await api.post('/api/cost/calculate', request);
+  calculate: async (request: CostCalculationRequest, explain = false): Promise<CostCalculationResponse> => {
+    const response = await api.post('/api/cost/calculate', request, { params: explain ? { explain: true } : undefined });
     return response.data;
   },
 
@@ -120,6 +307,16 @@ export const costingApi = {
     const response = await api.post('/api/cost/quick', request);
     return response.data;
   },
+
+  fitBudget: async (request: BudgetRequest): Promise<BudgetResponse> => {
+    const response = await api.post('/api/cost/budget', request);
+    return response.data;
+  },
+
+  calculateBatch: async (items: CostCalculationRequest[], explain = false): Promise<BatchCostResponse> => {
+    const response = await api.post('/api/cost/batch', { items }, { params: explain ? { explain: true } : undefined });
+    return response.data;
+  },
 };
 
 // Settings API
@@ -134,6 +331,16 @@ export const settingsApi = {
     return response.data;
   },
 
+  getTaxSettings: async (): Promise<TaxSettings> => {
+    const response = await api.get('/api/settings/tax');
+    return response.data;
+  },
+
+  updateTaxSettings: async (settings: Partial<TaxSettings>): Promise<TaxSettings> => {
+    const response = await api.put('/api/settings/tax', settings);
+    return response.data;
+  },
+
   getBranding: async (): Promise<BrandingSettings> => {
     const response = await api.get('/api/settings/branding');
     return response.data;
@@ -170,8 +377,16 @@ export const currencyApi = {
 
 // Export API
 export const exportApi = {
-  exportPdf: async (jobId: string): Promise<Blob> => {
+  exportPdf: async (jobId: string, includeComments = false): Promise<Blob> => {
     const response = await api.post(`/api/export/pdf/${jobId}`, {}, {
+      params: { includeComments },
+      responseType: 'blob',
+    });
+    return response.data;
+  },
+
+  exportTicket: async (jobId: string): Promise<Blob> => {
+    const response = await api.post(`/api/export/ticket/${jobId}`, {}, {
       responseType: 'blob',
     });
     return response.data;
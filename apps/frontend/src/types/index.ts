@@ -24,8 +24,19 @@ export interface ColorSpecification {
   backColors: number;
   spotColors: string[];
   isFullColor: boolean;
+  processCoverage?: number;
+  spotCoverage?: number;
 }
 
+export type LaminationFilm = 'gloss' | 'matte' | 'soft_touch' | 'anti_scuff';
+
+export interface LaminationSpecification {
+  film: LaminationFilm;
+  sides: 'single' | 'double';
+}
+
+export type PrintProcess = 'offset' | 'digital' | 'wide_format';
+
 export interface JobSpecifications {
   paperType: string;
   paperSize: string;
@@ -33,9 +44,30 @@ export interface JobSpecifications {
   colors: ColorSpecification;
   pages?: number;
   binding?: string;
-  lamination?: string;
+  lamination?: LaminationSpecification | null;
   finishing: string[];
   specialRequirements?: string;
+  process?: PrintProcess | null;
+}
+
+export interface TraceValue {
+  name: string;
+  value: number;
+  unit: string;
+}
+
+export interface CostExplanation {
+  formula: string;
+  inputs: TraceValue[];
+  intermediates: TraceValue[];
+}
+
+export interface CostLine {
+  category: 'paper' | 'plates' | 'ink' | 'labor' | 'binding' | 'finishing';
+  section?: string | null;
+  description: string;
+  amount: number;
+  explanation?: CostExplanation | null;
 }
 
 export interface CostBreakdown {
@@ -45,6 +77,15 @@ export interface CostBreakdown {
   bindingCost: number;
   finishingCost: number;
   overhead: number;
+  lines?: CostLine[];
+  productionHours?: number;
+  machineHours?: MachineHours[];
+}
+
+export interface MachineHours {
+  machineId: string;
+  machineName: string;
+  hours: number;
 }
 
 export interface Job {
@@ -57,16 +98,245 @@ export interface Job {
   costBreakdown: CostBreakdown;
   totalCost: number;
   unitCost: number;
+  pricing?: PriceSummary;
+  tax?: TaxSummary;
+  customerId?: string;
+  terms: QuoteTerms;
+  revision: number;
+  reorderOf?: string;
+  priceHeldUntil?: string;
+  promisedDate?: string;
+  marginApprovedBy?: string;
+  printProcess?: PrintProcess;
   status: JobStatus;
   createdAt: string;
   updatedAt: string;
 }
 
+export type TemplateScope = 'user' | 'shared';
+
+export interface JobTemplate {
+  id: string;
+  name: string;
+  description?: string;
+  jobType: JobType;
+  quantity: number;
+  specifications: JobSpecifications;
+  scope: TemplateScope;
+  ownerId: string;
+  createdAt: string;
+  updatedAt: string;
+}
+
+export interface CreateJobTemplateRequest {
+  name: string;
+  description?: string;
+  jobType: JobType;
+  quantity: number;
+  specifications: JobSpecifications;
+  scope: TemplateScope;
+}
+
+export interface CreateJobFromTemplateRequest {
+  title: string;
+  quantity?: number;
+  specifications?: JobSpecifications;
+  customerId?: string;
+  promoCode?: string;
+  requestedDeliveryDate?: string;
+}
+
+export interface ImportRowError {
+  row: number;
+  column?: string | null;
+  message: string;
+}
+
+export interface JobImportReport {
+  dryRun: boolean;
+  rows: number;
+  imported: {
+    row: number;
+    title: string;
+    jobType: JobType;
+    quantity: number;
+    totalCost: number;
+    jobId?: string | null;
+  }[];
+  errors: ImportRowError[];
+}
+
+export type AttachmentKind = 'artwork' | 'proof' | 'reference' | 'other';
+
+export interface JobAttachment {
+  id: string;
+  jobId: string;
+  kind: AttachmentKind;
+  filename: string;
+  mimeType: string;
+  sizeBytes: number;
+  checksum: string;
+  uploadedBy?: string | null;
+  createdAt: string;
+}
+
+export interface PageBox {
+  widthMm: number;
+  heightMm: number;
+}
+
+export interface ArtworkAnalysis {
+  attachmentId: string;
+  pageCount: number;
+  mediaBox: PageBox;
+  trimBox: PageBox;
+  bleedBox?: PageBox | null;
+  bleedMm: number;
+  hasBleed: boolean;
+  suggestedPages: number;
+  suggestedPaperSize: string;
+  warnings: {
+    kind: 'signature_multiple' | 'missing_bleed' | 'insufficient_bleed' | 'mixed_page_sizes' | 'size_mismatch';
+    message: string;
+  }[];
+}
+
+export interface JobComment {
+  id: string;
+  jobId: string;
+  authorId: string;
+  authorName?: string | null;
+  body: string;
+  customerVisible: boolean;
+  mentions: string[];
+  edited: boolean;
+  createdAt: string;
+  updatedAt: string;
+}
+
+export interface CommentEdit {
+  id: string;
+  commentId: string;
+  body: string;
+  customerVisible: boolean;
+  editedBy: string;
+  editedAt: string;
+}
+
+export interface JobDetail extends Job {
+  comments: JobComment[];
+}
+
+export interface DuplicateJobRequest {
+  title?: string;
+  recost?: boolean;
+  holdDays?: number;
+}
+
+export interface QuoteTerms {
+  promoCode?: string;
+  requestedDeliveryDate?: string;
+}
+
+export interface UpdateJobRequest {
+  title?: string;
+  quantity?: number;
+  specifications?: JobSpecifications;
+  status?: JobStatus;
+  terms?: QuoteTerms;
+  promisedDate?: string;
+}
+
+export interface Holiday {
+  date: string;
+  name: string;
+}
+
+export interface ScheduledRun {
+  jobId: string;
+  title: string;
+  hours: number;
+}
+
+export interface MachineDay {
+  machineId?: string | null;
+  machineName: string;
+  capacityHours: number;
+  bookedHours: number;
+  runs: ScheduledRun[];
+}
+
+export interface ScheduleDay {
+  date: string;
+  holiday?: string | null;
+  machines: MachineDay[];
+}
+
+export interface JobSchedule {
+  jobId: string;
+  title: string;
+  status: JobStatus;
+  requestedDate?: string | null;
+  promisedDate?: string | null;
+  startDate: string;
+  completionDate?: string | null;
+  late: boolean;
+  daysLate: number;
+}
+
+export interface ProductionSchedule {
+  from: string;
+  to: string;
+  days: ScheduleDay[];
+  jobs: JobSchedule[];
+}
+
+export interface JobRevision {
+  id: string;
+  jobId: string;
+  revision: number;
+  jobType: JobType;
+  quantity: number;
+  specifications: JobSpecifications;
+  costBreakdown: CostBreakdown;
+  totalCost: number;
+  unitCost: number;
+  pricing?: PriceSummary;
+  tax?: TaxSummary;
+  printProcess?: PrintProcess;
+  parametersVersion: number;
+  currency: Currency;
+  exchangeRate: number;
+  createdAt: string;
+}
+
+export interface RevisionDiff {
+  jobId: string;
+  from: number;
+  to: number;
+  specificationChanges: { field: string; from: unknown; to: unknown }[];
+  lineChanges: {
+    category: CostLine['category'];
+    section?: string;
+    description: string;
+    from?: number;
+    to?: number;
+    change: number;
+  }[];
+  overheadChange: number;
+  totalCostChange: number;
+  parametersChanged: boolean;
+  exchangeRateChanged: boolean;
+}
+
 export interface CreateJobRequest {
   title: string;
   jobType: JobType;
   quantity: number;
   specifications: JobSpecifications;
+  customerId?: string;
+  promoCode?: string;
+  requestedDeliveryDate?: string;
 }
 
 export interface CostCalculationRequest {
@@ -74,6 +344,9 @@ export interface CostCalculationRequest {
   quantity: number;
   specifications: JobSpecifications;
   currency?: Currency;
+  customerId?: string;
+  promoCode?: string;
+  requestedDeliveryDate?: string;
 }
 
 export interface CostCalculationResponse {
@@ -81,22 +354,171 @@ export interface CostCalculationResponse {
   totalCost: number;
   unitCost: number;
   estimatedDeliveryDays: number;
+  pricing?: PriceSummary;
+  tax?: TaxSummary;
+  process?: PrintProcess;
+  alternatives: ProcessAlternative[];
+  crossoverQuantity?: number;
   currency?: Currency;
   exchangeRate?: number;
+  parametersVersion?: number;
+}
+
+export interface BatchCostResponse {
+  parametersVersion?: number;
+  succeeded: number;
+  failed: number;
+  items: {
+    index: number;
+    result?: CostCalculationResponse | null;
+    error?: string | null;
+  }[];
+}
+
+export interface BudgetRequest {
+  jobType: JobType;
+  specifications: JobSpecifications;
+  budget: number;
+  currency?: Currency;
+  customerId?: string;
+  promoCode?: string;
+  requestedDeliveryDate?: string;
+  includesTax?: boolean;
+  stocks?: { paperType: string; paperWeight?: string }[];
+  varyFinishing?: boolean;
+  limit?: number;
+}
+
+export interface BudgetOption {
+  description: string;
+  quantity: number;
+  specifications: JobSpecifications;
+  process: PrintProcess;
+  costBreakdown: CostBreakdown;
+  totalCost: number;
+  unitCost: number;
+  pricing: PriceSummary;
+  tax: TaxSummary;
+  remaining: number;
+  crossoverQuantity?: number;
+}
+
+export interface BudgetResponse {
+  budget: number;
+  currency: Currency;
+  exchangeRate: number;
+  options: BudgetOption[];
+}
+
+export interface ProcessAlternative {
+  process: PrintProcess;
+  totalCost: number;
+  unitCost: number;
+  estimatedDeliveryDays: number;
+  selected: boolean;
 }
 
 export interface CostParameters {
   id: string;
   paperCostPerSheet: number;
-  plateCostPerJob: number;
+  plateCost: number;
   laborCostPerHour: number;
   bindingCostPerUnit: number;
   overheadPercentage: number;
   profitMarginPercentage: number;
+  pricing: PricingParameters;
+  adjustments: AdjustmentParameters;
   createdAt: string;
   updatedAt: string;
 }
 
+export type ProfitMode = 'markup' | 'margin';
+
+export interface PricingParameters {
+  mode: ProfitMode;
+  jobTypeRates: Partial<Record<JobType, number>>;
+  minimumMarginPercentage: number;
+}
+
+export interface PriceSummary {
+  productionCost: number;
+  overhead: number;
+  fullCost: number;
+  profit: number;
+  sellingPrice: number;
+  profitMode: ProfitMode;
+  profitRate: number;
+  marginPercentage: number;
+  markupPercentage: number;
+  adjustments: AdjustmentLine[];
+  quotedPrice: number;
+  requiresApproval: boolean;
+}
+
+export type AdjustmentKind =
+  | 'volume_discount'
+  | 'customer_discount'
+  | 'promo_discount'
+  | 'rush_surcharge'
+  | 'small_quantity_surcharge'
+  | 'minimum_order_charge';
+
+export interface AdjustmentLine {
+  kind: AdjustmentKind;
+  description: string;
+  rate?: number;
+  amount: number;
+}
+
+export interface Discount {
+  kind: 'percentage' | 'fixed';
+  value: number;
+}
+
+export interface AdjustmentParameters {
+  volumeDiscounts: { minQuantity: number; percentage: number }[];
+  promoCodes: { code: string; discount: Discount; validUntil?: string }[];
+  minimumOrderValue: number;
+  rushSurchargePerDay: number;
+  maxRushSurcharge: number;
+  smallQuantityThreshold: number;
+  smallQuantitySurcharge: number;
+}
+
+export type TaxTreatment = 'standard' | 'exempt' | 'reverse_charge';
+
+export interface TaxLine {
+  name: string;
+  rate: number;
+  taxableAmount: number;
+  amount: number;
+}
+
+export interface TaxSummary {
+  net: number;
+  lines: TaxLine[];
+  tax: number;
+  gross: number;
+  treatment: TaxTreatment;
+  note?: string;
+  companyTaxId?: string;
+  customerTaxId?: string;
+}
+
+export interface TaxRate {
+  name: string;
+  rate: number;
+}
+
+export interface TaxSettings {
+  companyCountry: string;
+  companyTaxId?: string;
+  rates: TaxRate[];
+  reverseChargeCountries: string[];
+  reverseChargeNote: string;
+  exemptionNote: string;
+}
+
 export interface BrandingSettings {
   id: string;
   companyName: string;